@@ -100,6 +100,8 @@ pub enum KindDump {
         method: IndexDocumentsMethod,
         documents_count: u64,
         allow_index_creation: bool,
+        #[serde(default)]
+        auto_generate_ids: bool,
     },
     DocumentDeletion {
         documents_ids: Vec<String>,
@@ -169,12 +171,14 @@ impl From<KindWithContent> for KindDump {
                 method,
                 documents_count,
                 allow_index_creation,
+                auto_generate_ids,
                 ..
             } => KindDump::DocumentImport {
                 primary_key,
                 method,
                 documents_count,
                 allow_index_creation,
+                auto_generate_ids,
             },
             KindWithContent::DocumentDeletion { documents_ids, .. } => {
                 KindDump::DocumentDeletion { documents_ids }
@@ -292,6 +296,14 @@ pub(crate) mod test {
             embedders: Setting::NotSet,
             search_cutoff_ms: Setting::NotSet,
             localized_attributes: Setting::NotSet,
+            changelog: Setting::NotSet,
+            version_attribute: Setting::NotSet,
+            auto_generate_ids: Setting::NotSet,
+            document_schema: Setting::NotSet,
+            ingest_pipeline: Setting::NotSet,
+            computed_fields: Setting::NotSet,
+            date_attributes: Setting::NotSet,
+            searchable_attributes_weights: Setting::NotSet,
             _kind: std::marker::PhantomData,
         };
         settings.check()
@@ -308,6 +320,7 @@ pub(crate) mod test {
                     kind: KindDump::DocumentImport {
                         method: milli::update::IndexDocumentsMethod::UpdateDocuments,
                         allow_index_creation: true,
+                        auto_generate_ids: false,
                         primary_key: Some(S("bone")),
                         documents_count: 12,
                     },
@@ -315,6 +328,9 @@ pub(crate) mod test {
                     details: Some(Details::DocumentAdditionOrUpdate {
                         received_documents: 12,
                         indexed_documents: Some(10),
+                        skipped_documents: None,
+                        generated_document_ids: None,
+                        rejected_documents: None,
                     }),
                     error: None,
                     enqueued_at: datetime!(2022-11-11 0:00 UTC),
@@ -332,6 +348,7 @@ pub(crate) mod test {
                     kind: KindDump::DocumentImport {
                         method: milli::update::IndexDocumentsMethod::UpdateDocuments,
                         allow_index_creation: true,
+                        auto_generate_ids: false,
                         primary_key: None,
                         documents_count: 2,
                     },
@@ -339,6 +356,9 @@ pub(crate) mod test {
                     details: Some(Details::DocumentAdditionOrUpdate {
                         received_documents: 2,
                         indexed_documents: None,
+                        skipped_documents: None,
+                        generated_document_ids: None,
+                        rejected_documents: None,
                     }),
                     error: None,
                     enqueued_at: datetime!(2022-11-11 0:00 UTC),
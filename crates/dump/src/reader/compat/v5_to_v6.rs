@@ -104,6 +104,7 @@ impl CompatV5ToV6 {
                                 }
                             },
                             allow_index_creation,
+                            auto_generate_ids: false,
                         },
                         v5::tasks::TaskContent::DocumentDeletion { deletion, .. } => match deletion
                         {
@@ -133,6 +134,9 @@ impl CompatV5ToV6 {
                             v6::Details::DocumentAdditionOrUpdate {
                                 received_documents: received_documents as u64,
                                 indexed_documents,
+                                skipped_documents: None,
+                                generated_document_ids: None,
+                                rejected_documents: None,
                             }
                         }
                         v5::Details::Settings { settings } => {
@@ -360,6 +364,8 @@ impl<T> From<v5::Settings<T>> for v6::Settings<v6::Unchecked> {
                     },
                     disable_on_words: typo.disable_on_words.into(),
                     disable_on_attributes: typo.disable_on_attributes.into(),
+                    phonetic_on_attributes: v6::Setting::NotSet,
+                    phonetic_algorithm: v6::Setting::NotSet,
                 }),
                 v5::Setting::Reset => v6::Setting::Reset,
                 v5::Setting::NotSet => v6::Setting::NotSet,
@@ -381,6 +387,14 @@ impl<T> From<v5::Settings<T>> for v6::Settings<v6::Unchecked> {
             },
             embedders: v6::Setting::NotSet,
             localized_attributes: v6::Setting::NotSet,
+            changelog: v6::Setting::NotSet,
+            version_attribute: v6::Setting::NotSet,
+            auto_generate_ids: v6::Setting::NotSet,
+            document_schema: v6::Setting::NotSet,
+            ingest_pipeline: v6::Setting::NotSet,
+            computed_fields: v6::Setting::NotSet,
+            date_attributes: v6::Setting::NotSet,
+            searchable_attributes_weights: v6::Setting::NotSet,
             search_cutoff_ms: v6::Setting::NotSet,
             _kind: std::marker::PhantomData,
         }
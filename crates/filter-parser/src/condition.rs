@@ -12,6 +12,7 @@ use nom::combinator::cut;
 use nom::sequence::{terminated, tuple};
 use Condition::*;
 
+use crate::value::parse_regex;
 use crate::{parse_value, FilterCondition, IResult, Span, Token};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -28,14 +29,21 @@ pub enum Condition<'a> {
     Between { from: Token<'a>, to: Token<'a> },
     Contains { keyword: Token<'a>, word: Token<'a> },
     StartsWith { keyword: Token<'a>, word: Token<'a> },
+    EndsWith { keyword: Token<'a>, word: Token<'a> },
+    Matches { keyword: Token<'a>, regex: Token<'a> },
+    CaseSensitiveEqual { keyword: Token<'a>, word: Token<'a> },
 }
 
 /// condition      = value ("==" | ">" ...) value
 pub fn parse_condition(input: Span) -> IResult<FilterCondition> {
-    let operator = alt((tag("<="), tag(">="), tag("!="), tag("<"), tag(">"), tag("=")));
+    let operator = alt((tag("=="), tag("<="), tag(">="), tag("!="), tag("<"), tag(">"), tag("=")));
     let (input, (fid, op, value)) = tuple((parse_value, operator, cut(parse_value)))(input)?;
 
     let condition = match *op.fragment() {
+        "==" => FilterCondition::Condition {
+            fid,
+            op: CaseSensitiveEqual { keyword: Token::new(op, None), word: value },
+        },
         "<=" => FilterCondition::Condition { fid, op: LowerThanOrEqual(value) },
         ">=" => FilterCondition::Condition { fid, op: GreaterThanOrEqual(value) },
         "!=" => FilterCondition::Condition { fid, op: NotEqual(value) },
@@ -102,7 +110,7 @@ pub fn parse_contains(input: Span) -> IResult<FilterCondition> {
         input,
         FilterCondition::Condition {
             fid,
-            op: Contains { keyword: Token { span: contains, value: None }, word: value },
+            op: Contains { keyword: Token::new(contains, None), word: value },
         },
     ))
 }
@@ -117,7 +125,7 @@ pub fn parse_not_contains(input: Span) -> IResult<FilterCondition> {
         input,
         FilterCondition::Not(Box::new(FilterCondition::Condition {
             fid,
-            op: Contains { keyword: Token { span: contains, value: None }, word: value },
+            op: Contains { keyword: Token::new(contains, None), word: value },
         })),
     ))
 }
@@ -130,7 +138,7 @@ pub fn parse_starts_with(input: Span) -> IResult<FilterCondition> {
         input,
         FilterCondition::Condition {
             fid,
-            op: StartsWith { keyword: Token { span: starts_with, value: None }, word: value },
+            op: StartsWith { keyword: Token::new(starts_with, None), word: value },
         },
     ))
 }
@@ -145,7 +153,63 @@ pub fn parse_not_starts_with(input: Span) -> IResult<FilterCondition> {
         input,
         FilterCondition::Not(Box::new(FilterCondition::Condition {
             fid,
-            op: StartsWith { keyword: Token { span: starts_with, value: None }, word: value },
+            op: StartsWith { keyword: Token::new(starts_with, None), word: value },
+        })),
+    ))
+}
+
+/// ends with      = value "ENDS WITH" value
+pub fn parse_ends_with(input: Span) -> IResult<FilterCondition> {
+    let (input, (fid, ends_with, value)) =
+        tuple((parse_value, tag("ENDS WITH"), cut(parse_value)))(input)?;
+    Ok((
+        input,
+        FilterCondition::Condition {
+            fid,
+            op: EndsWith { keyword: Token::new(ends_with, None), word: value },
+        },
+    ))
+}
+
+/// ends with      = value "NOT" WS+ "ENDS WITH" value
+pub fn parse_not_ends_with(input: Span) -> IResult<FilterCondition> {
+    let keyword = tuple((tag("NOT"), multispace1, tag("ENDS WITH")));
+    let (input, (fid, (_not, _spaces, ends_with), value)) =
+        tuple((parse_value, keyword, cut(parse_value)))(input)?;
+
+    Ok((
+        input,
+        FilterCondition::Not(Box::new(FilterCondition::Condition {
+            fid,
+            op: EndsWith { keyword: Token::new(ends_with, None), word: value },
+        })),
+    ))
+}
+
+/// matches        = value "MATCHES" regex
+pub fn parse_matches(input: Span) -> IResult<FilterCondition> {
+    let (input, (fid, matches, regex)) =
+        tuple((parse_value, tag("MATCHES"), cut(parse_regex)))(input)?;
+    Ok((
+        input,
+        FilterCondition::Condition {
+            fid,
+            op: Matches { keyword: Token::new(matches, None), regex },
+        },
+    ))
+}
+
+/// matches        = value "NOT" WS+ "MATCHES" regex
+pub fn parse_not_matches(input: Span) -> IResult<FilterCondition> {
+    let keyword = tuple((tag("NOT"), multispace1, tag("MATCHES")));
+    let (input, (fid, (_not, _spaces, matches), regex)) =
+        tuple((parse_value, keyword, cut(parse_regex)))(input)?;
+
+    Ok((
+        input,
+        FilterCondition::Not(Box::new(FilterCondition::Condition {
+            fid,
+            op: Matches { keyword: Token::new(matches, None), regex },
         })),
     ))
 }
@@ -0,0 +1,497 @@
+//! Structured JSON filters, an alternative to the string syntax that doesn't require escaping:
+//!
+//! ```text
+//! filter         = and | or | not | condition | size | nested | geoRadius | geoBoundingBox
+//! and            = { "and": [filter, ...] }
+//! or             = { "or": [filter, ...] }
+//! not            = { "not": filter }
+//! condition      = { "field": string, "op": operator, "value": value | [value, ...] }
+//! size           = { "size": string, "op": "=" | "!=" | ">" | ">=" | "<" | "<=", "value": number }
+//! nested         = { "nested": string, "filter": filter }
+//! geoRadius      = { "geoRadius": [number, number, number] }
+//! geoBoundingBox = { "geoBoundingBox": [[number, number], [number, number]] }
+//! value          = string | number | boolean
+//! ```
+//!
+//! The operators are the ones of the string syntax, e.g. `>=`, `IN`, `TO`, `NOT EXISTS` or
+//! `STARTS WITH`. The `IN` and `NOT IN` operators take an array of values, `TO` an array of
+//! two values and the `EXISTS`, `IS NULL` and `IS EMPTY` operators, and their negations,
+//! don't take any value.
+//!
+//! The errors refer to the JSON path of the invalid part of the filter, e.g. `$.and[1].op`.
+
+use std::fmt::Display;
+
+use serde_json::{Map, Value};
+
+use crate::{Condition, FilterCondition, Span, Token, MAX_FILTER_DEPTH};
+
+const FILTER_KEYS: &[&str] =
+    &["and", "or", "not", "field", "size", "nested", "geoRadius", "geoBoundingBox"];
+
+const OPERATORS: &[&str] = &[
+    "=",
+    "==",
+    "!=",
+    ">",
+    ">=",
+    "<",
+    "<=",
+    "IN",
+    "NOT IN",
+    "TO",
+    "EXISTS",
+    "NOT EXISTS",
+    "IS NULL",
+    "IS NOT NULL",
+    "IS EMPTY",
+    "IS NOT EMPTY",
+    "CONTAINS",
+    "NOT CONTAINS",
+    "STARTS WITH",
+    "NOT STARTS WITH",
+    "ENDS WITH",
+    "NOT ENDS WITH",
+    "MATCHES",
+    "NOT MATCHES",
+];
+
+const SIZE_OPERATORS: &[&str] = &["=", "!=", ">", ">=", "<", "<="];
+
+/// An invalid structured JSON filter, with the JSON path of its invalid part.
+#[derive(Debug)]
+pub struct JsonFilterError {
+    path: String,
+    message: String,
+}
+
+impl Display for JsonFilterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", self.message)?;
+        write!(f, "at `{}`", self.path)
+    }
+}
+
+impl std::error::Error for JsonFilterError {}
+
+impl<'a> FilterCondition<'a> {
+    /// Converts a structured JSON filter, like `{"field": "price", "op": ">", "value": 10}`,
+    /// into a filter condition.
+    pub fn from_json(filter: &'a Value) -> Result<Self, JsonFilterError> {
+        parse_filter(filter, "$".to_string(), 0)
+    }
+}
+
+fn parse_filter(
+    value: &Value,
+    path: String,
+    depth: usize,
+) -> Result<FilterCondition, JsonFilterError> {
+    if depth > MAX_FILTER_DEPTH {
+        return Err(invalid(
+            path,
+            "The filter exceeded the maximum depth limit. Try rewriting the filter so that it contains fewer nested conditions."
+                .to_string(),
+        ));
+    }
+
+    let Value::Object(object) = value else {
+        return Err(invalid(
+            path,
+            format!("Was expecting a filter object but instead got `{value}`."),
+        ));
+    };
+    let Some(kind) = object.keys().find(|key| FILTER_KEYS.contains(&key.as_str())) else {
+        return Err(invalid(
+            path,
+            format!("Was expecting a filter object with one of the keys {}.", list(FILTER_KEYS)),
+        ));
+    };
+
+    match kind.as_str() {
+        "and" => {
+            expect_keys(object, &path, &["and"])?;
+            parse_filters(&object["and"], format!("{path}.and"), depth).map(FilterCondition::And)
+        }
+        "or" => {
+            expect_keys(object, &path, &["or"])?;
+            parse_filters(&object["or"], format!("{path}.or"), depth).map(FilterCondition::Or)
+        }
+        "not" => {
+            expect_keys(object, &path, &["not"])?;
+            let filter = parse_filter(&object["not"], format!("{path}.not"), depth + 1)?;
+            Ok(match filter {
+                FilterCondition::Not(filter) => *filter,
+                filter => FilterCondition::Not(Box::new(filter)),
+            })
+        }
+        "field" => {
+            expect_keys(object, &path, &["field", "op", "value"])?;
+            parse_condition(object, &path)
+        }
+        "size" => {
+            expect_keys(object, &path, &["size", "op", "value"])?;
+            let fid = parse_string(&object["size"], format!("{path}.size"))?;
+            let op_path = format!("{path}.op");
+            let op = match object.get("op") {
+                Some(Value::String(op)) if SIZE_OPERATORS.contains(&op.as_str()) => op.as_str(),
+                Some(op) => return Err(unknown_operator(op, op_path, SIZE_OPERATORS)),
+                None => return Err(missing(op_path)),
+            };
+            let value = parse_value(object, &path)?;
+            let op = match op {
+                "=" => Condition::Equal(value),
+                "!=" => Condition::NotEqual(value),
+                ">" => Condition::GreaterThan(value),
+                ">=" => Condition::GreaterThanOrEqual(value),
+                "<" => Condition::LowerThan(value),
+                "<=" => Condition::LowerThanOrEqual(value),
+                _ => unreachable!(),
+            };
+            Ok(FilterCondition::Size { fid, op })
+        }
+        "nested" => {
+            expect_keys(object, &path, &["nested", "filter"])?;
+            let fid = parse_string(&object["nested"], format!("{path}.nested"))?;
+            let filter_path = format!("{path}.filter");
+            let filter = match object.get("filter") {
+                Some(filter) => parse_filter(filter, filter_path, depth + 1)?,
+                None => return Err(missing(filter_path)),
+            };
+            Ok(FilterCondition::Nested { fid, filter: Box::new(filter) })
+        }
+        "geoRadius" => {
+            expect_keys(object, &path, &["geoRadius"])?;
+            let path = format!("{path}.geoRadius");
+            match <[_; 3]>::try_from(parse_numbers(&object["geoRadius"], &path)?) {
+                Ok([lat, lng, radius]) => {
+                    Ok(FilterCondition::GeoLowerThan { point: [lat, lng], radius })
+                }
+                Err(_) => Err(invalid(
+                    path,
+                    "Was expecting three numbers: `[latitude, longitude, radius]`.".to_string(),
+                )),
+            }
+        }
+        "geoBoundingBox" => {
+            expect_keys(object, &path, &["geoBoundingBox"])?;
+            let path = format!("{path}.geoBoundingBox");
+            let expected = || {
+                invalid(
+                    path.clone(),
+                    "Was expecting two pairs of numbers: `[[latitude, longitude], [latitude, longitude]]`."
+                        .to_string(),
+                )
+            };
+            let Value::Array(points) = &object["geoBoundingBox"] else { return Err(expected()) };
+            let [top_right, bottom_left] = points.as_slice() else { return Err(expected()) };
+            let top_right = parse_numbers(top_right, &format!("{path}[0]"))?;
+            let bottom_left = parse_numbers(bottom_left, &format!("{path}[1]"))?;
+            match (<[_; 2]>::try_from(top_right), <[_; 2]>::try_from(bottom_left)) {
+                (Ok(top_right_point), Ok(bottom_left_point)) => {
+                    Ok(FilterCondition::GeoBoundingBox { top_right_point, bottom_left_point })
+                }
+                _ => Err(expected()),
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// condition      = { "field": string, "op": operator, "value": value | [value, ...] }
+fn parse_condition<'a>(
+    object: &'a Map<String, Value>,
+    path: &str,
+) -> Result<FilterCondition<'a>, JsonFilterError> {
+    let fid = parse_string(&object["field"], format!("{path}.field"))?;
+    let op_path = format!("{path}.op");
+    let (op, keyword) = match object.get("op") {
+        Some(Value::String(op)) if OPERATORS.contains(&op.as_str()) => (op.as_str(), token(op)),
+        Some(op) => return Err(unknown_operator(op, op_path, OPERATORS)),
+        None => return Err(missing(op_path)),
+    };
+
+    // the operators that doesn't take any value
+    let condition = match op {
+        "EXISTS" | "NOT EXISTS" => Some(Condition::Exists),
+        "IS NULL" | "IS NOT NULL" => Some(Condition::Null),
+        "IS EMPTY" | "IS NOT EMPTY" => Some(Condition::Empty),
+        _ => None,
+    };
+    if let Some(condition) = condition {
+        if object.contains_key("value") {
+            return Err(invalid(
+                format!("{path}.value"),
+                format!("The `{op}` operator doesn't take any value."),
+            ));
+        }
+        let condition = FilterCondition::Condition { fid, op: condition };
+        return Ok(if op.contains("NOT") {
+            FilterCondition::Not(Box::new(condition))
+        } else {
+            condition
+        });
+    }
+
+    let condition = match op {
+        "IN" | "NOT IN" => {
+            let values = parse_values(object, path)?;
+            let condition = FilterCondition::In { fid, els: values };
+            return Ok(if op == "IN" {
+                condition
+            } else {
+                FilterCondition::Not(Box::new(condition))
+            });
+        }
+        "TO" => match <[_; 2]>::try_from(parse_values(object, path)?) {
+            Ok([from, to]) => Condition::Between { from, to },
+            Err(_) => {
+                return Err(invalid(
+                    format!("{path}.value"),
+                    "Was expecting an array of two values: `[from, to]`.".to_string(),
+                ))
+            }
+        },
+        "=" => Condition::Equal(parse_value(object, path)?),
+        "==" => Condition::CaseSensitiveEqual { keyword, word: parse_value(object, path)? },
+        "!=" => Condition::NotEqual(parse_value(object, path)?),
+        ">" => Condition::GreaterThan(parse_value(object, path)?),
+        ">=" => Condition::GreaterThanOrEqual(parse_value(object, path)?),
+        "<" => Condition::LowerThan(parse_value(object, path)?),
+        "<=" => Condition::LowerThanOrEqual(parse_value(object, path)?),
+        "CONTAINS" | "NOT CONTAINS" => {
+            Condition::Contains { keyword, word: parse_value(object, path)? }
+        }
+        "STARTS WITH" | "NOT STARTS WITH" => {
+            Condition::StartsWith { keyword, word: parse_value(object, path)? }
+        }
+        "ENDS WITH" | "NOT ENDS WITH" => {
+            Condition::EndsWith { keyword, word: parse_value(object, path)? }
+        }
+        "MATCHES" | "NOT MATCHES" => {
+            Condition::Matches { keyword, regex: parse_value(object, path)? }
+        }
+        _ => unreachable!(),
+    };
+
+    let condition = FilterCondition::Condition { fid, op: condition };
+    Ok(if op.starts_with("NOT ") { FilterCondition::Not(Box::new(condition)) } else { condition })
+}
+
+/// Parses a non-empty array of filters.
+fn parse_filters(
+    value: &Value,
+    path: String,
+    depth: usize,
+) -> Result<Vec<FilterCondition>, JsonFilterError> {
+    match value {
+        Value::Array(filters) if !filters.is_empty() => filters
+            .iter()
+            .enumerate()
+            .map(|(i, filter)| parse_filter(filter, format!("{path}[{i}]"), depth + 1))
+            .collect(),
+        value => Err(invalid(
+            path,
+            format!("Was expecting a non-empty array of filters but instead got `{value}`."),
+        )),
+    }
+}
+
+/// Parses the single value of a condition.
+fn parse_value<'a>(
+    object: &'a Map<String, Value>,
+    path: &str,
+) -> Result<Token<'a>, JsonFilterError> {
+    let path = format!("{path}.value");
+    match object.get("value") {
+        Some(value) => value_token(value, path),
+        None => Err(missing(path)),
+    }
+}
+
+/// Parses the array of values of a condition.
+fn parse_values<'a>(
+    object: &'a Map<String, Value>,
+    path: &str,
+) -> Result<Vec<Token<'a>>, JsonFilterError> {
+    let path = format!("{path}.value");
+    match object.get("value") {
+        Some(Value::Array(values)) => values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| value_token(value, format!("{path}[{i}]")))
+            .collect(),
+        Some(value) => Err(invalid(
+            path,
+            format!("Was expecting an array of values but instead got `{value}`."),
+        )),
+        None => Err(missing(path)),
+    }
+}
+
+fn parse_string(value: &Value, path: String) -> Result<Token, JsonFilterError> {
+    match value {
+        Value::String(s) => Ok(token(s)),
+        value => Err(invalid(path, format!("Was expecting a string but instead got `{value}`."))),
+    }
+}
+
+fn parse_numbers(value: &Value, path: &str) -> Result<Vec<Token<'static>>, JsonFilterError> {
+    let expected = |value: &Value, path: String| {
+        invalid(path, format!("Was expecting an array of numbers but instead got `{value}`."))
+    };
+    let Value::Array(numbers) = value else { return Err(expected(value, path.to_string())) };
+    numbers
+        .iter()
+        .enumerate()
+        .map(|(i, value)| match value {
+            Value::Number(number) => Ok(owned_token(number.to_string())),
+            value => Err(expected(value, format!("{path}[{i}]"))),
+        })
+        .collect()
+}
+
+/// The values of the conditions are strings, numbers or booleans, like the facet values.
+fn value_token(value: &Value, path: String) -> Result<Token, JsonFilterError> {
+    match value {
+        Value::String(s) => Ok(token(s)),
+        Value::Number(number) => Ok(owned_token(number.to_string())),
+        Value::Bool(boolean) => Ok(owned_token(boolean.to_string())),
+        value => Err(invalid(
+            path,
+            format!("Was expecting a string, a number or a boolean but instead got `{value}`."),
+        )),
+    }
+}
+
+/// Returns an error if the filter object contains other keys than the allowed ones.
+fn expect_keys(
+    object: &Map<String, Value>,
+    path: &str,
+    allowed: &[&str],
+) -> Result<(), JsonFilterError> {
+    match object.keys().find(|key| !allowed.contains(&key.as_str())) {
+        Some(key) => Err(invalid(
+            format!("{path}.{key}"),
+            format!("Unknown key `{key}`, expected one of {}.", list(allowed)),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn token(s: &str) -> Token {
+    Token::new(Span::new_extra(s, s), None)
+}
+
+/// The numbers and booleans don't exist as strings in the JSON, their token owns its value.
+fn owned_token(value: String) -> Token<'static> {
+    Token::new(Span::new_extra("", ""), Some(value))
+}
+
+fn list(values: &[&str]) -> String {
+    values.iter().map(|value| format!("`{value}`")).collect::<Vec<_>>().join(", ")
+}
+
+fn invalid(path: String, message: String) -> JsonFilterError {
+    JsonFilterError { path, message }
+}
+
+fn missing(path: String) -> JsonFilterError {
+    invalid(path, "Missing mandatory key.".to_string())
+}
+
+fn unknown_operator(op: &Value, path: String, operators: &[&str]) -> JsonFilterError {
+    let op = match op {
+        Value::String(op) => op.clone(),
+        op => op.to_string(),
+    };
+    invalid(path, format!("Unknown operator `{op}`, expected one of {}.", list(operators)))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::FilterCondition;
+
+    #[track_caller]
+    fn p(filter: serde_json::Value) -> String {
+        match FilterCondition::from_json(&filter) {
+            Ok(filter) => filter.to_string(),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        insta::assert_snapshot!(p(json!({ "field": "price", "op": ">", "value": 10 })), @"{price} > {10}");
+        insta::assert_snapshot!(p(json!({ "field": "title", "op": "=", "value": "l'\"été\"" })), @r###"{title} = {l'"été"}"###);
+        insta::assert_snapshot!(p(json!({ "field": "available", "op": "!=", "value": true })), @"{available} != {true}");
+        insta::assert_snapshot!(p(json!({ "field": "price", "op": "TO", "value": [10, 20.5] })), @"{price} {10} TO {20.5}");
+        insta::assert_snapshot!(p(json!({ "field": "color", "op": "NOT IN", "value": ["red", "blue"] })), @"NOT ({color} IN[{red}, {blue}, ])");
+        insta::assert_snapshot!(p(json!({ "field": "color", "op": "IS NOT EMPTY" })), @"NOT ({color} IS EMPTY)");
+        insta::assert_snapshot!(p(json!({ "field": "code", "op": "NOT MATCHES", "value": "^AB/[0-9]+$" })), @"NOT ({code} MATCHES {^AB/[0-9]+$})");
+        insta::assert_snapshot!(p(json!({ "size": "images", "op": ">=", "value": 3 })), @"SIZE({images}) >= {3}");
+        insta::assert_snapshot!(p(json!({ "nested": "variants", "filter": { "field": "color", "op": "=", "value": "red" } })), @"{variants} {{color} = {red}}");
+        insta::assert_snapshot!(p(json!({ "geoRadius": [12, 13.5, 14] })), @"_geoRadius({12}, {13.5}, {14})");
+        insta::assert_snapshot!(p(json!({ "geoBoundingBox": [[12, 13], [14, 15]] })), @"_geoBoundingBox([{12}, {13}], [{14}, {15}])");
+        insta::assert_snapshot!(
+            p(json!({ "and": [
+                { "field": "price", "op": "<", "value": 10 },
+                { "or": [
+                    { "field": "color", "op": "EXISTS" },
+                    { "not": { "not": { "field": "tag", "op": "STARTS WITH", "value": "new" } } },
+                ] },
+            ] })),
+            @"AND[{price} < {10}, OR[{color} EXISTS, {tag} STARTS WITH {new}, ], ]"
+        );
+    }
+
+    #[test]
+    fn error() {
+        insta::assert_snapshot!(p(json!("price > 10")), @r###"
+        Was expecting a filter object but instead got `"price > 10"`.
+        at `$`
+        "###);
+        insta::assert_snapshot!(p(json!({ "price": 10 })), @r###"
+        Was expecting a filter object with one of the keys `and`, `or`, `not`, `field`, `size`, `nested`, `geoRadius`, `geoBoundingBox`.
+        at `$`
+        "###);
+        insta::assert_snapshot!(p(json!({ "and": [{ "field": "price", "op": ">>", "value": 10 }] })), @r###"
+        Unknown operator `>>`, expected one of `=`, `==`, `!=`, `>`, `>=`, `<`, `<=`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`.
+        at `$.and[0].op`
+        "###);
+        insta::assert_snapshot!(p(json!({ "or": [{ "field": "price", "op": ">", "value": null }] })), @r###"
+        Was expecting a string, a number or a boolean but instead got `null`.
+        at `$.or[0].value`
+        "###);
+        insta::assert_snapshot!(p(json!({ "not": { "field": "price", "op": ">" } })), @r###"
+        Missing mandatory key.
+        at `$.not.value`
+        "###);
+        insta::assert_snapshot!(p(json!({ "field": "price", "op": "EXISTS", "value": 3 })), @r###"
+        The `EXISTS` operator doesn't take any value.
+        at `$.value`
+        "###);
+        insta::assert_snapshot!(p(json!({ "field": "price", "op": "TO", "value": [1] })), @r###"
+        Was expecting an array of two values: `[from, to]`.
+        at `$.value`
+        "###);
+        insta::assert_snapshot!(p(json!({ "field": "price", "op": "=", "value": 1, "values": 2 })), @r###"
+        Unknown key `values`, expected one of `field`, `op`, `value`.
+        at `$.values`
+        "###);
+        insta::assert_snapshot!(p(json!({ "and": [] })), @r###"
+        Was expecting a non-empty array of filters but instead got `[]`.
+        at `$.and`
+        "###);
+        insta::assert_snapshot!(p(json!({ "geoRadius": [12, "13"] })), @r###"
+        Was expecting an array of numbers but instead got `"13"`.
+        at `$.geoRadius[1]`
+        "###);
+        insta::assert_snapshot!(p(json!({ "geoBoundingBox": [[12, 13], [14]] })), @r###"
+        Was expecting two pairs of numbers: `[[latitude, longitude], [latitude, longitude]]`.
+        at `$.geoBoundingBox`
+        "###);
+    }
+}
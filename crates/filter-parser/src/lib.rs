@@ -6,19 +6,24 @@
 //! or             = and ("OR" WS+ and)*
 //! and            = not ("AND" WS+ not)*
 //! not            = ("NOT" WS+ not) | primary
-//! primary        = (WS* "(" WS* expression WS* ")" WS*) | geoRadius | in | condition | exists | not_exists | to
+//! primary        = (WS* "(" WS* expression WS* ")" WS*) | geoRadius | size | nested | in | condition | exists | not_exists | to
+//! nested         = value "{" WS* expression WS* "}"
 //! in             = value "IN" WS* "[" value_list "]"
-//! condition      = value ("=" | "!=" | ">" | ">=" | "<" | "<=") value
+//! condition      = value ("=" | "==" | "!=" | ">" | ">=" | "<" | "<=") value
 //! exists         = value "EXISTS"
 //! not_exists     = value "NOT" WS+ "EXISTS"
 //! to             = value value "TO" WS+ value
-//! value          = WS* ( word | singleQuoted | doubleQuoted) WS+
+//! matches        = value "MATCHES" regex
+//! regex          = WS* "/" .* all but unescaped slashes "/" WS*
+//! value          = WS* ( word | date | singleQuoted | doubleQuoted) WS+
 //! value_list     = (value ("," value)* ","?)?
 //! singleQuoted   = "'" .* all but quotes "'"
 //! doubleQuoted   = "\"" .* all but double quotes "\""
 //! word           = (alphanumeric | _ | - | .)+
+//! date           = ("now" | digit{4} "-") (alphanumeric | _ | - | . | : | +)*
 //! geoRadius      = "_geoRadius(" WS* float WS* "," WS* float WS* "," float WS* ")"
 //! geoBoundingBox = "_geoBoundingBox([" WS * float WS* "," WS* float WS* "], [" WS* float WS* "," WS* float WS* "]")
+//! size           = "SIZE(" value ")" WS* ("=" | "!=" | ">" | ">=" | "<" | "<=") value
 //! ```
 //!
 //! Other BNF grammar used to handle some specific errors:
@@ -42,17 +47,20 @@
 
 mod condition;
 mod error;
+mod json;
 mod value;
 
 use std::fmt::Debug;
 
 pub use condition::{parse_condition, parse_to, Condition};
 use condition::{
-    parse_contains, parse_exists, parse_is_empty, parse_is_not_empty, parse_is_not_null,
-    parse_is_null, parse_not_contains, parse_not_exists, parse_not_starts_with, parse_starts_with,
+    parse_contains, parse_ends_with, parse_exists, parse_is_empty, parse_is_not_empty,
+    parse_is_not_null, parse_is_null, parse_matches, parse_not_contains, parse_not_ends_with,
+    parse_not_exists, parse_not_matches, parse_not_starts_with, parse_starts_with,
 };
 use error::{cut_with_err, ExpectedValueKind, NomErrorExt};
 pub use error::{Error, ErrorKind};
+pub use json::JsonFilterError;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{char, multispace0};
@@ -62,6 +70,9 @@ use nom::number::complete::recognize_float;
 use nom::sequence::{delimited, preceded, terminated, tuple};
 use nom::Finish;
 use nom_locate::LocatedSpan;
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime};
 pub(crate) use value::parse_value;
 use value::word_exact;
 
@@ -121,6 +132,73 @@ impl<'a> Token<'a> {
             Err(Error::new_from_kind(self.span, ErrorKind::NonFiniteFloat))
         }
     }
+
+    /// Parses the token as a finite float or, when it isn't a number, as a date
+    /// converted to a Unix timestamp in seconds, see [`parse_datetime`].
+    pub fn parse_finite_float_or_datetime(&self) -> Result<f64, Error> {
+        match self.parse_finite_float() {
+            Ok(value) => Ok(value),
+            Err(error) => match parse_datetime(self.value()) {
+                Some(timestamp) => Ok(timestamp),
+                None if looks_like_datetime(self.value()) => {
+                    Err(Error::new_from_kind(self.span, ErrorKind::MalformedDatetime))
+                }
+                None => Err(error),
+            },
+        }
+    }
+}
+
+/// Parses an RFC 3339 date-time, a `YYYY-MM-DD` date or a date relative to the current
+/// time, like `now`, `now-7d` or `now+1h`, into a Unix timestamp in seconds.
+///
+/// The units of the relative dates are `s`, `m`, `h`, `d` and `w`.
+pub fn parse_datetime(s: &str) -> Option<f64> {
+    if let Some(offset) = s.strip_prefix("now") {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        if offset.is_empty() {
+            return Some(now as f64);
+        }
+        let (sign, offset) = match offset.strip_prefix('-') {
+            Some(offset) => (-1, offset),
+            None => (1, offset.strip_prefix('+')?),
+        };
+        let (amount, unit) = offset.split_at(offset.char_indices().last()?.0);
+        let unit = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            "w" => 7 * 24 * 60 * 60,
+            _ => return None,
+        };
+        if amount.is_empty() || !amount.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let offset = amount.parse::<i64>().ok()?.checked_mul(unit)?;
+        return now.checked_add(sign * offset).map(|timestamp| timestamp as f64);
+    }
+
+    parse_date(s).map(|timestamp| timestamp as f64)
+}
+
+/// Parses an RFC 3339 date-time or a `YYYY-MM-DD` date into a Unix timestamp in seconds.
+pub fn parse_date(s: &str) -> Option<i64> {
+    if let Ok(datetime) = OffsetDateTime::parse(s, &Rfc3339) {
+        return Some(datetime.unix_timestamp());
+    }
+    let date = Date::parse(s, format_description!("[year]-[month]-[day]")).ok()?;
+    Some(date.midnight().assume_utc().unix_timestamp())
+}
+
+/// Whether the string was meant to be a date, i.e. it is relative to `now`
+/// or starts like a `YYYY-MM-DD` date.
+pub(crate) fn looks_like_datetime(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    s == "now"
+        || s.starts_with("now-")
+        || s.starts_with("now+")
+        || (bytes.len() >= 5 && bytes[..4].iter().all(u8::is_ascii_digit) && bytes[4] == b'-')
 }
 
 impl<'a> From<Span<'a>> for Token<'a> {
@@ -145,6 +223,8 @@ pub enum FilterCondition<'a> {
     And(Vec<Self>),
     GeoLowerThan { point: [Token<'a>; 2], radius: Token<'a> },
     GeoBoundingBox { top_right_point: [Token<'a>; 2], bottom_left_point: [Token<'a>; 2] },
+    Nested { fid: Token<'a>, filter: Box<Self> },
+    Size { fid: Token<'a>, op: Condition<'a> },
 }
 
 pub enum TraversedElement<'a> {
@@ -167,15 +247,21 @@ impl<'a> FilterCondition<'a> {
                 | Condition::LowerThanOrEqual(_)
                 | Condition::Between { .. } => None,
                 Condition::Contains { keyword, word: _ }
-                | Condition::StartsWith { keyword, word: _ } => Some(keyword),
+                | Condition::StartsWith { keyword, word: _ }
+                | Condition::EndsWith { keyword, word: _ }
+                | Condition::Matches { keyword, regex: _ }
+                | Condition::CaseSensitiveEqual { keyword, word: _ } => Some(keyword),
             },
-            FilterCondition::Not(this) => this.use_contains_operator(),
+            FilterCondition::Not(this) | FilterCondition::Nested { filter: this, .. } => {
+                this.use_contains_operator()
+            }
             FilterCondition::Or(seq) | FilterCondition::And(seq) => {
                 seq.iter().find_map(|filter| filter.use_contains_operator())
             }
             FilterCondition::GeoLowerThan { .. }
             | FilterCondition::GeoBoundingBox { .. }
-            | FilterCondition::In { .. } => None,
+            | FilterCondition::In { .. }
+            | FilterCondition::Size { .. } => None,
         }
     }
 
@@ -183,6 +269,8 @@ impl<'a> FilterCondition<'a> {
     pub fn token_at_depth(&self, depth: usize) -> Option<&Token> {
         match self {
             FilterCondition::Condition { fid, .. } if depth == 0 => Some(fid),
+            FilterCondition::Nested { fid, .. } if depth == 0 => Some(fid),
+            FilterCondition::Size { fid, .. } if depth == 0 => Some(fid),
             FilterCondition::Or(subfilters) => {
                 let depth = depth.saturating_sub(1);
                 for f in subfilters.iter() {
@@ -269,6 +357,20 @@ fn parse_in_body(input: Span) -> IResult<Vec<Token>> {
     Ok((input, content))
 }
 
+/// nested = value "{" WS* expression WS* "}"
+/// If we parse the opening brace we MUST parse the rest of the expression.
+fn parse_nested(input: Span, depth: usize) -> IResult<FilterCondition> {
+    let (rest, fid) = parse_value(input)?;
+    let (rest, _) = ws(char('{'))(rest)?;
+    let (rest, filter) = cut(|input| parse_expression(input, depth + 1))(rest)?;
+    let (rest, _) = cut_with_err(ws(char('}')), |c| {
+        Error::new_from_kind(input, ErrorKind::MissingClosingDelimiter(c.char()))
+    })(rest)?;
+
+    let filter = FilterCondition::Nested { fid, filter: Box::new(filter) };
+    Ok((rest, filter))
+}
+
 /// in = value "IN" "[" value_list "]"
 fn parse_in(input: Span) -> IResult<FilterCondition> {
     let (input, value) = parse_value(input)?;
@@ -402,6 +504,33 @@ fn parse_geo_bounding_box(input: Span) -> IResult<FilterCondition> {
     Ok((input, res))
 }
 
+/// size           = WS* "SIZE(" value ")" WS* ("=" | "!=" | ">" | ">=" | "<" | "<=") value
+/// If we parse `SIZE(` we MUST parse the rest of the expression.
+fn parse_size(input: Span) -> IResult<FilterCondition> {
+    let operator = alt((tag("<="), tag(">="), tag("!="), tag("<"), tag(">"), tag("=")));
+    // we want to allow space BEFORE the SIZE but not after
+    let parsed = preceded(
+        tuple((multispace0, word_exact("SIZE"), char('('))),
+        // if we were able to parse `SIZE(` and can't parse the rest of the input we return a failure
+        cut(tuple((terminated(parse_value, char(')')), ws(operator), parse_value))),
+    )(input)
+    .map_err(|e| e.map(|_| Error::new_from_kind(input, ErrorKind::Size)));
+
+    let (input, (fid, op, value)) = parsed?;
+
+    let op = match *op.fragment() {
+        "<=" => Condition::LowerThanOrEqual(value),
+        ">=" => Condition::GreaterThanOrEqual(value),
+        "!=" => Condition::NotEqual(value),
+        "<" => Condition::LowerThan(value),
+        ">" => Condition::GreaterThan(value),
+        "=" => Condition::Equal(value),
+        _ => unreachable!(),
+    };
+
+    Ok((input, FilterCondition::Size { fid, op }))
+}
+
 /// geoPoint      = WS* "_geoPoint(float WS* "," WS* float WS* "," WS* float)
 fn parse_geo_point(input: Span) -> IResult<FilterCondition> {
     // we want to forbid space BEFORE the _geoPoint but not after
@@ -457,7 +586,7 @@ fn parse_error_reserved_keyword(input: Span) -> IResult<FilterCondition> {
     }
 }
 
-/// primary        = (WS* "(" WS* expression WS* ")" WS*) | geoRadius | condition | exists | not_exists | to
+/// primary        = (WS* "(" WS* expression WS* ")" WS*) | geoRadius | size | nested | condition | exists | not_exists | to
 fn parse_primary(input: Span, depth: usize) -> IResult<FilterCondition> {
     if depth > MAX_FILTER_DEPTH {
         return Err(nom::Err::Error(Error::new_from_kind(input, ErrorKind::DepthLimitReached)));
@@ -473,6 +602,8 @@ fn parse_primary(input: Span, depth: usize) -> IResult<FilterCondition> {
         ),
         parse_geo_radius,
         parse_geo_bounding_box,
+        parse_size,
+        |input| parse_nested(input, depth + 1),
         parse_in,
         parse_not_in,
         parse_condition,
@@ -483,10 +614,16 @@ fn parse_primary(input: Span, depth: usize) -> IResult<FilterCondition> {
         parse_exists,
         parse_not_exists,
         parse_to,
-        parse_contains,
-        parse_not_contains,
-        parse_starts_with,
-        parse_not_starts_with,
+        alt((
+            parse_contains,
+            parse_not_contains,
+            parse_starts_with,
+            parse_not_starts_with,
+            parse_ends_with,
+            parse_not_ends_with,
+            parse_matches,
+            parse_not_matches,
+        )),
         // the next lines are only for error handling and are written at the end to have the less possible performance impact
         parse_geo,
         parse_geo_distance,
@@ -540,6 +677,12 @@ impl<'a> std::fmt::Display for FilterCondition<'a> {
             FilterCondition::GeoLowerThan { point, radius } => {
                 write!(f, "_geoRadius({}, {}, {})", point[0], point[1], radius)
             }
+            FilterCondition::Nested { fid, filter } => {
+                write!(f, "{fid} {{{filter}}}")
+            }
+            FilterCondition::Size { fid, op } => {
+                write!(f, "SIZE({fid}) {op}")
+            }
             FilterCondition::GeoBoundingBox {
                 top_right_point: top_left_point,
                 bottom_left_point: bottom_right_point,
@@ -571,6 +714,9 @@ impl<'a> std::fmt::Display for Condition<'a> {
             Condition::Between { from, to } => write!(f, "{from} TO {to}"),
             Condition::Contains { word, keyword: _ } => write!(f, "CONTAINS {word}"),
             Condition::StartsWith { word, keyword: _ } => write!(f, "STARTS WITH {word}"),
+            Condition::EndsWith { word, keyword: _ } => write!(f, "ENDS WITH {word}"),
+            Condition::Matches { regex, keyword: _ } => write!(f, "MATCHES {regex}"),
+            Condition::CaseSensitiveEqual { word, keyword: _ } => write!(f, "== {word}"),
         }
     }
 }
@@ -691,6 +837,19 @@ pub mod tests {
         insta::assert_snapshot!(p("NOT subscribers NOT STARTS WITH 'hel'"), @"{subscribers} STARTS WITH {hel}");
         insta::assert_snapshot!(p("subscribers NOT   STARTS WITH 'hel'"), @"NOT ({subscribers} STARTS WITH {hel})");
 
+        // Test ENDS WITH + NOT ENDS WITH
+        insta::assert_snapshot!(p("subscribers ENDS WITH 'llo'"), @"{subscribers} ENDS WITH {llo}");
+        insta::assert_snapshot!(p("subscribers NOT ENDS WITH llo"), @"NOT ({subscribers} ENDS WITH {llo})");
+
+        // Test MATCHES + NOT MATCHES
+        insta::assert_snapshot!(p("code MATCHES /^AB-[0-9]+$/"), @"{code} MATCHES {^AB-[0-9]+$}");
+        insta::assert_snapshot!(p(r"code MATCHES /a\/b/"), @"{code} MATCHES {a/b}");
+        insta::assert_snapshot!(p("code NOT MATCHES /^ab/ AND code EXISTS"), @"AND[NOT ({code} MATCHES {^ab}), {code} EXISTS, ]");
+
+        // Test the case-sensitive equality
+        insta::assert_snapshot!(p("code == AB-100"), @"{code} == {AB-100}");
+        insta::assert_snapshot!(p("NOT code == 'AB-100'"), @"NOT ({code} == {AB-100})");
+
         // Test nested NOT
         insta::assert_snapshot!(p("NOT NOT NOT NOT x = 5"), @"{x} = {5}");
         insta::assert_snapshot!(p("NOT NOT (NOT NOT x = 5)"), @"{x} = {5}");
@@ -718,6 +877,15 @@ pub mod tests {
         insta::assert_snapshot!(p("channel = ponce AND ( 'dog race' != 'bernese mountain' OR subscribers > 1000 )"), @"AND[{channel} = {ponce}, OR[{dog race} != {bernese mountain}, {subscribers} > {1000}, ], ]");
         insta::assert_snapshot!(p("(channel = ponce AND 'dog race' != 'bernese mountain' OR subscribers > 1000) AND _geoRadius(12, 13, 14)"), @"AND[OR[AND[{channel} = {ponce}, {dog race} != {bernese mountain}, ], {subscribers} > {1000}, ], _geoRadius({12}, {13}, {14}), ]");
 
+        // Test nested filters
+        insta::assert_snapshot!(p("variants { color = red AND size = M }"), @"{variants} {AND[{color} = {red}, {size} = {M}, ]}");
+        insta::assert_snapshot!(p("NOT variants{color IN [red, blue]} OR stock > 0"), @"OR[NOT ({variants} {{color} IN[{red}, {blue}, ]}), {stock} > {0}, ]");
+
+        // Test size functions
+        insta::assert_snapshot!(p("SIZE(images) >= 3"), @"SIZE({images}) >= {3}");
+        insta::assert_snapshot!(p("SIZE('product images')=0 OR NOT SIZE(tags) != 2"), @"OR[SIZE({product images}) = {0}, NOT (SIZE({tags}) != {2}), ]");
+        insta::assert_snapshot!(p("SIZE = 3"), @"{SIZE} = {3}");
+
         // Test recursion
         // This is the most that is allowed
         insta::assert_snapshot!(
@@ -762,7 +930,7 @@ pub mod tests {
         "###);
 
         insta::assert_snapshot!(p("'OR'"), @r###"
-        Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, or `_geoBoundingBox` at `\'OR\'`.
+        Was expecting an operation `=`, `==`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, or `_geoBoundingBox` at `\'OR\'`.
         1:5 'OR'
         "###);
 
@@ -772,12 +940,12 @@ pub mod tests {
         "###);
 
         insta::assert_snapshot!(p("channel Ponce"), @r###"
-        Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, or `_geoBoundingBox` at `channel Ponce`.
+        Was expecting an operation `=`, `==`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, or `_geoBoundingBox` at `channel Ponce`.
         1:14 channel Ponce
         "###);
 
         insta::assert_snapshot!(p("channel = Ponce OR"), @r###"
-        Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, or `_geoBoundingBox` but instead got nothing.
+        Was expecting an operation `=`, `==`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, or `_geoBoundingBox` but instead got nothing.
         19:19 channel = Ponce OR
         "###);
 
@@ -856,18 +1024,28 @@ pub mod tests {
         17:35 channel = mv OR (followers >= 1000
         "###);
 
+        insta::assert_snapshot!(p("channel = mv OR variants { color = red"), @r###"
+        Expression `variants { color = red` is missing the following closing delimiter: `}`.
+        17:39 channel = mv OR variants { color = red
+        "###);
+
+        insta::assert_snapshot!(p("SIZE(images) CONTAINS 3"), @r###"
+        The `SIZE` filter expects a field name and a comparison to a number: `SIZE(field) >= 3`.
+        1:24 SIZE(images) CONTAINS 3
+        "###);
+
         insta::assert_snapshot!(p("channel = mv OR followers >= 1000)"), @r###"
         Found unexpected characters at the end of the filter: `)`. You probably forgot an `OR` or an `AND` rule.
         34:35 channel = mv OR followers >= 1000)
         "###);
 
         insta::assert_snapshot!(p("colour NOT EXIST"), @r###"
-        Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, or `_geoBoundingBox` at `colour NOT EXIST`.
+        Was expecting an operation `=`, `==`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, or `_geoBoundingBox` at `colour NOT EXIST`.
         1:17 colour NOT EXIST
         "###);
 
         insta::assert_snapshot!(p("subscribers 100 TO1000"), @r###"
-        Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, or `_geoBoundingBox` at `subscribers 100 TO1000`.
+        Was expecting an operation `=`, `==`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, or `_geoBoundingBox` at `subscribers 100 TO1000`.
         1:23 subscribers 100 TO1000
         "###);
 
@@ -930,35 +1108,35 @@ pub mod tests {
         "###);
 
         insta::assert_snapshot!(p(r#"value NULL"#), @r###"
-        Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, or `_geoBoundingBox` at `value NULL`.
+        Was expecting an operation `=`, `==`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, or `_geoBoundingBox` at `value NULL`.
         1:11 value NULL
         "###);
         insta::assert_snapshot!(p(r#"value NOT NULL"#), @r###"
-        Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, or `_geoBoundingBox` at `value NOT NULL`.
+        Was expecting an operation `=`, `==`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, or `_geoBoundingBox` at `value NOT NULL`.
         1:15 value NOT NULL
         "###);
         insta::assert_snapshot!(p(r#"value EMPTY"#), @r###"
-        Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, or `_geoBoundingBox` at `value EMPTY`.
+        Was expecting an operation `=`, `==`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, or `_geoBoundingBox` at `value EMPTY`.
         1:12 value EMPTY
         "###);
         insta::assert_snapshot!(p(r#"value NOT EMPTY"#), @r###"
-        Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, or `_geoBoundingBox` at `value NOT EMPTY`.
+        Was expecting an operation `=`, `==`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, or `_geoBoundingBox` at `value NOT EMPTY`.
         1:16 value NOT EMPTY
         "###);
         insta::assert_snapshot!(p(r#"value IS"#), @r###"
-        Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, or `_geoBoundingBox` at `value IS`.
+        Was expecting an operation `=`, `==`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, or `_geoBoundingBox` at `value IS`.
         1:9 value IS
         "###);
         insta::assert_snapshot!(p(r#"value IS NOT"#), @r###"
-        Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, or `_geoBoundingBox` at `value IS NOT`.
+        Was expecting an operation `=`, `==`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, or `_geoBoundingBox` at `value IS NOT`.
         1:13 value IS NOT
         "###);
         insta::assert_snapshot!(p(r#"value IS EXISTS"#), @r###"
-        Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, or `_geoBoundingBox` at `value IS EXISTS`.
+        Was expecting an operation `=`, `==`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, or `_geoBoundingBox` at `value IS EXISTS`.
         1:16 value IS EXISTS
         "###);
         insta::assert_snapshot!(p(r#"value IS NOT EXISTS"#), @r###"
-        Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, or `_geoBoundingBox` at `value IS NOT EXISTS`.
+        Was expecting an operation `=`, `==`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, or `_geoBoundingBox` at `value IS NOT EXISTS`.
         1:20 value IS NOT EXISTS
         "###);
     }
@@ -978,6 +1156,37 @@ pub mod tests {
         assert!(filter.token_at_depth(3).is_none());
     }
 
+    #[test]
+    fn datetime() {
+        let token = |s| Token::from(s);
+        assert_eq!(token("12.5").parse_finite_float_or_datetime().unwrap(), 12.5);
+        assert_eq!(token("2024-01-01").parse_finite_float_or_datetime().unwrap(), 1704067200.0);
+        assert_eq!(
+            token("2024-01-01T02:00:00+02:00").parse_finite_float_or_datetime().unwrap(),
+            1704067200.0
+        );
+
+        let now = parse_datetime("now").unwrap();
+        let week_ago = parse_datetime("now-1w").unwrap();
+        assert!((now - week_ago - 7.0 * 24.0 * 3600.0).abs() <= 1.0);
+        let in_an_hour = parse_datetime("now+1h").unwrap();
+        assert!((in_an_hour - now - 3600.0).abs() <= 1.0);
+
+        assert_eq!(parse_datetime("now-d"), None);
+        assert_eq!(parse_datetime("now-7y"), None);
+        assert_eq!(parse_datetime("now--7d"), None);
+        assert_eq!(parse_datetime("2024-13-01"), None);
+
+        insta::assert_snapshot!(token("2024-13-01").parse_finite_float_or_datetime().unwrap_err(), @r###"
+        The date `2024-13-01` is malformed, it must be an RFC 3339 date-time, a `YYYY-MM-DD` date or a date relative to `now` like `now-7d`.
+        1:11 2024-13-01
+        "###);
+        insta::assert_snapshot!(token("cheap").parse_finite_float_or_datetime().unwrap_err(), @r###"
+        invalid float literal
+        1:6 cheap
+        "###);
+    }
+
     #[test]
     fn token_from_str() {
         let s = "test string that should not be parsed";
@@ -7,8 +7,8 @@ use nom::{InputIter, InputLength, InputTake, Slice};
 
 use crate::error::{ExpectedValueKind, NomErrorExt};
 use crate::{
-    parse_geo, parse_geo_bounding_box, parse_geo_distance, parse_geo_point, parse_geo_radius,
-    Error, ErrorKind, IResult, Span, Token,
+    looks_like_datetime, parse_geo, parse_geo_bounding_box, parse_geo_distance, parse_geo_point,
+    parse_geo_radius, Error, ErrorKind, IResult, Span, Token,
 };
 
 /// This function goes through all characters in the [Span] if it finds any escaped character (`\`).
@@ -64,6 +64,19 @@ pub fn word_not_keyword<'a>(input: Span<'a>) -> IResult<Token<'a>> {
     Ok((input, word))
 }
 
+// date           = ("now" | digit{4} "-") (alphanumeric | _ | - | . | : | +)*
+fn date_word<'a>(input: Span<'a>) -> IResult<Token<'a>> {
+    let (rest, word) = take_while1(is_date_component)(input)?;
+    if looks_like_datetime(word.fragment()) {
+        Ok((rest, word.into()))
+    } else {
+        Err(nom::Err::Error(Error::new_from_kind(
+            input,
+            ErrorKind::InternalError(nom::error::ErrorKind::TakeWhile1),
+        )))
+    }
+}
+
 // word           = {tag}
 pub fn word_exact<'a, 'b: 'a>(tag: &'b str) -> impl Fn(Span<'a>) -> IResult<'a, Token<'a>> {
     move |input| {
@@ -80,7 +93,7 @@ pub fn word_exact<'a, 'b: 'a>(tag: &'b str) -> impl Fn(Span<'a>) -> IResult<'a,
     }
 }
 
-/// value          = WS* ( word | singleQuoted | doubleQuoted) WS+
+/// value          = WS* ( word | date | singleQuoted | doubleQuoted) WS+
 pub fn parse_value(input: Span) -> IResult<Token> {
     // to get better diagnostic message we are going to strip the left whitespaces from the input right now
     let (input, _) = take_while(char::is_whitespace)(input)?;
@@ -141,6 +154,7 @@ pub fn parse_value(input: Span) -> IResult<Token> {
         alt((
             delimited(char('\''), cut(|input| quoted_by('\'', input)), cut(char('\''))),
             delimited(char('"'), cut(|input| quoted_by('"', input)), cut(char('"'))),
+            date_word,
             word_not_keyword,
         )),
         multispace0,
@@ -191,10 +205,41 @@ pub fn parse_value(input: Span) -> IResult<Token> {
     }
 }
 
+/// regex          = WS* "/" .* all but unescaped slashes "/" WS*
+pub fn parse_regex(input: Span) -> IResult<Token> {
+    let (input, _) = take_while(char::is_whitespace)(input)?;
+
+    let (input, regex) = terminated(
+        delimited(char('/'), cut(|input| quoted_by('/', input)), cut(char('/'))),
+        multispace0,
+    )(input)
+    .map_err(|e: nom::Err<Error>| {
+        e.map_err(|_| {
+            let error_word = take_till::<_, _, Error>(is_syntax_component);
+            Error::new_from_kind(error_word(input).unwrap().1, ErrorKind::ExpectedRegex)
+        })
+    })
+    .map_err(|e| {
+        e.map_fail(|failure| {
+            if matches!(failure.kind(), ErrorKind::Char(_)) {
+                Error::new_from_kind(input, ErrorKind::MissingClosingDelimiter(failure.char()))
+            } else {
+                failure
+            }
+        })
+    })?;
+
+    Ok((input, regex))
+}
+
 fn is_value_component(c: char) -> bool {
     c.is_alphanumeric() || ['_', '-', '.'].contains(&c)
 }
 
+fn is_date_component(c: char) -> bool {
+    is_value_component(c) || [':', '+'].contains(&c)
+}
+
 fn is_syntax_component(c: char) -> bool {
     c.is_whitespace() || ['(', ')', '=', '<', '>', '!'].contains(&c)
 }
@@ -213,7 +258,9 @@ fn is_keyword(s: &str) -> bool {
             | "EMPTY"
             | "CONTAINS"
             | "STARTS"
+            | "ENDS"
             | "WITH"
+            | "MATCHES"
             | "_geoRadius"
             | "_geoBoundingBox"
     )
@@ -232,6 +279,11 @@ pub mod test {
             ("channel", rtok("", "channel")),
             (".private", rtok("", ".private")),
             ("I-love-kebab", rtok("", "I-love-kebab")),
+            ("2024-01-01T00:00:00+02:00", rtok("", "2024-01-01T00:00:00+02:00")),
+            ("now-7d", rtok("", "now-7d")),
+            // the `:` and `+` are only part of the dates
+            ("c++", rtok("", "c")),
+            ("title:rust", rtok("", "title")),
             ("but_snakes_is_also_good", rtok("", "but_snakes_is_also_good")),
             ("parens(", rtok("", "parens")),
             ("parens)", rtok("", "parens")),
@@ -8,7 +8,7 @@ The main function of the autobatcher is [`next_autobatch`].
 use std::ops::ControlFlow::{self, Break, Continue};
 
 use meilisearch_types::milli::update::IndexDocumentsMethod::{
-    self, ReplaceDocuments, UpdateDocuments,
+    self, PatchDocuments, ReplaceDocuments, UpdateDocuments,
 };
 use meilisearch_types::tasks::TaskId;
 
@@ -328,6 +328,18 @@ impl BatchKind {
                     operation_ids,
                 })
             }
+            (
+                BatchKind::DocumentOperation { method: PatchDocuments, allow_index_creation, primary_key: _, mut operation_ids },
+                K::DocumentImport { method: PatchDocuments, primary_key: pk, .. },
+            ) => {
+                operation_ids.push(id);
+                Continue(BatchKind::DocumentOperation {
+                    method: PatchDocuments,
+                    allow_index_creation,
+                    primary_key: pk,
+                    operation_ids,
+                })
+            }
             (
                 BatchKind::DocumentOperation { method, allow_index_creation, primary_key, mut operation_ids },
                 K::DocumentDeletion { by_filter: false },
@@ -515,6 +527,19 @@ impl BatchKind {
                     operation_ids,
                 })
             }
+            (
+                BatchKind::SettingsAndDocumentOperation { settings_ids, method: PatchDocuments, allow_index_creation, primary_key: _, mut operation_ids },
+                K::DocumentImport { method: PatchDocuments, primary_key: pk2, .. },
+            ) => {
+                operation_ids.push(id);
+                Continue(BatchKind::SettingsAndDocumentOperation {
+                    settings_ids,
+                    method: PatchDocuments,
+                    allow_index_creation,
+                    primary_key: pk2,
+                    operation_ids,
+                })
+            }
             // But we can't batch a settings and a doc op with another doc op
             // this MUST be AFTER the two previous branch
             (
@@ -619,6 +644,7 @@ mod tests {
             content_file: Uuid::new_v4(),
             documents_count: 0,
             allow_index_creation,
+            auto_generate_ids: false,
         }
     }
 
@@ -695,6 +721,10 @@ mod tests {
         debug_snapshot!(autobatch_from(false,None,  [doc_imp(UpdateDocuments, false, None)]), @"Some((DocumentOperation { method: UpdateDocuments, allow_index_creation: false, primary_key: None, operation_ids: [0] }, false))");
         debug_snapshot!(autobatch_from(false,None,  [doc_imp(UpdateDocuments, false, None), doc_imp(UpdateDocuments, false, None), doc_imp(UpdateDocuments, false, None)]), @"Some((DocumentOperation { method: UpdateDocuments, allow_index_creation: false, primary_key: None, operation_ids: [0, 1, 2] }, false))");
 
+        // we can autobatch one or multiple `PatchDocuments` together but not with other methods.
+        debug_snapshot!(autobatch_from(true, None, [doc_imp(PatchDocuments, true, None), doc_imp(PatchDocuments, true, None)]), @"Some((DocumentOperation { method: PatchDocuments, allow_index_creation: true, primary_key: None, operation_ids: [0, 1] }, true))");
+        debug_snapshot!(autobatch_from(true, None, [doc_imp(PatchDocuments, true, None), doc_imp(UpdateDocuments, true, None)]), @"Some((DocumentOperation { method: PatchDocuments, allow_index_creation: true, primary_key: None, operation_ids: [0] }, true))");
+
         // we can autobatch one or multiple DocumentDeletion together
         debug_snapshot!(autobatch_from(true, None, [doc_del()]), @"Some((DocumentDeletion { deletion_ids: [0], includes_by_filter: false }, false))");
         debug_snapshot!(autobatch_from(true, None, [doc_del(), doc_del(), doc_del()]), @"Some((DocumentDeletion { deletion_ids: [0, 1, 2], includes_by_filter: false }, false))");
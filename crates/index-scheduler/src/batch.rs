@@ -33,13 +33,16 @@ use meilisearch_types::error::Code;
 use meilisearch_types::heed::{RoTxn, RwTxn};
 use meilisearch_types::milli::documents::{obkv_to_object, DocumentsBatchReader, PrimaryKey};
 use meilisearch_types::milli::heed::CompactionOption;
+use meilisearch_types::milli::index::DocumentChangeKind;
 use meilisearch_types::milli::update::new::indexer::{self, UpdateByFunction};
 use meilisearch_types::milli::update::{IndexDocumentsMethod, Settings as MilliSettings};
 use meilisearch_types::milli::vector::parsed_vectors::{
     ExplicitVectors, VectorOrArrayOfVectors, RESERVED_VECTORS_FIELD_NAME,
 };
 use meilisearch_types::milli::{self, Filter, ThreadPoolNoAbortBuilder};
-use meilisearch_types::settings::{apply_settings_to_builder, Settings, Unchecked};
+use meilisearch_types::settings::{
+    apply_settings_to_builder, settings_version, Settings, Unchecked, MAX_SETTINGS_HISTORY_LEN,
+};
 use meilisearch_types::tasks::{
     Details, IndexSwap, Kind, KindWithContent, Status, Task, TaskProgress,
 };
@@ -93,7 +96,7 @@ pub(crate) enum Batch {
 
 #[derive(Debug)]
 pub(crate) enum DocumentOperation {
-    Add(Uuid),
+    Add { content_file: Uuid, auto_generate_ids: bool },
     Delete(Vec<String>),
 }
 
@@ -338,10 +341,12 @@ impl IndexScheduler {
                         KindWithContent::DocumentAdditionOrUpdate {
                             content_file,
                             documents_count,
+                            auto_generate_ids,
                             ..
                         } => {
                             documents_counts.push(documents_count);
-                            operations.push(DocumentOperation::Add(content_file));
+                            operations
+                                .push(DocumentOperation::Add { content_file, auto_generate_ids });
                         }
                         KindWithContent::DocumentDeletion { ref documents_ids, .. } => {
                             documents_counts.push(documents_ids.len() as u64);
@@ -1275,6 +1280,21 @@ impl IndexScheduler {
 
         match operation {
             IndexOperation::DocumentClear { mut tasks, .. } => {
+                // The first document clear is the one that effectively deletes the documents.
+                if let Some(task) = tasks.first() {
+                    let mut deleted_documents = Vec::new();
+                    if index.changelog(index_wtxn)? {
+                        for entry in index.external_documents_ids().iter(index_wtxn)? {
+                            let (external_id, _docid) = entry?;
+                            deleted_documents.push(external_id.to_string());
+                        }
+                    }
+                    let changes = deleted_documents.iter().map(|external_id| {
+                        (task.uid, external_id.as_str(), DocumentChangeKind::Deletion)
+                    });
+                    self.record_document_changes(index_wtxn, index, changes)?;
+                }
+
                 let count = milli::update::ClearDocuments::new(index_wtxn, index).execute()?;
 
                 let mut first_clear_found = false;
@@ -1307,7 +1327,7 @@ impl IndexScheduler {
                 // to a fresh thread.
                 let mut content_files = Vec::new();
                 for operation in &operations {
-                    if let DocumentOperation::Add(content_uuid) = operation {
+                    if let DocumentOperation::Add { content_file: content_uuid, .. } = operation {
                         let content_file = self.file_store.get_update(*content_uuid)?;
                         let mmap = unsafe { memmap2::Mmap::map(&content_file)? };
                         if !mmap.is_empty() {
@@ -1326,9 +1346,13 @@ impl IndexScheduler {
                 let embedders = self.embedders(embedders)?;
                 for operation in operations {
                     match operation {
-                        DocumentOperation::Add(_content_uuid) => {
+                        DocumentOperation::Add { auto_generate_ids, .. } => {
                             let mmap = content_files_iter.next().unwrap();
-                            indexer.add_documents(mmap)?;
+                            if auto_generate_ids {
+                                indexer.add_documents_generating_ids(mmap)?;
+                            } else {
+                                indexer.add_documents(mmap)?;
+                            }
                         }
                         DocumentOperation::Delete(document_ids) => {
                             let document_ids: bumpalo::collections::vec::Vec<_> = document_ids
@@ -1376,6 +1400,9 @@ impl IndexScheduler {
                             Some(Details::DocumentAdditionOrUpdate {
                                 received_documents,
                                 indexed_documents: Some(stats.document_count),
+                                skipped_documents: stats.skipped_documents,
+                                generated_document_ids: stats.generated_document_ids,
+                                rejected_documents: stats.rejected_documents,
                             })
                         }
                         Some(Details::DocumentDeletion { provided_ids, .. }) => {
@@ -1409,6 +1436,12 @@ impl IndexScheduler {
                     })
                     .unwrap()?;
 
+                    let changes =
+                        document_changes.changelog().map(|(external_id, kind, payload_index)| {
+                            (tasks[payload_index].uid, external_id, kind)
+                        });
+                    self.record_document_changes(index_wtxn, index, changes)?;
+
                     tracing::info!(indexing_result = ?addition, processed_in = ?started_processing_at.elapsed(), "document indexing done");
                 }
 
@@ -1488,10 +1521,12 @@ impl IndexScheduler {
                         }
                     };
 
+                    let mut changes = Vec::new();
                     pool.install(|| {
                         let indexer =
                             UpdateByFunction::new(candidates, context.clone(), code.clone());
-                        let document_changes = indexer.into_changes(&primary_key)?;
+                        let document_changes =
+                            indexer.into_changes(index, index_wtxn, &primary_key)?;
                         let embedders = index.embedding_configs(index_wtxn)?;
                         let embedders = self.embedders(embedders)?;
 
@@ -1508,10 +1543,16 @@ impl IndexScheduler {
                             &send_progress,
                         )?;
 
+                        changes = document_changes.into_changelog();
                         Result::Ok(())
                     })
                     .unwrap()?;
 
+                    let changes = changes
+                        .iter()
+                        .map(|(external_id, kind)| (task.uid, external_id.as_str(), *kind));
+                    self.record_document_changes(index_wtxn, index, changes)?;
+
                     // tracing::info!(indexing_result = ?addition, processed_in = ?started_processing_at.elapsed(), "document indexing done");
                 }
 
@@ -1544,8 +1585,11 @@ impl IndexScheduler {
             IndexOperation::DocumentDeletion { mut tasks, index_uid: _ } => {
                 let mut to_delete = RoaringBitmap::new();
                 let external_documents_ids = index.external_documents_ids();
+                let changelog = index.changelog(index_wtxn)?;
+                let mut deleted_by_task = Vec::new();
 
                 for task in tasks.iter_mut() {
+                    let previously_deleted = changelog.then(|| to_delete.clone());
                     let before = to_delete.len();
                     task.status = Status::Succeeded;
 
@@ -1613,12 +1657,24 @@ impl IndexScheduler {
                         }
                         _ => unreachable!(),
                     }
+
+                    if let Some(previously_deleted) = previously_deleted {
+                        deleted_by_task.push((task.uid, &to_delete - previously_deleted));
+                    }
                 }
 
                 if to_delete.is_empty() {
                     return Ok(tasks);
                 }
 
+                // The external ids must be retrieved before the documents are deleted.
+                let mut deleted_documents = Vec::new();
+                for (task_uid, docids) in deleted_by_task {
+                    for external_id in index.external_id_of(index_wtxn, docids)? {
+                        deleted_documents.push((task_uid, external_id?));
+                    }
+                }
+
                 let rtxn = index.read_txn()?;
                 let db_fields_ids_map = index.fields_ids_map(&rtxn)?;
                 let mut new_fields_ids_map = db_fields_ids_map.clone();
@@ -1662,12 +1718,24 @@ impl IndexScheduler {
                     })
                     .unwrap()?;
 
+                    let changes = deleted_documents.iter().map(|(task_uid, external_id)| {
+                        (*task_uid, external_id.as_str(), DocumentChangeKind::Deletion)
+                    });
+                    self.record_document_changes(index_wtxn, index, changes)?;
+
                     // tracing::info!(indexing_result = ?addition, processed_in = ?started_processing_at.elapsed(), "document indexing done");
                 }
 
                 Ok(tasks)
             }
             IndexOperation::Settings { index_uid: _, settings, mut tasks } => {
+                // The first version of the history is made of the settings the index had
+                // before its first update so that it is always possible to roll back to them.
+                let mut history = index.settings_history(index_wtxn)?;
+                if history.is_empty() {
+                    history.push(settings_version(index, index_wtxn, 0, None)?);
+                }
+
                 let indexer_config = self.index_mapper.indexer_config();
                 let mut builder = milli::update::Settings::new(index_wtxn, index, indexer_config);
 
@@ -1686,6 +1754,13 @@ impl IndexScheduler {
                     || must_stop_processing.get(),
                 )?;
 
+                let version = history.last().map_or(0, |last| last.version + 1);
+                let task_uid = tasks.last().map(|task| task.uid);
+                history.push(settings_version(index, index_wtxn, version, task_uid)?);
+                let overflow = history.len().saturating_sub(MAX_SETTINGS_HISTORY_LEN);
+                history.drain(..overflow);
+                index.put_settings_history(index_wtxn, &history)?;
+
                 Ok(tasks)
             }
             IndexOperation::SettingsAndDocumentOperation {
@@ -1753,6 +1828,30 @@ impl IndexScheduler {
         }
     }
 
+    /// Records the document changes made by the tasks in the changelog of the index, if it is
+    /// enabled, and removes the changes made by the tasks that are no longer in the task queue.
+    fn record_document_changes<'a>(
+        &self,
+        index_wtxn: &mut RwTxn,
+        index: &Index,
+        changes: impl IntoIterator<Item = (TaskId, &'a str, DocumentChangeKind)>,
+    ) -> Result<()> {
+        if !index.changelog(index_wtxn)? {
+            return Ok(());
+        }
+
+        for (task_uid, external_id, kind) in changes {
+            index.put_document_change(index_wtxn, task_uid, external_id, kind)?;
+        }
+
+        let rtxn = self.env.read_txn()?;
+        if let Some(first_task_id) = self.first_task_id(&rtxn)? {
+            index.delete_document_changes_before(index_wtxn, first_task_id)?;
+        }
+
+        Ok(())
+    }
+
     /// Delete each given task from all the databases (if it is deleteable).
     ///
     /// Return the number of tasks that were actually deleted.
@@ -1785,10 +1884,18 @@ impl IndexScheduler {
             affected_indexes.extend(task.indexes().into_iter().map(|x| x.to_owned()));
             affected_statuses.insert(task.status);
             affected_kinds.insert(task.kind.as_kind());
-            // Note: don't delete the persisted task data since
+            // Note: we usually don't need to delete the persisted task data since
             // we can only delete succeeded, failed, and canceled tasks.
             // In each of those cases, the persisted data is supposed to
-            // have been deleted already.
+            // have been deleted already, unless we keep it for the followers.
+            if self.keep_update_files {
+                if let Err(e) = self.delete_persisted_task_data(&task) {
+                    tracing::error!(
+                        "Failure to delete the content files associated with task {}. Error: {e}",
+                        task.uid
+                    );
+                }
+            }
             utils::remove_task_datetime(wtxn, self.enqueued_at, task.enqueued_at, task.uid)?;
             if let Some(started_at) = task.started_at {
                 utils::remove_task_datetime(wtxn, self.started_at, started_at, task.uid)?;
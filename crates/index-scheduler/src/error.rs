@@ -111,6 +111,12 @@ pub enum Error {
     TaskNotFound(TaskId),
     #[error("Batch `{0}` not found.")]
     BatchNotFound(BatchId),
+    #[error("Task `{0}` has no content file.")]
+    TaskContentFileNotFound(TaskId),
+    #[error("This instance is a follower of `{0}` and cannot register tasks. Send your write operations to the leader instead.")]
+    FollowerInstance(String),
+    #[error("This instance is in read-only mode and cannot register tasks. Disable the `readOnly` experimental feature to perform write operations again.")]
+    ReadOnlyInstance,
     #[error("Query parameters to filter the tasks to delete are missing. Available query parameters are: `uids`, `indexUids`, `statuses`, `types`, `canceledBy`, `beforeEnqueuedAt`, `afterEnqueuedAt`, `beforeStartedAt`, `afterStartedAt`, `beforeFinishedAt`, `afterFinishedAt`.")]
     TaskDeletionWithEmptyQuery,
     #[error("Query parameters to filter the tasks to cancel are missing. Available query parameters are: `uids`, `indexUids`, `statuses`, `types`, `canceledBy`, `beforeEnqueuedAt`, `afterEnqueuedAt`, `beforeStartedAt`, `afterStartedAt`, `beforeFinishedAt`, `afterFinishedAt`.")]
@@ -185,6 +191,9 @@ impl Error {
             | Error::InvalidIndexUid { .. }
             | Error::TaskNotFound(_)
             | Error::BatchNotFound(_)
+            | Error::TaskContentFileNotFound(_)
+            | Error::FollowerInstance(_)
+            | Error::ReadOnlyInstance
             | Error::TaskDeletionWithEmptyQuery
             | Error::TaskCancelationWithEmptyQuery
             | Error::AbortedTask
@@ -231,6 +240,9 @@ impl ErrorCode for Error {
             Error::InvalidIndexUid { .. } => Code::InvalidIndexUid,
             Error::TaskNotFound(_) => Code::TaskNotFound,
             Error::BatchNotFound(_) => Code::BatchNotFound,
+            Error::TaskContentFileNotFound(_) => Code::TaskContentFileNotFound,
+            Error::FollowerInstance(_) => Code::FollowerInstance,
+            Error::ReadOnlyInstance => Code::ReadOnlyInstance,
             Error::TaskDeletionWithEmptyQuery => Code::MissingTaskFilters,
             Error::TaskCancelationWithEmptyQuery => Code::MissingTaskFilters,
             // TODO: not sure of the Code to use
@@ -87,7 +87,8 @@ impl RoFeatures {
             Ok(())
         } else {
             Err(FeatureNotEnabledError {
-                disabled_action: "Using `CONTAINS` or `STARTS WITH` in a filter",
+                disabled_action:
+                    "Using `CONTAINS`, `STARTS WITH`, `ENDS WITH`, `MATCHES` or `==` in a filter",
                 feature: "contains filter",
                 issue_link: "https://github.com/orgs/meilisearch/discussions/763",
             }
@@ -105,11 +106,13 @@ impl FeatureData {
         let txn = env.read_txn()?;
         let persisted_features: RuntimeTogglableFeatures =
             runtime_features_db.get(&txn, EXPERIMENTAL_FEATURES)?.unwrap_or_default();
-        let InstanceTogglableFeatures { metrics, logs_route, contains_filter } = instance_features;
+        let InstanceTogglableFeatures { metrics, logs_route, contains_filter, read_only } =
+            instance_features;
         let runtime = Arc::new(RwLock::new(RuntimeTogglableFeatures {
             metrics: metrics || persisted_features.metrics,
             logs_route: logs_route || persisted_features.logs_route,
             contains_filter: contains_filter || persisted_features.contains_filter,
+            read_only: read_only || persisted_features.read_only,
             ..persisted_features
         }));
 
@@ -20,6 +20,8 @@ pub fn snapshot_index_scheduler(scheduler: &IndexScheduler) -> String {
     let IndexScheduler {
         autobatching_enabled,
         cleanup_enabled: _,
+        keep_update_files: _,
+        leader_url: _,
         must_stop_processing: _,
         processing_tasks,
         file_store,
@@ -256,8 +258,22 @@ fn snapshot_details(d: &Details) -> String {
         Details::DocumentAdditionOrUpdate {
             received_documents,
             indexed_documents,
+            skipped_documents,
+            generated_document_ids,
+            rejected_documents,
         } => {
-            format!("{{ received_documents: {received_documents}, indexed_documents: {indexed_documents:?} }}")
+            let mut details = format!("{{ received_documents: {received_documents}, indexed_documents: {indexed_documents:?}");
+            if let Some(skipped_documents) = skipped_documents {
+                write!(details, ", skipped_documents: {skipped_documents}").unwrap();
+            }
+            if let Some(generated_document_ids) = generated_document_ids {
+                write!(details, ", generated_document_ids: {generated_document_ids:?}").unwrap();
+            }
+            if let Some(rejected_documents) = rejected_documents {
+                write!(details, ", rejected_documents: {rejected_documents:?}").unwrap();
+            }
+            details.push_str(" }");
+            details
         }
         Details::DocumentEdition {
             deleted_documents,
@@ -297,6 +297,12 @@ pub struct IndexSchedulerOptions {
     /// Set to `true` iff the index scheduler is allowed to automatically
     /// delete the finished tasks when there are too many tasks.
     pub cleanup_enabled: bool,
+    /// Set to `true` to keep the update files of the processed tasks until
+    /// the tasks themselves are deleted, so that followers can download them.
+    pub keep_update_files: bool,
+    /// The URL of the leader instance this instance replicates its tasks from.
+    /// When set, no task can be registered through the regular API.
+    pub leader_url: Option<String>,
     /// The maximum number of tasks stored in the task queue before starting
     /// to auto schedule task deletions.
     pub max_number_of_tasks: usize,
@@ -375,6 +381,12 @@ pub struct IndexScheduler {
     /// Whether we should automatically cleanup the task queue or not.
     pub(crate) cleanup_enabled: bool,
 
+    /// Whether we should keep the update files after processing their tasks.
+    pub(crate) keep_update_files: bool,
+
+    /// The URL of the leader we are following, if any.
+    pub(crate) leader_url: Option<String>,
+
     /// The max number of tasks allowed before the scheduler starts to delete
     /// the finished tasks automatically.
     pub(crate) max_number_of_tasks: usize,
@@ -452,6 +464,8 @@ impl IndexScheduler {
             wake_up: self.wake_up.clone(),
             autobatching_enabled: self.autobatching_enabled,
             cleanup_enabled: self.cleanup_enabled,
+            keep_update_files: self.keep_update_files,
+            leader_url: self.leader_url.clone(),
             max_number_of_tasks: self.max_number_of_tasks,
             max_number_of_batched_tasks: self.max_number_of_batched_tasks,
             snapshots_path: self.snapshots_path.clone(),
@@ -577,6 +591,8 @@ impl IndexScheduler {
             wake_up: Arc::new(SignalEvent::auto(true)),
             autobatching_enabled: options.autobatching_enabled,
             cleanup_enabled: options.cleanup_enabled,
+            keep_update_files: options.keep_update_files,
+            leader_url: options.leader_url,
             max_number_of_tasks: options.max_number_of_tasks,
             max_number_of_batched_tasks: options.max_number_of_batched_tasks,
             dumps_path: options.dumps_path,
@@ -1435,6 +1451,28 @@ impl IndexScheduler {
         kind: KindWithContent,
         task_id: Option<TaskId>,
         dry_run: bool,
+    ) -> Result<Task> {
+        if let Some(leader_url) = &self.leader_url {
+            return Err(Error::FollowerInstance(leader_url.clone()));
+        }
+        if self.features().runtime_features().read_only {
+            return Err(Error::ReadOnlyInstance);
+        }
+        self.register_task(kind, task_id, dry_run)
+    }
+
+    /// Register a task replicated from the leader, with the same uid it has on the leader.
+    ///
+    /// Contrary to [`register`](IndexScheduler::register), this method is allowed on followers.
+    pub fn register_replicated(&self, kind: KindWithContent, task_id: TaskId) -> Result<Task> {
+        self.register_task(kind, Some(task_id), false)
+    }
+
+    fn register_task(
+        &self,
+        kind: KindWithContent,
+        task_id: Option<TaskId>,
+        dry_run: bool,
     ) -> Result<Task> {
         let mut wtxn = self.env.write_txn()?;
 
@@ -1560,6 +1598,17 @@ impl IndexScheduler {
         Ok(self.file_store.compute_total_size()?)
     }
 
+    /// Returns the update file associated with the given uuid.
+    pub fn update_file(&self, uuid: Uuid) -> Result<std::fs::File> {
+        Ok(self.file_store.get_update(uuid)?)
+    }
+
+    /// Returns the uid that will be given to the next registered task.
+    pub fn next_task_uid(&self) -> Result<TaskId> {
+        let rtxn = self.env.read_txn()?;
+        self.next_task_id(&rtxn)
+    }
+
     /// Delete a file from the index scheduler.
     ///
     /// Counterpart to the [`create_update_file`](IndexScheduler::create_update_file) method.
@@ -1587,6 +1636,11 @@ impl IndexScheduler {
             self.breakpoint(Breakpoint::Start);
         }
 
+        // In read-only mode the tasks stay enqueued until the mode is disabled.
+        if self.features().runtime_features().read_only {
+            return Ok(TickOutcome::WaitForSignal);
+        }
+
         if self.cleanup_enabled {
             self.cleanup_task_queue()?;
         }
@@ -1750,27 +1804,30 @@ impl IndexScheduler {
 
         wtxn.commit().map_err(Error::HeedTransaction)?;
 
-        // Once the tasks are committed, we should delete all the update files associated ASAP to avoid leaking files in case of a restart
-        tracing::debug!("Deleting the update files");
-
-        //We take one read transaction **per thread**. Then, every thread is going to pull out new IDs from the roaring bitmap with the help of an atomic shared index into the bitmap
-        let idx = AtomicU32::new(0);
-        (0..current_num_threads()).into_par_iter().try_for_each(|_| -> Result<()> {
-            let rtxn = self.read_txn()?;
-            while let Some(id) = ids.select(idx.fetch_add(1, Ordering::Relaxed)) {
-                let task = self
-                    .get_task(&rtxn, id)
-                    .map_err(|e| Error::TaskDatabaseUpdate(Box::new(e)))?
-                    .ok_or(Error::CorruptedTaskQueue)?;
-                if let Err(e) = self.delete_persisted_task_data(&task) {
-                    tracing::error!(
-                        "Failure to delete the content files associated with task {}. Error: {e}",
-                        task.uid
-                    );
+        // Once the tasks are committed, we should delete all the update files associated ASAP to avoid leaking files in case of a restart.
+        // When followers may still need them, the files are only deleted along with their tasks.
+        if !self.keep_update_files {
+            tracing::debug!("Deleting the update files");
+
+            //We take one read transaction **per thread**. Then, every thread is going to pull out new IDs from the roaring bitmap with the help of an atomic shared index into the bitmap
+            let idx = AtomicU32::new(0);
+            (0..current_num_threads()).into_par_iter().try_for_each(|_| -> Result<()> {
+                let rtxn = self.read_txn()?;
+                while let Some(id) = ids.select(idx.fetch_add(1, Ordering::Relaxed)) {
+                    let task = self
+                        .get_task(&rtxn, id)
+                        .map_err(|e| Error::TaskDatabaseUpdate(Box::new(e)))?
+                        .ok_or(Error::CorruptedTaskQueue)?;
+                    if let Err(e) = self.delete_persisted_task_data(&task) {
+                        tracing::error!(
+                            "Failure to delete the content files associated with task {}. Error: {e}",
+                            task.uid
+                        );
+                    }
                 }
-            }
-            Ok(())
-        })?;
+                Ok(())
+            })?;
+        }
 
         // We shouldn't crash the tick function if we can't send data to the webhook.
         let _ = self.notify_webhook(&ids);
@@ -1929,6 +1986,8 @@ impl IndexScheduler {
     pub fn put_runtime_features(&self, features: RuntimeTogglableFeatures) -> Result<()> {
         let wtxn = self.env.write_txn().map_err(Error::HeedTransaction)?;
         self.features.put_runtime_features(wtxn, features)?;
+        // the read-only mode may have been disabled, the scheduler must check its queue again
+        self.wake_up.signal();
         Ok(())
     }
 
@@ -2071,6 +2130,7 @@ impl<'a> Dump<'a> {
                     method,
                     documents_count,
                     allow_index_creation,
+                    auto_generate_ids,
                 } => KindWithContent::DocumentAdditionOrUpdate {
                     index_uid: task.index_uid.ok_or(Error::CorruptedDump)?,
                     primary_key,
@@ -2078,6 +2138,7 @@ impl<'a> Dump<'a> {
                     content_file: content_uuid.ok_or(Error::CorruptedDump)?,
                     documents_count,
                     allow_index_creation,
+                    auto_generate_ids,
                 },
                 KindDump::DocumentDeletion { documents_ids } => KindWithContent::DocumentDeletion {
                     documents_ids,
@@ -2311,6 +2372,8 @@ mod tests {
                 indexer_config,
                 autobatching_enabled: true,
                 cleanup_enabled: true,
+                keep_update_files: false,
+                leader_url: None,
                 max_number_of_tasks: 1_000_000,
                 max_number_of_batched_tasks: usize::MAX,
                 instance_features: Default::default(),
@@ -2381,6 +2444,7 @@ mod tests {
             content_file: Uuid::from_u128(content_file_uuid),
             documents_count,
             allow_index_creation: true,
+            auto_generate_ids: false,
         }
     }
 
@@ -2852,6 +2916,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -2897,6 +2962,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -2937,6 +3003,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -3003,6 +3070,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -3069,6 +3137,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -3320,6 +3389,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -3494,6 +3564,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: true,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -3545,6 +3616,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: true,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -3598,6 +3670,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: true,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -3717,6 +3790,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: true,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -3772,6 +3846,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: true,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -4717,6 +4792,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -4759,6 +4835,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -4819,6 +4896,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: false,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -4871,6 +4949,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: false,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -4933,6 +5012,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: false,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -5000,6 +5080,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: false,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -5072,6 +5153,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -5129,6 +5211,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -5185,6 +5268,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: true,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -5248,6 +5332,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: true,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -5308,6 +5393,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: true,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -5394,6 +5480,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: true,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -5481,6 +5568,7 @@ mod tests {
                         content_file: uuid,
                         documents_count,
                         allow_index_creation: true,
+                        auto_generate_ids: false,
                     },
                     None,
                     false,
@@ -6192,6 +6280,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -6266,6 +6355,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -6388,6 +6478,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -6519,6 +6610,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: true,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -6621,6 +6713,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: false,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -6796,6 +6889,7 @@ mod tests {
                     content_file: uuid,
                     documents_count,
                     allow_index_creation: false,
+                    auto_generate_ids: false,
                 },
                 None,
                 false,
@@ -220,6 +220,7 @@ InvalidApiKeyName                     , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyOffset                   , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyUid                      , InvalidRequest       , BAD_REQUEST ;
 InvalidContentType                    , InvalidRequest       , UNSUPPORTED_MEDIA_TYPE ;
+InvalidDocumentAutoGenerateId         , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentCsvDelimiter           , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentFields                 , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentRetrieveVectors        , InvalidRequest       , BAD_REQUEST ;
@@ -231,9 +232,14 @@ InvalidVectorDimensions               , InvalidRequest       , BAD_REQUEST ;
 InvalidVectorsType                    , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentId                     , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentLimit                  , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentVersion                , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentOffset                 , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentPatch                  , InvalidRequest       , BAD_REQUEST ;
+InvalidDocumentSchema                 , InvalidRequest       , BAD_REQUEST ;
+InvalidComputedField                  , InvalidRequest       , BAD_REQUEST ;
 InvalidEmbedder                       , InvalidRequest       , BAD_REQUEST ;
 InvalidHybridQuery                    , InvalidRequest       , BAD_REQUEST ;
+InvalidIndexChangesSince              , InvalidRequest       , BAD_REQUEST ;
 InvalidIndexLimit                     , InvalidRequest       , BAD_REQUEST ;
 InvalidIndexOffset                    , InvalidRequest       , BAD_REQUEST ;
 InvalidIndexPrimaryKey                , InvalidRequest       , BAD_REQUEST ;
@@ -263,6 +269,8 @@ InvalidSearchCropMarker               , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchFacets                   , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchSemanticRatio            , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchLocales                  , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchFieldScopedQuery         , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchRankingRules             , InvalidRequest       , BAD_REQUEST ;
 InvalidFacetSearchFacetName           , InvalidRequest       , BAD_REQUEST ;
 InvalidSimilarId                      , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchFilter                   , InvalidRequest       , BAD_REQUEST ;
@@ -305,6 +313,15 @@ InvalidSettingsDictionary             , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsSynonyms               , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsTypoTolerance          , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsLocalizedAttributes    , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsRollbackVersion        , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsChangelog              , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsVersionAttribute       , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsAutoGenerateIds        , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsDocumentSchema         , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsIngestPipeline         , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsComputedFields         , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsDateAttributes         , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsSearchableAttributesWeights, InvalidRequest       , BAD_REQUEST ;
 InvalidState                          , Internal             , INTERNAL_SERVER_ERROR ;
 InvalidStoreFile                      , Internal             , INTERNAL_SERVER_ERROR ;
 InvalidSwapDuplicateIndexFound        , InvalidRequest       , BAD_REQUEST ;
@@ -327,6 +344,7 @@ IoError                               , System               , UNPROCESSABLE_ENT
 FeatureNotEnabled                     , InvalidRequest       , BAD_REQUEST ;
 MalformedPayload                      , InvalidRequest       , BAD_REQUEST ;
 MaxFieldsLimitExceeded                , InvalidRequest       , BAD_REQUEST ;
+FollowerInstance                      , InvalidRequest       , FORBIDDEN ;
 MissingApiKeyActions                  , InvalidRequest       , BAD_REQUEST ;
 MissingApiKeyExpiresAt                , InvalidRequest       , BAD_REQUEST ;
 MissingApiKeyIndexes                  , InvalidRequest       , BAD_REQUEST ;
@@ -342,8 +360,11 @@ MissingSwapIndexes                    , InvalidRequest       , BAD_REQUEST ;
 MissingTaskFilters                    , InvalidRequest       , BAD_REQUEST ;
 NoSpaceLeftOnDevice                   , System               , UNPROCESSABLE_ENTITY;
 PayloadTooLarge                       , InvalidRequest       , PAYLOAD_TOO_LARGE ;
+ReadOnlyInstance                      , InvalidRequest       , FORBIDDEN ;
+SettingsVersionNotFound               , InvalidRequest       , NOT_FOUND ;
 TooManySearchRequests                 , System               , SERVICE_UNAVAILABLE ;
 TaskNotFound                          , InvalidRequest       , NOT_FOUND ;
+TaskContentFileNotFound               , InvalidRequest       , NOT_FOUND ;
 BatchNotFound                         , InvalidRequest       , NOT_FOUND ;
 TooManyOpenFiles                      , System               , UNPROCESSABLE_ENTITY ;
 TooManyVectors                        , InvalidRequest       , BAD_REQUEST ;
@@ -389,6 +410,12 @@ impl ErrorCode for milli::Error {
                     UserError::InvalidDocumentId { .. } | UserError::TooManyDocumentIds { .. } => {
                         Code::InvalidDocumentId
                     }
+                    UserError::InvalidDocumentVersion { .. } => Code::InvalidDocumentVersion,
+                    UserError::InvalidDocumentPatch { .. }
+                    | UserError::UnsupportedDocumentPatch => Code::InvalidDocumentPatch,
+                    UserError::InvalidDocumentSchema { .. } => Code::InvalidDocumentSchema,
+                    UserError::InvalidComputedField { .. } => Code::InvalidSettingsComputedFields,
+                    UserError::ComputedFieldRuntimeError { .. } => Code::InvalidComputedField,
                     UserError::MissingDocumentField(_) => Code::InvalidDocumentFields,
                     UserError::InvalidFieldForSource { .. }
                     | UserError::MissingFieldForSource { .. }
@@ -409,6 +436,7 @@ impl ErrorCode for milli::Error {
                         Code::IndexPrimaryKeyMultipleCandidatesFound
                     }
                     UserError::PrimaryKeyCannotBeChanged(_) => Code::IndexPrimaryKeyAlreadyExists,
+                    UserError::InvalidCompositePrimaryKey { .. } => Code::InvalidIndexPrimaryKey,
                     UserError::InvalidDistinctAttribute { .. } => Code::InvalidSearchDistinct,
                     UserError::SortRankingRuleMissing => Code::InvalidSearchSort,
                     UserError::InvalidFacetsDistribution { .. } => Code::InvalidSearchFacets,
@@ -8,6 +8,7 @@ pub struct RuntimeTogglableFeatures {
     pub logs_route: bool,
     pub edit_documents_by_function: bool,
     pub contains_filter: bool,
+    pub read_only: bool,
 }
 
 #[derive(Default, Debug, Clone, Copy)]
@@ -15,4 +16,5 @@ pub struct InstanceTogglableFeatures {
     pub metrics: bool,
     pub logs_route: bool,
     pub contains_filter: bool,
+    pub read_only: bool,
 }
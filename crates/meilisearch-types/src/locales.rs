@@ -8,6 +8,9 @@ use serde::{Deserialize, Serialize};
 pub struct LocalizedAttributesRuleView {
     pub attribute_patterns: Vec<String>,
     pub locales: Vec<Locale>,
+    #[deserr(default)]
+    #[serde(default)]
+    pub stemming: bool,
 }
 
 impl From<LocalizedAttributesRule> for LocalizedAttributesRuleView {
@@ -15,6 +18,7 @@ impl From<LocalizedAttributesRule> for LocalizedAttributesRuleView {
         Self {
             attribute_patterns: rule.attribute_patterns,
             locales: rule.locales.into_iter().map(|l| l.into()).collect(),
+            stemming: rule.stemming,
         }
     }
 }
@@ -24,6 +28,7 @@ impl From<LocalizedAttributesRuleView> for LocalizedAttributesRule {
         Self {
             attribute_patterns: view.attribute_patterns,
             locales: view.locales.into_iter().map(|l| l.into()).collect(),
+            stemming: view.stemming,
         }
     }
 }
@@ -8,16 +8,21 @@ use std::str::FromStr;
 
 use deserr::{DeserializeError, Deserr, ErrorKind, MergeWithError, ValuePointerRef};
 use fst::IntoStreamer;
-use milli::index::IndexEmbeddingConfig;
+use milli::document_schema::{DocumentSchema, FieldSchema, FieldType, SchemaMode};
+use milli::index::{IndexEmbeddingConfig, SettingsVersion};
+use milli::ingest_pipeline::IngestProcessor;
+use milli::phonetic::PhoneticAlgorithm;
 use milli::proximity::ProximityPrecision;
 use milli::update::Setting;
 use milli::{Criterion, CriterionError, Index, DEFAULT_VALUES_PER_FACET};
 use serde::{Deserialize, Serialize, Serializer};
+use time::OffsetDateTime;
 
 use crate::deserr::DeserrJsonError;
 use crate::error::deserr_codes::*;
 use crate::facet_values_sort::FacetValuesSort;
 use crate::locales::LocalizedAttributesRuleView;
+use crate::tasks::TaskId;
 
 /// The maximum number of results that the engine
 /// will be able to return in one search call.
@@ -97,6 +102,12 @@ pub struct TypoSettings {
     #[serde(default, skip_serializing_if = "Setting::is_not_set")]
     #[deserr(default)]
     pub disable_on_attributes: Setting<BTreeSet<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default)]
+    pub phonetic_on_attributes: Setting<BTreeSet<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsTypoTolerance>)]
+    pub phonetic_algorithm: Setting<PhoneticAlgorithmView>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Deserr)]
@@ -134,6 +145,20 @@ impl MergeWithError<milli::CriterionError> for DeserrJsonError<InvalidSettingsRa
     }
 }
 
+impl MergeWithError<milli::CriterionError> for DeserrJsonError<InvalidSearchRankingRules> {
+    fn merge(
+        _self_: Option<Self>,
+        other: milli::CriterionError,
+        merge_location: ValuePointerRef,
+    ) -> ControlFlow<Self, Self> {
+        Self::error::<Infallible>(
+            None,
+            ErrorKind::Unexpected { msg: other.to_string() },
+            merge_location,
+        )
+    }
+}
+
 /// Holds all the settings for an index. `T` can either be `Checked` if they represents settings
 /// whose validity is guaranteed, or `Unchecked` if they need to be validated. In the later case, a
 /// call to `check` will return a `Settings<Checked>` from a `Settings<Unchecked>`.
@@ -202,6 +227,30 @@ pub struct Settings<T> {
     #[serde(default, skip_serializing_if = "Setting::is_not_set")]
     #[deserr(default, error = DeserrJsonError<InvalidSettingsLocalizedAttributes>)]
     pub localized_attributes: Setting<Vec<LocalizedAttributesRuleView>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsChangelog>)]
+    pub changelog: Setting<bool>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsVersionAttribute>)]
+    pub version_attribute: Setting<String>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsAutoGenerateIds>)]
+    pub auto_generate_ids: Setting<bool>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsDocumentSchema>)]
+    pub document_schema: Setting<DocumentSchemaView>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsIngestPipeline>)]
+    pub ingest_pipeline: Setting<Vec<IngestProcessor>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsComputedFields>)]
+    pub computed_fields: Setting<BTreeMap<String, String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsDateAttributes>)]
+    pub date_attributes: Setting<BTreeSet<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default, error = DeserrJsonError<InvalidSettingsSearchableAttributesWeights>)]
+    pub searchable_attributes_weights: Setting<BTreeMap<String, u16>>,
 
     #[serde(skip)]
     #[deserr(skip)]
@@ -266,6 +315,14 @@ impl Settings<Checked> {
             embedders: Setting::Reset,
             search_cutoff_ms: Setting::Reset,
             localized_attributes: Setting::Reset,
+            changelog: Setting::Reset,
+            version_attribute: Setting::Reset,
+            auto_generate_ids: Setting::Reset,
+            document_schema: Setting::Reset,
+            ingest_pipeline: Setting::Reset,
+            computed_fields: Setting::Reset,
+            date_attributes: Setting::Reset,
+            searchable_attributes_weights: Setting::Reset,
             _kind: PhantomData,
         }
     }
@@ -290,6 +347,14 @@ impl Settings<Checked> {
             embedders,
             search_cutoff_ms,
             localized_attributes: localized_attributes_rules,
+            changelog,
+            version_attribute,
+            auto_generate_ids,
+            document_schema,
+            ingest_pipeline,
+            computed_fields,
+            date_attributes,
+            searchable_attributes_weights,
             _kind,
         } = self;
 
@@ -312,6 +377,14 @@ impl Settings<Checked> {
             embedders,
             search_cutoff_ms,
             localized_attributes: localized_attributes_rules,
+            changelog,
+            version_attribute,
+            auto_generate_ids,
+            document_schema,
+            ingest_pipeline,
+            computed_fields,
+            date_attributes,
+            searchable_attributes_weights,
             _kind: PhantomData,
         }
     }
@@ -360,6 +433,14 @@ impl Settings<Unchecked> {
             embedders: self.embedders,
             search_cutoff_ms: self.search_cutoff_ms,
             localized_attributes: self.localized_attributes,
+            changelog: self.changelog,
+            version_attribute: self.version_attribute,
+            auto_generate_ids: self.auto_generate_ids,
+            document_schema: self.document_schema,
+            ingest_pipeline: self.ingest_pipeline,
+            computed_fields: self.computed_fields,
+            date_attributes: self.date_attributes,
+            searchable_attributes_weights: self.searchable_attributes_weights,
             _kind: PhantomData,
         }
     }
@@ -420,6 +501,17 @@ impl Settings<Unchecked> {
                 .localized_attributes
                 .clone()
                 .or(self.localized_attributes.clone()),
+            changelog: other.changelog.or(self.changelog),
+            version_attribute: other.version_attribute.clone().or(self.version_attribute.clone()),
+            auto_generate_ids: other.auto_generate_ids.or(self.auto_generate_ids),
+            document_schema: other.document_schema.clone().or(self.document_schema.clone()),
+            ingest_pipeline: other.ingest_pipeline.clone().or(self.ingest_pipeline.clone()),
+            computed_fields: other.computed_fields.clone().or(self.computed_fields.clone()),
+            date_attributes: other.date_attributes.clone().or(self.date_attributes.clone()),
+            searchable_attributes_weights: other
+                .searchable_attributes_weights
+                .clone()
+                .or(self.searchable_attributes_weights.clone()),
             embedders: match (self.embedders.clone(), other.embedders.clone()) {
                 (Setting::NotSet, set) | (set, Setting::NotSet) => set,
                 (Setting::Set(_) | Setting::Reset, Setting::Reset) => Setting::Reset,
@@ -469,6 +561,14 @@ pub fn apply_settings_to_builder(
         embedders,
         search_cutoff_ms,
         localized_attributes: localized_attributes_rules,
+        changelog,
+        version_attribute,
+        auto_generate_ids,
+        document_schema,
+        ingest_pipeline,
+        computed_fields,
+        date_attributes,
+        searchable_attributes_weights,
         _kind,
     } = settings;
 
@@ -602,6 +702,20 @@ pub fn apply_settings_to_builder(
                 Setting::Reset => builder.reset_exact_attributes(),
                 Setting::NotSet => (),
             }
+
+            match value.phonetic_on_attributes {
+                Setting::Set(ref attributes) => {
+                    builder.set_phonetic_attributes(attributes.iter().cloned().collect())
+                }
+                Setting::Reset => builder.reset_phonetic_attributes(),
+                Setting::NotSet => (),
+            }
+
+            match value.phonetic_algorithm {
+                Setting::Set(algorithm) => builder.set_phonetic_algorithm(algorithm.into()),
+                Setting::Reset => builder.reset_phonetic_algorithm(),
+                Setting::NotSet => (),
+            }
         }
         Setting::Reset => {
             // all typo settings need to be reset here.
@@ -610,6 +724,8 @@ pub fn apply_settings_to_builder(
             builder.reset_min_word_len_two_typos();
             builder.reset_exact_words();
             builder.reset_exact_attributes();
+            builder.reset_phonetic_attributes();
+            builder.reset_phonetic_algorithm();
         }
         Setting::NotSet => (),
     }
@@ -657,6 +773,54 @@ pub fn apply_settings_to_builder(
         Setting::Reset => builder.reset_search_cutoff(),
         Setting::NotSet => (),
     }
+
+    match changelog {
+        Setting::Set(enabled) => builder.set_changelog(*enabled),
+        Setting::Reset => builder.reset_changelog(),
+        Setting::NotSet => (),
+    }
+
+    match version_attribute {
+        Setting::Set(ref attr) => builder.set_version_attribute(attr.clone()),
+        Setting::Reset => builder.reset_version_attribute(),
+        Setting::NotSet => (),
+    }
+
+    match auto_generate_ids {
+        Setting::Set(enabled) => builder.set_auto_generate_ids(*enabled),
+        Setting::Reset => builder.reset_auto_generate_ids(),
+        Setting::NotSet => (),
+    }
+
+    match document_schema {
+        Setting::Set(schema) => builder.set_document_schema(schema.clone().into()),
+        Setting::Reset => builder.reset_document_schema(),
+        Setting::NotSet => (),
+    }
+
+    match ingest_pipeline {
+        Setting::Set(pipeline) => builder.set_ingest_pipeline(pipeline.clone()),
+        Setting::Reset => builder.reset_ingest_pipeline(),
+        Setting::NotSet => (),
+    }
+
+    match computed_fields {
+        Setting::Set(fields) => builder.set_computed_fields(fields.clone()),
+        Setting::Reset => builder.reset_computed_fields(),
+        Setting::NotSet => (),
+    }
+
+    match date_attributes {
+        Setting::Set(names) => builder.set_date_fields(names.clone()),
+        Setting::Reset => builder.reset_date_fields(),
+        Setting::NotSet => (),
+    }
+
+    match searchable_attributes_weights {
+        Setting::Set(weights) => builder.set_searchable_attributes_weights(weights.clone()),
+        Setting::Reset => builder.reset_searchable_attributes_weights(),
+        Setting::NotSet => (),
+    }
 }
 
 pub enum SecretPolicy {
@@ -712,11 +876,17 @@ pub fn settings(
 
     let disabled_attributes = index.exact_attributes(rtxn)?.into_iter().map(String::from).collect();
 
+    let phonetic_attributes =
+        index.phonetic_attributes(rtxn)?.into_iter().map(String::from).collect();
+    let phonetic_algorithm = index.phonetic_algorithm(rtxn)?.unwrap_or_default();
+
     let typo_tolerance = TypoSettings {
         enabled: Setting::Set(index.authorize_typos(rtxn)?),
         min_word_size_for_typos: Setting::Set(min_typo_word_len),
         disable_on_words: Setting::Set(disabled_words),
         disable_on_attributes: Setting::Set(disabled_attributes),
+        phonetic_on_attributes: Setting::Set(phonetic_attributes),
+        phonetic_algorithm: Setting::Set(phonetic_algorithm.into()),
     };
 
     let faceting = FacetingSettings {
@@ -791,6 +961,20 @@ pub fn settings(
             Some(rules) => Setting::Set(rules.into_iter().map(|r| r.into()).collect()),
             None => Setting::Reset,
         },
+        changelog: Setting::Set(index.changelog(rtxn)?),
+        version_attribute: match index.version_attribute(rtxn)? {
+            Some(field) => Setting::Set(field.to_string()),
+            None => Setting::Reset,
+        },
+        auto_generate_ids: Setting::Set(index.auto_generate_ids(rtxn)?),
+        document_schema: match index.document_schema(rtxn)? {
+            Some(schema) => Setting::Set(schema.into()),
+            None => Setting::Reset,
+        },
+        ingest_pipeline: Setting::Set(index.ingest_pipeline(rtxn)?),
+        computed_fields: Setting::Set(index.computed_fields(rtxn)?),
+        date_attributes: Setting::Set(index.date_fields(rtxn)?),
+        searchable_attributes_weights: Setting::Set(index.searchable_attributes_weights(rtxn)?),
         _kind: PhantomData,
     };
 
@@ -801,6 +985,63 @@ pub fn settings(
     Ok(settings)
 }
 
+/// The maximum number of versions kept in the settings history of an index.
+pub const MAX_SETTINGS_HISTORY_LEN: usize = 100;
+
+/// Returns the current settings of the index, ready to be stored in its settings history.
+pub fn settings_version(
+    index: &Index,
+    rtxn: &crate::heed::RoTxn,
+    version: u32,
+    task_uid: Option<TaskId>,
+) -> Result<SettingsVersion, milli::Error> {
+    let settings = settings(index, rtxn, SecretPolicy::RevealSecrets)?.into_unchecked();
+    let settings = match serde_json::to_value(settings).map_err(milli::InternalError::SerdeJson)? {
+        serde_json::Value::Object(settings) => settings,
+        _ => unreachable!("settings are always serialized as a JSON object"),
+    };
+
+    Ok(SettingsVersion { version, task_uid, recorded_at: OffsetDateTime::now_utc(), settings })
+}
+
+impl Settings<Unchecked> {
+    /// Returns the settings that must be applied to restore the given version.
+    pub fn from_version(version: &SettingsVersion) -> Result<Self, serde_json::Error> {
+        let mut settings: Self =
+            serde_json::from_value(serde_json::Value::Object(version.settings.clone()))?;
+        // An index without embedders doesn't return them in its settings,
+        // restoring such a version must remove the embedders added since.
+        if settings.embedders.is_not_set() {
+            settings.embedders = Setting::Reset;
+        }
+        Ok(settings)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsVersionView {
+    pub version: u32,
+    pub task_uid: Option<TaskId>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub recorded_at: OffsetDateTime,
+    pub settings: Settings<Unchecked>,
+}
+
+impl SettingsVersionView {
+    pub fn new(version: &SettingsVersion) -> Result<Self, serde_json::Error> {
+        let mut settings: Settings<Unchecked> =
+            serde_json::from_value(serde_json::Value::Object(version.settings.clone()))?;
+        settings.hide_secrets();
+        Ok(Self {
+            version: version.version,
+            task_uid: version.task_uid,
+            recorded_at: version.recorded_at,
+            settings,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Deserr)]
 #[deserr(try_from(&String) = FromStr::from_str -> CriterionError)]
 pub enum RankingRuleView {
@@ -823,6 +1064,8 @@ pub enum RankingRuleView {
     Asc(String),
     /// Sorted by the decreasing value of the field specified.
     Desc(String),
+    /// Sorted by the decreasing score computed by the expression specified.
+    Function(String),
 }
 impl Serialize for RankingRuleView {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -879,6 +1122,7 @@ impl From<Criterion> for RankingRuleView {
             Criterion::Exactness => RankingRuleView::Exactness,
             Criterion::Asc(x) => RankingRuleView::Asc(x),
             Criterion::Desc(x) => RankingRuleView::Desc(x),
+            Criterion::Function(x) => RankingRuleView::Function(x),
         }
     }
 }
@@ -893,6 +1137,7 @@ impl From<RankingRuleView> for Criterion {
             RankingRuleView::Exactness => Criterion::Exactness,
             RankingRuleView::Asc(x) => Criterion::Asc(x),
             RankingRuleView::Desc(x) => Criterion::Desc(x),
+            RankingRuleView::Function(x) => Criterion::Function(x),
         }
     }
 }
@@ -923,6 +1168,130 @@ impl From<ProximityPrecisionView> for ProximityPrecision {
     }
 }
 
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Deserr, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(error = DeserrJsonError<InvalidSettingsTypoTolerance>, rename_all = camelCase, deny_unknown_fields)]
+pub enum PhoneticAlgorithmView {
+    #[default]
+    DoubleMetaphone,
+    BeiderMorse,
+}
+
+impl From<PhoneticAlgorithm> for PhoneticAlgorithmView {
+    fn from(value: PhoneticAlgorithm) -> Self {
+        match value {
+            PhoneticAlgorithm::DoubleMetaphone => PhoneticAlgorithmView::DoubleMetaphone,
+            PhoneticAlgorithm::BeiderMorse => PhoneticAlgorithmView::BeiderMorse,
+        }
+    }
+}
+impl From<PhoneticAlgorithmView> for PhoneticAlgorithm {
+    fn from(value: PhoneticAlgorithmView) -> Self {
+        match value {
+            PhoneticAlgorithmView::DoubleMetaphone => PhoneticAlgorithm::DoubleMetaphone,
+            PhoneticAlgorithmView::BeiderMorse => PhoneticAlgorithm::BeiderMorse,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserr, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(error = DeserrJsonError<InvalidSettingsDocumentSchema>, rename_all = camelCase, deny_unknown_fields)]
+pub struct DocumentSchemaView {
+    #[serde(default)]
+    #[deserr(default)]
+    pub mode: SchemaModeView,
+    #[serde(default)]
+    #[deserr(default)]
+    pub fields: BTreeMap<String, FieldSchemaView>,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Deserr, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(error = DeserrJsonError<InvalidSettingsDocumentSchema>, rename_all = camelCase, deny_unknown_fields)]
+pub enum SchemaModeView {
+    #[default]
+    Strict,
+    Lenient,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserr, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(error = DeserrJsonError<InvalidSettingsDocumentSchema>, rename_all = camelCase, deny_unknown_fields)]
+pub struct FieldSchemaView {
+    #[serde(rename = "type")]
+    #[deserr(rename = "type")]
+    pub field_type: FieldTypeView,
+    #[serde(default)]
+    #[deserr(default)]
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserr, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[deserr(error = DeserrJsonError<InvalidSettingsDocumentSchema>, rename_all = camelCase, deny_unknown_fields)]
+pub enum FieldTypeView {
+    String,
+    Number,
+    Boolean,
+    Array,
+    Object,
+}
+
+impl From<DocumentSchema> for DocumentSchemaView {
+    fn from(value: DocumentSchema) -> Self {
+        let DocumentSchema { mode, fields } = value;
+        DocumentSchemaView {
+            mode: match mode {
+                SchemaMode::Strict => SchemaModeView::Strict,
+                SchemaMode::Lenient => SchemaModeView::Lenient,
+            },
+            fields: fields.into_iter().map(|(name, field)| (name, field.into())).collect(),
+        }
+    }
+}
+
+impl From<DocumentSchemaView> for DocumentSchema {
+    fn from(value: DocumentSchemaView) -> Self {
+        let DocumentSchemaView { mode, fields } = value;
+        DocumentSchema {
+            mode: match mode {
+                SchemaModeView::Strict => SchemaMode::Strict,
+                SchemaModeView::Lenient => SchemaMode::Lenient,
+            },
+            fields: fields.into_iter().map(|(name, field)| (name, field.into())).collect(),
+        }
+    }
+}
+
+impl From<FieldSchema> for FieldSchemaView {
+    fn from(value: FieldSchema) -> Self {
+        let FieldSchema { field_type, required } = value;
+        let field_type = match field_type {
+            FieldType::String => FieldTypeView::String,
+            FieldType::Number => FieldTypeView::Number,
+            FieldType::Boolean => FieldTypeView::Boolean,
+            FieldType::Array => FieldTypeView::Array,
+            FieldType::Object => FieldTypeView::Object,
+        };
+        FieldSchemaView { field_type, required }
+    }
+}
+
+impl From<FieldSchemaView> for FieldSchema {
+    fn from(value: FieldSchemaView) -> Self {
+        let FieldSchemaView { field_type, required } = value;
+        let field_type = match field_type {
+            FieldTypeView::String => FieldType::String,
+            FieldTypeView::Number => FieldType::Number,
+            FieldTypeView::Boolean => FieldType::Boolean,
+            FieldTypeView::Array => FieldType::Array,
+            FieldTypeView::Object => FieldType::Object,
+        };
+        FieldSchema { field_type, required }
+    }
+}
+
 #[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
 pub struct WildcardSetting(Setting<Vec<String>>);
 
@@ -989,6 +1358,14 @@ pub(crate) mod test {
             pagination: Setting::NotSet,
             embedders: Setting::NotSet,
             localized_attributes: Setting::NotSet,
+            changelog: Setting::NotSet,
+            version_attribute: Setting::NotSet,
+            auto_generate_ids: Setting::NotSet,
+            document_schema: Setting::NotSet,
+            ingest_pipeline: Setting::NotSet,
+            computed_fields: Setting::NotSet,
+            date_attributes: Setting::NotSet,
+            searchable_attributes_weights: Setting::NotSet,
             search_cutoff_ms: Setting::NotSet,
             _kind: PhantomData::<Unchecked>,
         };
@@ -1018,6 +1395,14 @@ pub(crate) mod test {
             pagination: Setting::NotSet,
             embedders: Setting::NotSet,
             localized_attributes: Setting::NotSet,
+            changelog: Setting::NotSet,
+            version_attribute: Setting::NotSet,
+            auto_generate_ids: Setting::NotSet,
+            document_schema: Setting::NotSet,
+            ingest_pipeline: Setting::NotSet,
+            computed_fields: Setting::NotSet,
+            date_attributes: Setting::NotSet,
+            searchable_attributes_weights: Setting::NotSet,
             search_cutoff_ms: Setting::NotSet,
             _kind: PhantomData::<Unchecked>,
         };
@@ -58,6 +58,12 @@ pub struct DetailsView {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub indexed_documents: Option<Option<u64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped_documents: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generated_document_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rejected_documents: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited_documents: Option<Option<u64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub primary_key: Option<Option<String>>,
@@ -102,6 +108,24 @@ impl DetailsView {
                 }
                 (Some(Some(left)), Some(Some(right))) => Some(Some(left + right)),
             },
+            skipped_documents: match (self.skipped_documents, other.skipped_documents) {
+                (None, None) => None,
+                (None, Some(doc)) | (Some(doc), None) => Some(doc),
+                (Some(left), Some(right)) => Some(left + right),
+            },
+            generated_document_ids: match (
+                &self.generated_document_ids,
+                &other.generated_document_ids,
+            ) {
+                (None, None) => None,
+                (None, Some(ids)) | (Some(ids), None) => Some(ids.clone()),
+                (Some(left), Some(right)) => Some(left.iter().chain(right).cloned().collect()),
+            },
+            rejected_documents: match (&self.rejected_documents, &other.rejected_documents) {
+                (None, None) => None,
+                (None, Some(rejected)) | (Some(rejected), None) => Some(rejected.clone()),
+                (Some(left), Some(right)) => Some(left.iter().chain(right).cloned().collect()),
+            },
             edited_documents: match (self.edited_documents, other.edited_documents) {
                 (None, None) => None,
                 (None, Some(None)) | (Some(None), None) | (Some(None), Some(None)) => Some(None),
@@ -211,13 +235,20 @@ impl DetailsView {
 impl From<Details> for DetailsView {
     fn from(details: Details) -> Self {
         match details {
-            Details::DocumentAdditionOrUpdate { received_documents, indexed_documents } => {
-                DetailsView {
-                    received_documents: Some(received_documents),
-                    indexed_documents: Some(indexed_documents),
-                    ..DetailsView::default()
-                }
-            }
+            Details::DocumentAdditionOrUpdate {
+                received_documents,
+                indexed_documents,
+                skipped_documents,
+                generated_document_ids,
+                rejected_documents,
+            } => DetailsView {
+                received_documents: Some(received_documents),
+                indexed_documents: Some(indexed_documents),
+                skipped_documents,
+                generated_document_ids,
+                rejected_documents,
+                ..DetailsView::default()
+            },
             Details::DocumentEdition {
                 deleted_documents,
                 edited_documents,
@@ -155,6 +155,9 @@ pub enum KindWithContent {
         content_file: Uuid,
         documents_count: u64,
         allow_index_creation: bool,
+        /// Generate an id for the documents without one, whatever the setting of the index.
+        #[serde(default)]
+        auto_generate_ids: bool,
     },
     DocumentDeletion {
         index_uid: String,
@@ -270,6 +273,9 @@ impl KindWithContent {
                 Some(Details::DocumentAdditionOrUpdate {
                     received_documents: *documents_count,
                     indexed_documents: None,
+                    skipped_documents: None,
+                    generated_document_ids: None,
+                    rejected_documents: None,
                 })
             }
             KindWithContent::DocumentEdition { index_uid: _, filter_expr, context, function } => {
@@ -327,6 +333,9 @@ impl KindWithContent {
                 Some(Details::DocumentAdditionOrUpdate {
                     received_documents: *documents_count,
                     indexed_documents: Some(0),
+                    skipped_documents: None,
+                    generated_document_ids: None,
+                    rejected_documents: None,
                 })
             }
             KindWithContent::DocumentEdition { index_uid: _, filter_expr, context, function } => {
@@ -387,6 +396,9 @@ impl From<&KindWithContent> for Option<Details> {
                 Some(Details::DocumentAdditionOrUpdate {
                     received_documents: *documents_count,
                     indexed_documents: None,
+                    skipped_documents: None,
+                    generated_document_ids: None,
+                    rejected_documents: None,
                 })
             }
             KindWithContent::DocumentEdition { .. } => None,
@@ -596,6 +608,12 @@ pub enum Details {
     DocumentAdditionOrUpdate {
         received_documents: u64,
         indexed_documents: Option<u64>,
+        #[serde(default)]
+        skipped_documents: Option<u64>,
+        #[serde(default)]
+        generated_document_ids: Option<Vec<String>>,
+        #[serde(default)]
+        rejected_documents: Option<Vec<String>>,
     },
     SettingsUpdate {
         settings: Box<Settings<Unchecked>>,
@@ -643,8 +661,21 @@ impl Details {
     pub fn to_failed(&self) -> Self {
         let mut details = self.clone();
         match &mut details {
-            Self::DocumentAdditionOrUpdate { indexed_documents, .. } => {
-                *indexed_documents = Some(0)
+            Self::DocumentAdditionOrUpdate {
+                indexed_documents,
+                skipped_documents,
+                generated_document_ids,
+                rejected_documents,
+                ..
+            } => {
+                *indexed_documents = Some(0);
+                *skipped_documents = skipped_documents.map(|_| 0);
+                if let Some(ids) = generated_document_ids {
+                    ids.clear();
+                }
+                if let Some(rejected) = rejected_documents {
+                    rejected.clear();
+                }
             }
             Self::DocumentEdition { edited_documents, .. } => *edited_documents = Some(0),
             Self::DocumentDeletion { deleted_documents, .. } => *deleted_documents = Some(0),
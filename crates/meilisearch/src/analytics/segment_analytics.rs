@@ -182,6 +182,7 @@ impl SegmentAnalytics {
 #[derive(Debug, Clone, Serialize)]
 struct Infos {
     env: String,
+    read_only: bool,
     experimental_contains_filter: bool,
     experimental_vector_store: bool,
     experimental_enable_metrics: bool,
@@ -191,6 +192,7 @@ struct Infos {
     experimental_nb_searches_per_core: usize,
     experimental_logs_mode: LogMode,
     experimental_replication_parameters: bool,
+    experimental_replication_follower: bool,
     experimental_enable_logs_route: bool,
     experimental_reduce_indexing_memory_usage: bool,
     experimental_max_number_of_batched_tasks: usize,
@@ -236,6 +238,8 @@ impl Infos {
             experimental_nb_searches_per_core,
             experimental_logs_mode,
             experimental_replication_parameters,
+            experimental_replication_leader_url,
+            experimental_replication_leader_api_key: _,
             experimental_enable_logs_route,
             experimental_reduce_indexing_memory_usage,
             experimental_max_number_of_batched_tasks,
@@ -267,6 +271,7 @@ impl Infos {
             indexer_options,
             config_file_path,
             no_analytics: _,
+            read_only,
         } = options;
 
         let schedule_snapshot = match schedule_snapshot {
@@ -283,12 +288,14 @@ impl Infos {
             logs_route,
             edit_documents_by_function,
             contains_filter,
+            read_only: read_only_at_runtime,
         } = features;
 
         // We're going to override every sensible information.
         // We consider information sensible if it contains a path, an address, or a key.
         Self {
             env,
+            read_only: read_only | read_only_at_runtime,
             experimental_contains_filter: experimental_contains_filter | contains_filter,
             experimental_vector_store: vector_store,
             experimental_edit_documents_by_function: edit_documents_by_function,
@@ -298,6 +305,7 @@ impl Infos {
             experimental_nb_searches_per_core: experimental_nb_searches_per_core.into(),
             experimental_logs_mode,
             experimental_replication_parameters,
+            experimental_replication_follower: experimental_replication_leader_url.is_some(),
             experimental_enable_logs_route: experimental_enable_logs_route | logs_route,
             experimental_reduce_indexing_memory_usage,
             gpu_enabled: meilisearch_types::milli::vector::is_cuda_enabled(),
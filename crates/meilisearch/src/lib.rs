@@ -7,6 +7,7 @@ pub mod extractors;
 pub mod metrics;
 pub mod middleware;
 pub mod option;
+pub mod replication;
 pub mod routes;
 pub mod search;
 pub mod search_queue;
@@ -307,7 +308,10 @@ fn open_or_create_database_unchecked(
             enable_mdb_writemap: opt.experimental_reduce_indexing_memory_usage,
             indexer_config: (&opt.indexer_options).try_into()?,
             autobatching_enabled: true,
-            cleanup_enabled: !opt.experimental_replication_parameters,
+            cleanup_enabled: !opt.experimental_replication_parameters
+                && opt.experimental_replication_leader_url.is_none(),
+            keep_update_files: opt.experimental_replication_parameters,
+            leader_url: opt.experimental_replication_leader_url.clone(),
             max_number_of_tasks: 1_000_000,
             max_number_of_batched_tasks: opt.experimental_max_number_of_batched_tasks,
             index_growth_amount: byte_unit::Byte::from_str("10GiB").unwrap().as_u64() as usize,
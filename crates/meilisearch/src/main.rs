@@ -16,8 +16,8 @@ use meilisearch::analytics::Analytics;
 use meilisearch::option::LogMode;
 use meilisearch::search_queue::SearchQueue;
 use meilisearch::{
-    analytics, create_app, setup_meilisearch, LogRouteHandle, LogRouteType, LogStderrHandle,
-    LogStderrType, Opt, SubscriberForSecondLayer,
+    analytics, create_app, replication, setup_meilisearch, LogRouteHandle, LogRouteType,
+    LogStderrHandle, LogStderrType, Opt, SubscriberForSecondLayer,
 };
 use meilisearch_auth::{generate_master_key, AuthController, MASTER_KEY_MIN_SIZE};
 use mimalloc::MiMalloc;
@@ -124,6 +124,14 @@ async fn try_main() -> anyhow::Result<()> {
 
     let (index_scheduler, auth_controller) = setup_meilisearch(&opt)?;
 
+    if let Some(leader_url) = &opt.experimental_replication_leader_url {
+        replication::spawn_follower(
+            index_scheduler.clone(),
+            leader_url.clone(),
+            opt.experimental_replication_leader_api_key.clone(),
+        )?;
+    }
+
     let analytics =
         analytics::Analytics::new(&opt, index_scheduler.clone(), auth_controller.clone()).await;
 
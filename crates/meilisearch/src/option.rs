@@ -30,6 +30,7 @@ const MEILI_ENV: &str = "MEILI_ENV";
 const MEILI_TASK_WEBHOOK_URL: &str = "MEILI_TASK_WEBHOOK_URL";
 const MEILI_TASK_WEBHOOK_AUTHORIZATION_HEADER: &str = "MEILI_TASK_WEBHOOK_AUTHORIZATION_HEADER";
 const MEILI_NO_ANALYTICS: &str = "MEILI_NO_ANALYTICS";
+const MEILI_READ_ONLY: &str = "MEILI_READ_ONLY";
 const MEILI_HTTP_PAYLOAD_SIZE_LIMIT: &str = "MEILI_HTTP_PAYLOAD_SIZE_LIMIT";
 const MEILI_SSL_CERT_PATH: &str = "MEILI_SSL_CERT_PATH";
 const MEILI_SSL_KEY_PATH: &str = "MEILI_SSL_KEY_PATH";
@@ -51,6 +52,9 @@ const MEILI_LOG_LEVEL: &str = "MEILI_LOG_LEVEL";
 const MEILI_EXPERIMENTAL_LOGS_MODE: &str = "MEILI_EXPERIMENTAL_LOGS_MODE";
 const MEILI_EXPERIMENTAL_REPLICATION_PARAMETERS: &str = "MEILI_EXPERIMENTAL_REPLICATION_PARAMETERS";
 const MEILI_EXPERIMENTAL_ENABLE_LOGS_ROUTE: &str = "MEILI_EXPERIMENTAL_ENABLE_LOGS_ROUTE";
+const MEILI_EXPERIMENTAL_REPLICATION_LEADER_URL: &str = "MEILI_EXPERIMENTAL_REPLICATION_LEADER_URL";
+const MEILI_EXPERIMENTAL_REPLICATION_LEADER_API_KEY: &str =
+    "MEILI_EXPERIMENTAL_REPLICATION_LEADER_API_KEY";
 const MEILI_EXPERIMENTAL_CONTAINS_FILTER: &str = "MEILI_EXPERIMENTAL_CONTAINS_FILTER";
 const MEILI_EXPERIMENTAL_ENABLE_METRICS: &str = "MEILI_EXPERIMENTAL_ENABLE_METRICS";
 const MEILI_EXPERIMENTAL_SEARCH_QUEUE_SIZE: &str = "MEILI_EXPERIMENTAL_SEARCH_QUEUE_SIZE";
@@ -213,6 +217,14 @@ pub struct Opt {
     #[clap(long, env = MEILI_NO_ANALYTICS)]
     pub no_analytics: bool,
 
+    /// Starts the instance in read-only mode.
+    ///
+    /// Every route creating a task is refused and the enqueued tasks are not processed, while searching
+    /// and fetching documents keep working. It can be disabled at runtime through the `/experimental-features` route.
+    #[serde(default)]
+    #[clap(long, env = MEILI_READ_ONLY)]
+    pub read_only: bool,
+
     /// Sets the maximum size of the index. Value must be given in bytes or explicitly stating a base unit (for instance: 107374182400, '107.7Gb', or '107374 Mb').
     #[clap(skip = default_max_index_size())]
     #[serde(skip, default = "default_max_index_size")]
@@ -397,10 +409,24 @@ pub struct Opt {
     /// - /!\ Disable the automatic clean up of old processed tasks, you're in charge of that now
     /// - Lets you specify a custom task ID upon registering a task
     /// - Lets you execute dry-register a task (get an answer from the route but nothing is actually registered in meilisearch and it won't be processed)
+    /// - Keeps the update files of the processed tasks until the tasks are deleted, so that followers can download them
     #[clap(long, env = MEILI_EXPERIMENTAL_REPLICATION_PARAMETERS)]
     #[serde(default)]
     pub experimental_replication_parameters: bool,
 
+    /// Experimental follower mode, makes this instance replicate the task queue of the given leader instance.
+    ///
+    /// - The leader must be started with `--experimental-replication-parameters` so that it keeps the update files of its tasks
+    /// - The follower replays the tasks of its leader with the same task uids and refuses to register any other task
+    /// - The follower must be started from an empty database or from a snapshot of its leader
+    /// - The API keys of the embedders are never sent by the leader, the follower relies on its own environment (e.g. `MEILI_OPENAI_API_KEY`)
+    #[clap(long, env = MEILI_EXPERIMENTAL_REPLICATION_LEADER_URL)]
+    pub experimental_replication_leader_url: Option<String>,
+
+    /// The API key the follower uses to read the task queue of its leader.
+    #[clap(long, env = MEILI_EXPERIMENTAL_REPLICATION_LEADER_API_KEY)]
+    pub experimental_replication_leader_api_key: Option<String>,
+
     /// Experimental RAM reduction during indexing, do not use in production, see: <https://github.com/meilisearch/product/discussions/652>
     #[clap(long, env = MEILI_EXPERIMENTAL_REDUCE_INDEXING_MEMORY_USAGE)]
     #[serde(default)]
@@ -503,6 +529,7 @@ impl Opt {
             ignore_dump_if_db_exists: _,
             config_file_path: _,
             no_analytics,
+            read_only,
             experimental_contains_filter,
             experimental_enable_metrics,
             experimental_search_queue_size,
@@ -511,6 +538,8 @@ impl Opt {
             experimental_logs_mode,
             experimental_enable_logs_route,
             experimental_replication_parameters,
+            experimental_replication_leader_url,
+            experimental_replication_leader_api_key,
             experimental_reduce_indexing_memory_usage,
         } = self;
         export_to_env_if_not_present(MEILI_DB_PATH, db_path);
@@ -530,6 +559,7 @@ impl Opt {
         }
 
         export_to_env_if_not_present(MEILI_NO_ANALYTICS, no_analytics.to_string());
+        export_to_env_if_not_present(MEILI_READ_ONLY, read_only.to_string());
         export_to_env_if_not_present(
             MEILI_HTTP_PAYLOAD_SIZE_LIMIT,
             http_payload_size_limit.to_string(),
@@ -588,6 +618,15 @@ impl Opt {
             MEILI_EXPERIMENTAL_REPLICATION_PARAMETERS,
             experimental_replication_parameters.to_string(),
         );
+        if let Some(leader_url) = experimental_replication_leader_url {
+            export_to_env_if_not_present(MEILI_EXPERIMENTAL_REPLICATION_LEADER_URL, leader_url);
+        }
+        if let Some(leader_api_key) = experimental_replication_leader_api_key {
+            export_to_env_if_not_present(
+                MEILI_EXPERIMENTAL_REPLICATION_LEADER_API_KEY,
+                leader_api_key,
+            );
+        }
         export_to_env_if_not_present(
             MEILI_EXPERIMENTAL_ENABLE_LOGS_ROUTE,
             experimental_enable_logs_route.to_string(),
@@ -648,6 +687,7 @@ impl Opt {
             metrics: self.experimental_enable_metrics,
             logs_route: self.experimental_enable_logs_route,
             contains_filter: self.experimental_contains_filter,
+            read_only: self.read_only,
         }
     }
 }
@@ -0,0 +1,155 @@
+//! The follower side of the experimental replication.
+//!
+//! A follower periodically polls the task queue of its leader and registers
+//! every new task locally, with the same uid, so both instances process the
+//! exact same operations in the same order.
+
+use std::io::Write;
+use std::sync::Arc;
+use std::time::Duration;
+
+use index_scheduler::{IndexScheduler, TaskId};
+use meilisearch_types::milli::update::Setting;
+use meilisearch_types::tasks::KindWithContent;
+use serde::Deserialize;
+use tracing::{debug, error, info};
+
+/// The number of tasks fetched from the leader at once.
+const TASKS_PER_PAGE: u32 = 100;
+/// How long we wait before polling the leader again when there is nothing to replicate.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Deserialize)]
+struct TaskUid {
+    uid: TaskId,
+}
+
+#[derive(Debug, Deserialize)]
+struct TasksPage {
+    results: Vec<TaskUid>,
+}
+
+struct Follower {
+    client: reqwest::Client,
+    leader_url: String,
+    api_key: Option<String>,
+    index_scheduler: Arc<IndexScheduler>,
+    next_task_uid: TaskId,
+}
+
+/// Starts replicating the task queue of the leader in the background.
+pub fn spawn_follower(
+    index_scheduler: Arc<IndexScheduler>,
+    leader_url: String,
+    api_key: Option<String>,
+) -> anyhow::Result<()> {
+    let client = reqwest::Client::builder().connect_timeout(Duration::from_secs(10)).build()?;
+    let next_task_uid = index_scheduler.next_task_uid()?;
+    let leader_url = leader_url.trim_end_matches('/').to_string();
+    info!(%leader_url, next_task_uid, "Following the leader");
+
+    let follower = Follower { client, leader_url, api_key, index_scheduler, next_task_uid };
+    tokio::spawn(follower.run());
+    Ok(())
+}
+
+impl Follower {
+    async fn run(mut self) {
+        loop {
+            match self.replicate_next_tasks().await {
+                Ok(0) => tokio::time::sleep(POLL_INTERVAL).await,
+                Ok(count) => debug!(count, "Replicated tasks from the leader"),
+                Err(error) => {
+                    let next_task_uid = self.next_task_uid;
+                    error!(%error, next_task_uid, "Error while replicating the leader");
+                    tokio::time::sleep(POLL_INTERVAL).await;
+                }
+            }
+        }
+    }
+
+    /// Registers the tasks that were enqueued on the leader since the last call
+    /// and returns the number of tasks fetched.
+    async fn replicate_next_tasks(&mut self) -> anyhow::Result<usize> {
+        let url = format!(
+            "{}/tasks?from={}&reverse=true&limit={TASKS_PER_PAGE}",
+            self.leader_url, self.next_task_uid
+        );
+        let page: TasksPage = self.get(&url).await?.json().await?;
+
+        for TaskUid { uid } in &page.results {
+            self.replicate_task(*uid).await?;
+            self.next_task_uid = uid + 1;
+        }
+
+        Ok(page.results.len())
+    }
+
+    async fn replicate_task(&self, uid: TaskId) -> anyhow::Result<()> {
+        let url = format!("{}/tasks/{uid}/content", self.leader_url);
+        let mut kind: KindWithContent = self.get(&url).await?.json().await?;
+
+        match &mut kind {
+            // the dumps and snapshots are local to each instance
+            KindWithContent::DumpCreation { .. } | KindWithContent::SnapshotCreation => {
+                return Ok(())
+            }
+            KindWithContent::DocumentAdditionOrUpdate { content_file, .. } => {
+                *content_file = self.download_content_file(uid).await?;
+            }
+            // the leader hides the API keys of its embedders, we keep our own instead
+            KindWithContent::SettingsUpdate { new_settings, .. } => {
+                if let Setting::Set(embedders) = &mut new_settings.embedders {
+                    for embedder in embedders.values_mut() {
+                        if let Setting::Set(embedder) = embedder {
+                            embedder.api_key = Setting::NotSet;
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        let index_scheduler = self.index_scheduler.clone();
+        tokio::task::spawn_blocking(move || {
+            let content_file = match &kind {
+                KindWithContent::DocumentAdditionOrUpdate { content_file, .. } => {
+                    Some(*content_file)
+                }
+                _ => None,
+            };
+            if let Err(e) = index_scheduler.register_replicated(kind, uid) {
+                if let Some(content_file) = content_file {
+                    index_scheduler.delete_update_file(content_file)?;
+                }
+                return Err(e);
+            }
+            Ok(())
+        })
+        .await??;
+        Ok(())
+    }
+
+    /// Downloads the update file of the task in the local file store and returns its new uuid.
+    async fn download_content_file(&self, uid: TaskId) -> anyhow::Result<uuid::Uuid> {
+        let url = format!("{}/tasks/{uid}/content-file", self.leader_url);
+        let content = self.get(&url).await?.bytes().await?;
+
+        let index_scheduler = self.index_scheduler.clone();
+        tokio::task::spawn_blocking(move || -> anyhow::Result<uuid::Uuid> {
+            let (uuid, mut file) = index_scheduler.create_update_file(false)?;
+            file.write_all(&content)?;
+            file.persist()?;
+            Ok(uuid)
+        })
+        .await?
+    }
+
+    async fn get(&self, url: &str) -> anyhow::Result<reqwest::Response> {
+        let mut request = self.client.get(url);
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+        Ok(request.send().await?.error_for_status()?)
+    }
+}
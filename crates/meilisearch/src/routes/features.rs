@@ -48,6 +48,8 @@ pub struct RuntimeTogglableFeatures {
     pub edit_documents_by_function: Option<bool>,
     #[deserr(default)]
     pub contains_filter: Option<bool>,
+    #[deserr(default)]
+    pub read_only: Option<bool>,
 }
 
 #[derive(Serialize)]
@@ -57,6 +59,7 @@ pub struct PatchExperimentalFeatureAnalytics {
     logs_route: bool,
     edit_documents_by_function: bool,
     contains_filter: bool,
+    read_only: bool,
 }
 
 impl Aggregate for PatchExperimentalFeatureAnalytics {
@@ -71,6 +74,7 @@ impl Aggregate for PatchExperimentalFeatureAnalytics {
             logs_route: new.logs_route,
             edit_documents_by_function: new.edit_documents_by_function,
             contains_filter: new.contains_filter,
+            read_only: new.read_only,
         })
     }
 
@@ -101,6 +105,7 @@ async fn patch_features(
             .edit_documents_by_function
             .unwrap_or(old_features.edit_documents_by_function),
         contains_filter: new_features.0.contains_filter.unwrap_or(old_features.contains_filter),
+        read_only: new_features.0.read_only.unwrap_or(old_features.read_only),
     };
 
     // explicitly destructure for analytics rather than using the `Serialize` implementation, because
@@ -112,6 +117,7 @@ async fn patch_features(
         logs_route,
         edit_documents_by_function,
         contains_filter,
+        read_only,
     } = new_features;
 
     analytics.publish(
@@ -121,6 +127,7 @@ async fn patch_features(
             logs_route,
             edit_documents_by_function,
             contains_filter,
+            read_only,
         },
         &req,
     );
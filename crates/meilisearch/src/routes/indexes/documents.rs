@@ -396,13 +396,15 @@ fn documents_by_query(
     Ok(HttpResponse::Ok().json(ret))
 }
 
-#[derive(Deserialize, Debug, Deserr)]
+#[derive(Debug, Deserr)]
 #[deserr(error = DeserrQueryParamError, rename_all = camelCase, deny_unknown_fields)]
 pub struct UpdateDocumentsQuery {
     #[deserr(default, error = DeserrQueryParamError<InvalidIndexPrimaryKey>)]
     pub primary_key: Option<String>,
     #[deserr(default, try_from(char) = from_char_csv_delimiter -> DeserrQueryParamError<InvalidDocumentCsvDelimiter>, error = DeserrQueryParamError<InvalidDocumentCsvDelimiter>)]
     pub csv_delimiter: Option<u8>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidDocumentAutoGenerateId>)]
+    pub auto_generate_id: Param<bool>,
 }
 
 fn from_char_csv_delimiter(
@@ -496,6 +498,7 @@ pub async fn replace_documents(
         index_uid,
         params.primary_key,
         params.csv_delimiter,
+        params.auto_generate_id.0,
         body,
         IndexDocumentsMethod::ReplaceDocuments,
         uid,
@@ -544,17 +547,27 @@ pub async fn update_documents(
         &req,
     );
 
+    let mime_type = extract_mime_type(&req)?;
+    // a merge patch is applied on the existing documents instead of only replacing their top-level fields
+    let method = match &mime_type {
+        Some(mime) if mime.essence_str() == "application/merge-patch+json" => {
+            IndexDocumentsMethod::PatchDocuments
+        }
+        _ => IndexDocumentsMethod::UpdateDocuments,
+    };
+
     let allow_index_creation = index_scheduler.filters().allow_index_creation(&index_uid);
     let uid = get_task_id(&req, &opt)?;
     let dry_run = is_dry_run(&req, &opt)?;
     let task = document_addition(
-        extract_mime_type(&req)?,
+        mime_type,
         index_scheduler,
         index_uid,
         params.primary_key,
         params.csv_delimiter,
+        params.auto_generate_id.0,
         body,
-        IndexDocumentsMethod::UpdateDocuments,
+        method,
         uid,
         dry_run,
         allow_index_creation,
@@ -572,6 +585,7 @@ async fn document_addition(
     index_uid: IndexUid,
     primary_key: Option<String>,
     csv_delimiter: Option<u8>,
+    auto_generate_ids: bool,
     mut body: Payload,
     method: IndexDocumentsMethod,
     task_id: Option<TaskId>,
@@ -583,6 +597,11 @@ async fn document_addition(
         csv_delimiter,
     ) {
         (Some(("application", "json")), None) => PayloadType::Json,
+        (Some(("application", "merge-patch")), None)
+            if method == IndexDocumentsMethod::PatchDocuments =>
+        {
+            PayloadType::Json
+        }
         (Some(("application", "x-ndjson")), None) => PayloadType::Ndjson,
         (Some(("text", "csv")), None) => PayloadType::Csv { delimiter: b',' },
         (Some(("text", "csv")), Some(delimiter)) => PayloadType::Csv { delimiter },
@@ -597,6 +616,13 @@ async fn document_addition(
                 "application/x-ndjson",
             )))
         }
+        (Some(("application", "merge-patch")), Some(_))
+            if method == IndexDocumentsMethod::PatchDocuments =>
+        {
+            return Err(MeilisearchHttpError::CsvDelimiterWithWrongContentType(String::from(
+                "application/merge-patch+json",
+            )))
+        }
         (Some((type_, subtype)), _) => {
             return Err(MeilisearchHttpError::InvalidContentType(
                 format!("{}/{}", type_, subtype),
@@ -686,6 +712,7 @@ async fn document_addition(
         documents_count,
         primary_key,
         allow_index_creation,
+        auto_generate_ids,
         index_uid: index_uid.to_string(),
     };
 
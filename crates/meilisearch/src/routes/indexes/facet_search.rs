@@ -255,6 +255,8 @@ impl From<FacetSearchQuery> for SearchQuery {
             hybrid,
             ranking_score_threshold,
             locales,
+            field_scoped_query: false,
+            ranking_rules: None,
         }
     }
 }
@@ -1,7 +1,7 @@
 use std::collections::BTreeSet;
 use std::convert::Infallible;
 
-use actix_web::web::Data;
+use actix_web::web::{Bytes, Data};
 use actix_web::{web, HttpRequest, HttpResponse};
 use deserr::actix_web::{AwebJson, AwebQueryParameter};
 use deserr::{DeserializeError, Deserr, ValuePointerRef};
@@ -12,13 +12,15 @@ use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::{Code, ResponseError};
 use meilisearch_types::index_uid::IndexUid;
 use meilisearch_types::milli::{self, FieldDistribution, Index};
-use meilisearch_types::tasks::KindWithContent;
+use meilisearch_types::tasks::{KindWithContent, TaskId};
 use serde::Serialize;
 use time::OffsetDateTime;
+use tokio::sync::mpsc;
 use tracing::debug;
 
 use super::{get_task_id, Pagination, SummarizedTaskView, PAGINATION_DEFAULT_LIMIT};
 use crate::analytics::{Aggregate, Analytics};
+use crate::error::MeilisearchHttpError;
 use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::{AuthenticationError, GuardedData};
 use crate::extractors::sequential_extractor::SeqHandler;
@@ -49,6 +51,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                     .route(web::delete().to(SeqHandler(delete_index))),
             )
             .service(web::resource("/stats").route(web::get().to(SeqHandler(get_index_stats))))
+            .service(web::resource("/changes").route(web::get().to(SeqHandler(get_index_changes))))
             .service(web::scope("/documents").configure(documents::configure))
             .service(web::scope("/search").configure(search::configure))
             .service(web::scope("/facet-search").configure(facet_search::configure))
@@ -154,6 +157,7 @@ pub async fn create_index(
 ) -> Result<HttpResponse, ResponseError> {
     debug!(parameters = ?body, "Create index");
     let IndexCreateRequest { primary_key, uid } = body.into_inner();
+    validate_primary_key(primary_key.as_deref())?;
 
     let allow_index_creation = index_scheduler.filters().allow_index_creation(&uid);
     if allow_index_creation {
@@ -201,6 +205,16 @@ pub struct UpdateIndexRequest {
     primary_key: Option<String>,
 }
 
+/// Rejects the malformed composite primary keys before registering the task.
+fn validate_primary_key(primary_key: Option<&str>) -> Result<(), ResponseError> {
+    match primary_key.map(milli::documents::validate_primary_key) {
+        Some(Err(error)) => {
+            Err(ResponseError::from_msg(error.to_string(), Code::InvalidIndexPrimaryKey))
+        }
+        _ => Ok(()),
+    }
+}
+
 pub async fn get_index(
     index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_GET }>, Data<IndexScheduler>>,
     index_uid: web::Path<String>,
@@ -244,6 +258,7 @@ pub async fn update_index(
     debug!(parameters = ?body, "Update index");
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
     let body = body.into_inner();
+    validate_primary_key(body.primary_key.as_deref())?;
     analytics.publish(
         IndexUpdatedAggregate { primary_key: body.primary_key.iter().cloned().collect() },
         &req,
@@ -316,3 +331,65 @@ pub async fn get_index_stats(
     debug!(returns = ?stats, "Get index stats");
     Ok(HttpResponse::Ok().json(stats))
 }
+
+#[derive(Deserr, Debug, Clone, Copy)]
+#[deserr(error = DeserrQueryParamError, rename_all = camelCase, deny_unknown_fields)]
+pub struct IndexChangesQuery {
+    #[deserr(default, error = DeserrQueryParamError<InvalidIndexChangesSince>)]
+    pub since: Option<Param<TaskId>>,
+}
+
+/// Returns the document changes recorded in the changelog of the index as NDJSON,
+/// one line per change, ordered by task uid.
+pub async fn get_index_changes(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_GET }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    params: AwebQueryParameter<IndexChangesQuery, DeserrQueryParamError>,
+) -> Result<HttpResponse, ResponseError> {
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+    let IndexChangesQuery { since } = params.into_inner();
+    debug!(parameters = ?since, "Get index changes");
+
+    let index = index_scheduler.index(&index_uid)?;
+    let (sender, receiver) = mpsc::channel(1);
+    tokio::task::spawn_blocking(move || {
+        if let Err(error) = send_index_changes(&index, since.map(|since| since.0), &sender) {
+            let _ = sender.blocking_send(Err(error.into()));
+        }
+    });
+
+    let changes = futures_util::stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|chunk| (chunk, receiver))
+    });
+
+    Ok(HttpResponse::Ok().content_type("application/x-ndjson").streaming(changes))
+}
+
+/// The size from which the NDJSON lines of the changelog are sent to the client.
+const INDEX_CHANGES_CHUNK_SIZE: usize = 8192;
+
+/// Sends the changelog of the index by chunks of NDJSON lines,
+/// stops early when the client closed the connection.
+fn send_index_changes(
+    index: &Index,
+    since: Option<TaskId>,
+    sender: &mpsc::Sender<Result<Bytes, ResponseError>>,
+) -> Result<(), MeilisearchHttpError> {
+    let rtxn = index.read_txn()?;
+    let mut chunk = Vec::new();
+    for entry in index.document_changes(&rtxn, since)? {
+        serde_json::to_writer(&mut chunk, &entry?)?;
+        chunk.push(b'\n');
+        if chunk.len() >= INDEX_CHANGES_CHUNK_SIZE
+            && sender.blocking_send(Ok(std::mem::take(&mut chunk).into())).is_err()
+        {
+            return Ok(());
+        }
+    }
+
+    if !chunk.is_empty() {
+        let _ = sender.blocking_send(Ok(chunk.into()));
+    }
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use std::convert::Infallible;
+
 use actix_web::web::Data;
 use actix_web::{web, HttpRequest, HttpResponse};
 use deserr::actix_web::{AwebJson, AwebQueryParameter};
@@ -10,6 +12,7 @@ use meilisearch_types::index_uid::IndexUid;
 use meilisearch_types::locales::Locale;
 use meilisearch_types::milli;
 use meilisearch_types::serde_cs::vec::CS;
+use meilisearch_types::settings::RankingRuleView;
 use serde_json::Value;
 use tracing::debug;
 
@@ -93,6 +96,42 @@ pub struct SearchQueryGet {
     pub ranking_score_threshold: Option<RankingScoreThresholdGet>,
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchLocales>)]
     pub locales: Option<CS<Locale>>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchFieldScopedQuery>)]
+    pub field_scoped_query: Param<bool>,
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchRankingRules>)]
+    pub ranking_rules: Option<RankingRulesGet>,
+}
+
+/// The ranking rules of a GET search, separated by the commas found outside of the
+/// parentheses of the function rules, e.g. `words,function(max(rank, 1)),typo`.
+#[derive(Debug, Clone, PartialEq, deserr::Deserr)]
+#[deserr(try_from(String) = RankingRulesGet::split -> Infallible)]
+pub struct RankingRulesGet(Vec<String>);
+
+impl RankingRulesGet {
+    fn split(s: String) -> Result<Self, Infallible> {
+        if s.is_empty() {
+            return Ok(RankingRulesGet(Vec::new()));
+        }
+
+        let mut rules = Vec::new();
+        let mut depth = 0usize;
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    rules.push(s[start..i].to_string());
+                    start = i + 1;
+                }
+                _ => (),
+            }
+        }
+        rules.push(s[start..].to_string());
+
+        Ok(RankingRulesGet(rules))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, deserr::Deserr)]
@@ -157,6 +196,23 @@ impl TryFrom<SearchQueryGet> for SearchQuery {
             }
         };
 
+        let ranking_rules = match other.ranking_rules {
+            Some(ranking_rules) => Some(
+                ranking_rules
+                    .0
+                    .into_iter()
+                    .map(|rule| rule.parse())
+                    .collect::<Result<Vec<RankingRuleView>, _>>()
+                    .map_err(|error| {
+                        ResponseError::from_msg(
+                            format!("Invalid value in parameter `rankingRules`: {error}"),
+                            meilisearch_types::error::Code::InvalidSearchRankingRules,
+                        )
+                    })?,
+            ),
+            None => None,
+        };
+
         if other.vector.is_some() && hybrid.is_none() {
             return Err(ResponseError::from_msg(
                 "`hybridEmbedder` is mandatory when `vector` is present".into(),
@@ -191,6 +247,8 @@ impl TryFrom<SearchQueryGet> for SearchQuery {
             hybrid,
             ranking_score_threshold: other.ranking_score_threshold.map(|o| o.0),
             locales: other.locales.map(|o| o.into_iter().collect()),
+            field_scoped_query: other.field_scoped_query.0,
+            ranking_rules,
         })
     }
 }
@@ -53,6 +53,8 @@ pub struct SearchAggregator<Method: AggregateMethod> {
     // q
     // The maximum number of terms in a q request
     max_terms_number: usize,
+    // Whether the field-scoped query syntax was enabled
+    field_scoped_query: bool,
 
     // vector
     // The maximum number of floats in a vector request
@@ -91,6 +93,8 @@ pub struct SearchAggregator<Method: AggregateMethod> {
     show_ranking_score: bool,
     show_ranking_score_details: bool,
     ranking_score_threshold: bool,
+    // every time a search overrides the ranking rules of the index
+    ranking_rules_total_number_of_uses: usize,
 
     marker: std::marker::PhantomData<Method>,
 }
@@ -125,6 +129,8 @@ impl<Method: AggregateMethod> SearchAggregator<Method> {
             hybrid,
             ranking_score_threshold,
             locales,
+            field_scoped_query,
+            ranking_rules,
         } = query;
 
         let mut ret = Self::default();
@@ -189,6 +195,7 @@ impl<Method: AggregateMethod> SearchAggregator<Method> {
         }
 
         ret.matching_strategy.insert(format!("{:?}", matching_strategy), 1);
+        ret.field_scoped_query = *field_scoped_query;
 
         if let Some(locales) = locales {
             ret.locales = locales.iter().copied().collect();
@@ -203,6 +210,7 @@ impl<Method: AggregateMethod> SearchAggregator<Method> {
         ret.show_ranking_score = *show_ranking_score;
         ret.show_ranking_score_details = *show_ranking_score_details;
         ret.ranking_score_threshold = ranking_score_threshold.is_some();
+        ret.ranking_rules_total_number_of_uses = ranking_rules.is_some().into();
 
         if let Some(hybrid) = hybrid {
             ret.semantic_ratio = hybrid.semantic_ratio != DEFAULT_SEMANTIC_RATIO();
@@ -257,6 +265,7 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
             used_syntax,
             attributes_to_search_on_total_number_of_uses,
             max_terms_number,
+            field_scoped_query,
             max_vector_size,
             retrieve_vectors,
             matching_strategy,
@@ -280,6 +289,7 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
             total_degraded,
             total_used_negative_operator,
             ranking_score_threshold,
+            ranking_rules_total_number_of_uses,
             mut locales,
             marker: _,
         } = *new;
@@ -321,6 +331,7 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
 
         // q
         self.max_terms_number = self.max_terms_number.max(max_terms_number);
+        self.field_scoped_query |= field_scoped_query;
 
         // vector
         self.max_vector_size = self.max_vector_size.max(max_vector_size);
@@ -360,6 +371,9 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
         self.show_ranking_score |= show_ranking_score;
         self.show_ranking_score_details |= show_ranking_score_details;
         self.ranking_score_threshold |= ranking_score_threshold;
+        self.ranking_rules_total_number_of_uses = self
+            .ranking_rules_total_number_of_uses
+            .saturating_add(ranking_rules_total_number_of_uses);
 
         // locales
         self.locales.append(&mut locales);
@@ -383,6 +397,7 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
             used_syntax,
             attributes_to_search_on_total_number_of_uses,
             max_terms_number,
+            field_scoped_query,
             max_vector_size,
             retrieve_vectors,
             matching_strategy,
@@ -406,6 +421,7 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
             total_degraded,
             total_used_negative_operator,
             ranking_score_threshold,
+            ranking_rules_total_number_of_uses,
             locales,
             marker: _,
         } = *self;
@@ -442,6 +458,7 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
             },
             "q": {
                "max_terms_number": max_terms_number,
+               "field_scoped_query": field_scoped_query,
             },
             "vector": {
                 "max_vector_size": max_vector_size,
@@ -477,6 +494,7 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
                 "show_ranking_score": show_ranking_score,
                 "show_ranking_score_details": show_ranking_score_details,
                 "ranking_score_threshold": ranking_score_threshold,
+                "ranking_rules_total_number_of_uses": ranking_rules_total_number_of_uses,
             },
         })
     }
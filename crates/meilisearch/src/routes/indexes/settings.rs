@@ -1,17 +1,23 @@
 use actix_web::web::Data;
 use actix_web::{web, HttpRequest, HttpResponse};
 use deserr::actix_web::AwebJson;
+use deserr::Deserr;
 use index_scheduler::IndexScheduler;
 use meilisearch_types::deserr::DeserrJsonError;
+use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::ResponseError;
 use meilisearch_types::index_uid::IndexUid;
 use meilisearch_types::milli::update::Setting;
-use meilisearch_types::settings::{settings, SecretPolicy, Settings, Unchecked};
+use meilisearch_types::settings::{
+    settings, SecretPolicy, Settings, SettingsVersionView, Unchecked,
+};
 use meilisearch_types::tasks::KindWithContent;
+use serde_json::json;
 use tracing::debug;
 
 use super::settings_analytics::*;
 use crate::analytics::Analytics;
+use crate::error::MeilisearchHttpError;
 use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::GuardedData;
 use crate::routes::{get_task_id, is_dry_run, SummarizedTaskView};
@@ -369,6 +375,102 @@ make_setting_route!(
     SearchCutoffMsAnalytics
 );
 
+make_setting_route!(
+    "/changelog",
+    put,
+    bool,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsChangelog,
+    >,
+    changelog,
+    "changelog",
+    ChangelogAnalytics
+);
+
+make_setting_route!(
+    "/version-attribute",
+    put,
+    String,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsVersionAttribute,
+    >,
+    version_attribute,
+    "versionAttribute",
+    VersionAttributeAnalytics
+);
+
+make_setting_route!(
+    "/auto-generate-ids",
+    put,
+    bool,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsAutoGenerateIds,
+    >,
+    auto_generate_ids,
+    "autoGenerateIds",
+    AutoGenerateIdsAnalytics
+);
+
+make_setting_route!(
+    "/document-schema",
+    put,
+    meilisearch_types::settings::DocumentSchemaView,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsDocumentSchema,
+    >,
+    document_schema,
+    "documentSchema",
+    DocumentSchemaAnalytics
+);
+
+make_setting_route!(
+    "/ingest-pipeline",
+    put,
+    Vec<meilisearch_types::milli::ingest_pipeline::IngestProcessor>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsIngestPipeline,
+    >,
+    ingest_pipeline,
+    "ingestPipeline",
+    IngestPipelineAnalytics
+);
+
+make_setting_route!(
+    "/computed-fields",
+    put,
+    std::collections::BTreeMap<String, String>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsComputedFields,
+    >,
+    computed_fields,
+    "computedFields",
+    ComputedFieldsAnalytics
+);
+
+make_setting_route!(
+    "/date-attributes",
+    put,
+    std::collections::BTreeSet<String>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsDateAttributes,
+    >,
+    date_attributes,
+    "dateAttributes",
+    DateAttributesAnalytics
+);
+
+make_setting_route!(
+    "/searchable-attributes-weights",
+    put,
+    std::collections::BTreeMap<String, u16>,
+    meilisearch_types::deserr::DeserrJsonError<
+        meilisearch_types::error::deserr_codes::InvalidSettingsSearchableAttributesWeights,
+    >,
+    searchable_attributes_weights,
+    "searchableAttributesWeights",
+    SearchableAttributesWeightsAnalytics
+);
+
 macro_rules! generate_configure {
     ($($mod:ident),*) => {
         pub fn configure(cfg: &mut web::ServiceConfig) {
@@ -378,6 +480,8 @@ macro_rules! generate_configure {
                 .route(web::patch().to(SeqHandler(update_all)))
                 .route(web::get().to(SeqHandler(get_all)))
                 .route(web::delete().to(SeqHandler(delete_all))))
+                .service(web::resource("/history").route(web::get().to(SeqHandler(get_history))))
+                .service(web::resource("/rollback").route(web::post().to(SeqHandler(rollback))))
                 $(.service($mod::resources()))*;
         }
     };
@@ -401,7 +505,15 @@ generate_configure!(
     pagination,
     faceting,
     embedders,
-    search_cutoff_ms
+    search_cutoff_ms,
+    changelog,
+    version_attribute,
+    auto_generate_ids,
+    document_schema,
+    ingest_pipeline,
+    computed_fields,
+    date_attributes,
+    searchable_attributes_weights
 );
 
 pub async fn update_all(
@@ -456,6 +568,28 @@ pub async fn update_all(
             non_separator_tokens: NonSeparatorTokensAnalytics::new(
                 new_settings.non_separator_tokens.as_ref().set(),
             ),
+            changelog: ChangelogAnalytics::new(new_settings.changelog.as_ref().set()),
+            version_attribute: VersionAttributeAnalytics::new(
+                new_settings.version_attribute.as_ref().set(),
+            ),
+            auto_generate_ids: AutoGenerateIdsAnalytics::new(
+                new_settings.auto_generate_ids.as_ref().set(),
+            ),
+            document_schema: DocumentSchemaAnalytics::new(
+                new_settings.document_schema.as_ref().set(),
+            ),
+            ingest_pipeline: IngestPipelineAnalytics::new(
+                new_settings.ingest_pipeline.as_ref().set(),
+            ),
+            computed_fields: ComputedFieldsAnalytics::new(
+                new_settings.computed_fields.as_ref().set(),
+            ),
+            date_attributes: DateAttributesAnalytics::new(
+                new_settings.date_attributes.as_ref().set(),
+            ),
+            searchable_attributes_weights: SearchableAttributesWeightsAnalytics::new(
+                new_settings.searchable_attributes_weights.as_ref().set(),
+            ),
         },
         &req,
     );
@@ -521,6 +655,71 @@ pub async fn delete_all(
     Ok(HttpResponse::Accepted().json(task))
 }
 
+pub async fn get_history(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SETTINGS_GET }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+
+    let index = index_scheduler.index(&index_uid)?;
+    let rtxn = index.read_txn()?;
+    let history = index
+        .settings_history(&rtxn)?
+        .iter()
+        .rev()
+        .map(SettingsVersionView::new)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(MeilisearchHttpError::from)?;
+    debug!(returns = ?history, "Get settings history");
+    Ok(HttpResponse::Ok().json(json!({ "results": history })))
+}
+
+#[derive(Debug, Deserr)]
+#[deserr(error = DeserrJsonError, rename_all = camelCase, deny_unknown_fields)]
+pub struct RollbackSettings {
+    #[deserr(error = DeserrJsonError<InvalidSettingsRollbackVersion>)]
+    pub version: u32,
+}
+
+pub async fn rollback(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SETTINGS_UPDATE }>, Data<IndexScheduler>>,
+    index_uid: web::Path<String>,
+    body: AwebJson<RollbackSettings, DeserrJsonError>,
+    req: HttpRequest,
+    opt: web::Data<Opt>,
+) -> Result<HttpResponse, ResponseError> {
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+    let RollbackSettings { version } = body.into_inner();
+    debug!(parameters = ?version, "Rollback settings");
+
+    let index = index_scheduler.index(&index_uid)?;
+    let rtxn = index.read_txn()?;
+    let history = index.settings_history(&rtxn)?;
+    let settings_version = history
+        .iter()
+        .find(|v| v.version == version)
+        .ok_or(MeilisearchHttpError::SettingsVersionNotFound(version))?;
+    let new_settings =
+        Settings::from_version(settings_version).map_err(MeilisearchHttpError::from)?;
+    drop(rtxn);
+
+    let task = KindWithContent::SettingsUpdate {
+        index_uid: index_uid.into_inner(),
+        new_settings: Box::new(new_settings),
+        is_deletion: false,
+        allow_index_creation: false,
+    };
+    let uid = get_task_id(&req, &opt)?;
+    let dry_run = is_dry_run(&req, &opt)?;
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task, uid, dry_run))
+            .await??
+            .into();
+
+    debug!(returns = ?task, "Rollback settings");
+    Ok(HttpResponse::Accepted().json(task))
+}
+
 fn validate_settings(
     settings: Settings<Unchecked>,
     index_scheduler: &IndexScheduler,
@@ -7,10 +7,12 @@ use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 use meilisearch_types::facet_values_sort::FacetValuesSort;
 use meilisearch_types::locales::{Locale, LocalizedAttributesRuleView};
+use meilisearch_types::milli::ingest_pipeline::IngestProcessor;
 use meilisearch_types::milli::update::Setting;
 use meilisearch_types::milli::vector::settings::EmbeddingSettings;
 use meilisearch_types::settings::{
-    FacetingSettings, PaginationSettings, ProximityPrecisionView, RankingRuleView, TypoSettings,
+    DocumentSchemaView, FacetingSettings, PaginationSettings, PhoneticAlgorithmView,
+    ProximityPrecisionView, RankingRuleView, SchemaModeView, TypoSettings,
 };
 use serde::Serialize;
 
@@ -36,6 +38,14 @@ pub struct SettingsAnalytics {
     pub dictionary: DictionaryAnalytics,
     pub separator_tokens: SeparatorTokensAnalytics,
     pub non_separator_tokens: NonSeparatorTokensAnalytics,
+    pub changelog: ChangelogAnalytics,
+    pub version_attribute: VersionAttributeAnalytics,
+    pub auto_generate_ids: AutoGenerateIdsAnalytics,
+    pub document_schema: DocumentSchemaAnalytics,
+    pub ingest_pipeline: IngestPipelineAnalytics,
+    pub computed_fields: ComputedFieldsAnalytics,
+    pub date_attributes: DateAttributesAnalytics,
+    pub searchable_attributes_weights: SearchableAttributesWeightsAnalytics,
 }
 
 impl Aggregate for SettingsAnalytics {
@@ -113,6 +123,14 @@ impl Aggregate for SettingsAnalytics {
                     .typo_tolerance
                     .min_word_size_for_two_typos
                     .or(self.typo_tolerance.min_word_size_for_two_typos),
+                phonetic_on_attributes: new
+                    .typo_tolerance
+                    .phonetic_on_attributes
+                    .or(self.typo_tolerance.phonetic_on_attributes),
+                phonetic_algorithm: new
+                    .typo_tolerance
+                    .phonetic_algorithm
+                    .or(self.typo_tolerance.phonetic_algorithm),
             },
             faceting: FacetingAnalytics {
                 max_values_per_facet: new
@@ -183,6 +201,35 @@ impl Aggregate for SettingsAnalytics {
             non_separator_tokens: NonSeparatorTokensAnalytics {
                 total: new.non_separator_tokens.total.or(self.non_separator_tokens.total),
             },
+            changelog: ChangelogAnalytics {
+                enabled: new.changelog.enabled.or(self.changelog.enabled),
+            },
+            version_attribute: VersionAttributeAnalytics {
+                set: self.version_attribute.set | new.version_attribute.set,
+            },
+            auto_generate_ids: AutoGenerateIdsAnalytics {
+                enabled: new.auto_generate_ids.enabled.or(self.auto_generate_ids.enabled),
+            },
+            document_schema: DocumentSchemaAnalytics {
+                set: self.document_schema.set | new.document_schema.set,
+                mode: new.document_schema.mode.or(self.document_schema.mode),
+                fields: new.document_schema.fields.or(self.document_schema.fields),
+            },
+            ingest_pipeline: IngestPipelineAnalytics {
+                processors: new.ingest_pipeline.processors.or(self.ingest_pipeline.processors),
+            },
+            computed_fields: ComputedFieldsAnalytics {
+                total: new.computed_fields.total.or(self.computed_fields.total),
+            },
+            date_attributes: DateAttributesAnalytics {
+                total: new.date_attributes.total.or(self.date_attributes.total),
+            },
+            searchable_attributes_weights: SearchableAttributesWeightsAnalytics {
+                total: new
+                    .searchable_attributes_weights
+                    .total
+                    .or(self.searchable_attributes_weights.total),
+            },
         })
     }
 
@@ -371,6 +418,8 @@ pub struct TypoToleranceAnalytics {
     pub disable_on_words: Option<bool>,
     pub min_word_size_for_one_typo: Option<u8>,
     pub min_word_size_for_two_typos: Option<u8>,
+    pub phonetic_on_attributes: Option<bool>,
+    pub phonetic_algorithm: Option<PhoneticAlgorithmView>,
 }
 
 impl TypoToleranceAnalytics {
@@ -391,6 +440,12 @@ impl TypoToleranceAnalytics {
                 .as_ref()
                 .and_then(|s| s.min_word_size_for_typos.as_ref().set().map(|s| s.two_typos.set()))
                 .flatten(),
+            phonetic_on_attributes: setting
+                .as_ref()
+                .and_then(|s| s.phonetic_on_attributes.as_ref().set().map(|m| !m.is_empty())),
+            phonetic_algorithm: setting
+                .as_ref()
+                .and_then(|s| s.phonetic_algorithm.as_ref().set().copied()),
         }
     }
     pub fn into_settings(self) -> SettingsAnalytics {
@@ -620,3 +675,132 @@ impl NonSeparatorTokensAnalytics {
         SettingsAnalytics { non_separator_tokens: self, ..Default::default() }
     }
 }
+
+#[derive(Serialize, Default)]
+#[serde(transparent)]
+pub struct ChangelogAnalytics {
+    pub enabled: Option<bool>,
+}
+
+impl ChangelogAnalytics {
+    pub fn new(enabled: Option<&bool>) -> Self {
+        Self { enabled: enabled.copied() }
+    }
+
+    pub fn into_settings(self) -> SettingsAnalytics {
+        SettingsAnalytics { changelog: self, ..Default::default() }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct VersionAttributeAnalytics {
+    pub set: bool,
+}
+
+impl VersionAttributeAnalytics {
+    pub fn new(version_attribute: Option<&String>) -> Self {
+        Self { set: version_attribute.is_some() }
+    }
+
+    pub fn into_settings(self) -> SettingsAnalytics {
+        SettingsAnalytics { version_attribute: self, ..Default::default() }
+    }
+}
+
+#[derive(Serialize, Default)]
+#[serde(transparent)]
+pub struct AutoGenerateIdsAnalytics {
+    pub enabled: Option<bool>,
+}
+
+impl AutoGenerateIdsAnalytics {
+    pub fn new(enabled: Option<&bool>) -> Self {
+        Self { enabled: enabled.copied() }
+    }
+
+    pub fn into_settings(self) -> SettingsAnalytics {
+        SettingsAnalytics { auto_generate_ids: self, ..Default::default() }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct DocumentSchemaAnalytics {
+    pub set: bool,
+    pub mode: Option<SchemaModeView>,
+    pub fields: Option<usize>,
+}
+
+impl DocumentSchemaAnalytics {
+    pub fn new(schema: Option<&DocumentSchemaView>) -> Self {
+        Self {
+            set: schema.is_some(),
+            mode: schema.map(|schema| schema.mode),
+            fields: schema.map(|schema| schema.fields.len()),
+        }
+    }
+
+    pub fn into_settings(self) -> SettingsAnalytics {
+        SettingsAnalytics { document_schema: self, ..Default::default() }
+    }
+}
+
+#[derive(Serialize, Default)]
+#[serde(transparent)]
+pub struct IngestPipelineAnalytics {
+    pub processors: Option<usize>,
+}
+
+impl IngestPipelineAnalytics {
+    pub fn new(pipeline: Option<&Vec<IngestProcessor>>) -> Self {
+        Self { processors: pipeline.map(|pipeline| pipeline.len()) }
+    }
+
+    pub fn into_settings(self) -> SettingsAnalytics {
+        SettingsAnalytics { ingest_pipeline: self, ..Default::default() }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct ComputedFieldsAnalytics {
+    pub total: Option<usize>,
+}
+
+impl ComputedFieldsAnalytics {
+    pub fn new(fields: Option<&BTreeMap<String, String>>) -> Self {
+        Self { total: fields.map(|fields| fields.len()) }
+    }
+
+    pub fn into_settings(self) -> SettingsAnalytics {
+        SettingsAnalytics { computed_fields: self, ..Default::default() }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct DateAttributesAnalytics {
+    pub total: Option<usize>,
+}
+
+impl DateAttributesAnalytics {
+    pub fn new(setting: Option<&BTreeSet<String>>) -> Self {
+        Self { total: setting.as_ref().map(|s| s.len()) }
+    }
+
+    pub fn into_settings(self) -> SettingsAnalytics {
+        SettingsAnalytics { date_attributes: self, ..Default::default() }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct SearchableAttributesWeightsAnalytics {
+    pub total: Option<usize>,
+}
+
+impl SearchableAttributesWeightsAnalytics {
+    pub fn new(setting: Option<&BTreeMap<String, u16>>) -> Self {
+        Self { total: setting.as_ref().map(|s| s.len()) }
+    }
+
+    pub fn into_settings(self) -> SettingsAnalytics {
+        SettingsAnalytics { searchable_attributes_weights: self, ..Default::default() }
+    }
+}
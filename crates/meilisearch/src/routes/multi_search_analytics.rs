@@ -66,6 +66,8 @@ impl MultiSearchAggregator {
                     hybrid: _,
                     ranking_score_threshold: _,
                     locales: _,
+                    field_scoped_query: _,
+                    ranking_rules: _,
                 } = query;
 
                 index_uid.as_str()
@@ -1,3 +1,5 @@
+use std::io::Read;
+
 use actix_web::web::Data;
 use actix_web::{web, HttpRequest, HttpResponse};
 use deserr::actix_web::AwebQueryParameter;
@@ -11,7 +13,7 @@ use meilisearch_types::error::{InvalidTaskDateError, ResponseError};
 use meilisearch_types::index_uid::IndexUid;
 use meilisearch_types::star_or::{OptionStarOr, OptionStarOrList};
 use meilisearch_types::task_view::TaskView;
-use meilisearch_types::tasks::{Kind, KindWithContent, Status};
+use meilisearch_types::tasks::{Kind, KindWithContent, Status, Task};
 use serde::Serialize;
 use time::format_description::well_known::Rfc3339;
 use time::macros::format_description;
@@ -32,7 +34,12 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route(web::delete().to(SeqHandler(delete_tasks))),
     )
     .service(web::resource("/cancel").route(web::post().to(SeqHandler(cancel_tasks))))
-    .service(web::resource("/{task_id}").route(web::get().to(SeqHandler(get_task))));
+    .service(web::resource("/{task_id}").route(web::get().to(SeqHandler(get_task))))
+    .service(web::resource("/{task_id}/content").route(web::get().to(SeqHandler(get_task_content))))
+    .service(
+        web::resource("/{task_id}/content-file")
+            .route(web::get().to(SeqHandler(get_task_content_file))),
+    );
 }
 
 #[derive(Debug, Deserr)]
@@ -387,6 +394,89 @@ async fn get_task(
     }
 }
 
+/// Fetch the authorized task corresponding to the uid in the route.
+fn find_authorized_task(
+    index_scheduler: &IndexScheduler,
+    filters: &meilisearch_auth::AuthFilter,
+    task_uid: String,
+) -> Result<Task, ResponseError> {
+    let task_uid: TaskId = match task_uid.parse() {
+        Ok(id) => id,
+        Err(_e) => return Err(index_scheduler::Error::InvalidTaskUid { task_uid }.into()),
+    };
+
+    let query = index_scheduler::Query { uids: Some(vec![task_uid]), ..Query::default() };
+    let (mut tasks, _) = index_scheduler.get_tasks_from_authorized_indexes(query, filters)?;
+    match tasks.pop() {
+        Some(task) => Ok(task),
+        None => Err(index_scheduler::Error::TaskNotFound(task_uid).into()),
+    }
+}
+
+/// Returns the full content of a task, as needed by a follower to replay it.
+async fn get_task_content(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::TASKS_GET }>, Data<IndexScheduler>>,
+    task_uid: web::Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    let task =
+        find_authorized_task(&index_scheduler, index_scheduler.filters(), task_uid.into_inner())?;
+
+    let kind = match task.kind {
+        // the keys of the instance must never be exposed
+        KindWithContent::DumpCreation { keys: _, instance_uid } => {
+            KindWithContent::DumpCreation { keys: Vec::new(), instance_uid }
+        }
+        // nor the API keys of the embedders
+        KindWithContent::SettingsUpdate {
+            index_uid,
+            mut new_settings,
+            is_deletion,
+            allow_index_creation,
+        } => {
+            new_settings.hide_secrets();
+            KindWithContent::SettingsUpdate {
+                index_uid,
+                new_settings,
+                is_deletion,
+                allow_index_creation,
+            }
+        }
+        kind => kind,
+    };
+
+    Ok(HttpResponse::Ok().json(kind))
+}
+
+/// Returns the raw update file of a document addition task.
+async fn get_task_content_file(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DOCUMENTS_GET }>, Data<IndexScheduler>>,
+    task_uid: web::Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    let task =
+        find_authorized_task(&index_scheduler, index_scheduler.filters(), task_uid.into_inner())?;
+    let Some(content_uuid) = task.content_uuid() else {
+        return Err(index_scheduler::Error::TaskContentFileNotFound(task.uid).into());
+    };
+
+    let content = task::spawn_blocking(move || -> Result<Vec<u8>, index_scheduler::Error> {
+        let mut file = match index_scheduler.update_file(content_uuid) {
+            Ok(file) => file,
+            Err(index_scheduler::Error::FileStore(file_store::Error::IoError(e)))
+                if e.kind() == std::io::ErrorKind::NotFound =>
+            {
+                return Err(index_scheduler::Error::TaskContentFileNotFound(task.uid));
+            }
+            Err(e) => return Err(e),
+        };
+        let mut content = Vec::new();
+        file.read_to_end(&mut content)?;
+        Ok(content)
+    })
+    .await??;
+
+    Ok(HttpResponse::Ok().content_type("application/x-ndjson").body(content))
+}
+
 pub enum DeserializeDateOption {
     Before,
     After,
@@ -410,6 +410,7 @@ impl FederatedFacets {
 
                         stats.min = f64::min(stats.min, index_stats.min);
                         stats.max = f64::max(stats.max, index_stats.max);
+                        stats.datetime &= index_stats.datetime;
                     }
                 }
             }
@@ -586,8 +587,15 @@ pub fn perform_federated_search(
                     None
                 };
 
+                let criteria = match &query.ranking_rules {
+                    Some(query_ranking_rules) => {
+                        ranking_rules::override_criteria(&criteria, query_ranking_rules)?
+                    }
+                    None => criteria.clone(),
+                };
+
                 let ranking_rules = ranking_rules::RankingRules::new(
-                    criteria.clone(),
+                    criteria,
                     sort,
                     query.matching_strategy.into(),
                     canonicalization_kind,
@@ -20,7 +20,7 @@ use meilisearch_types::milli::score_details::{ScoreDetails, ScoringStrategy};
 use meilisearch_types::milli::vector::parsed_vectors::ExplicitVectors;
 use meilisearch_types::milli::vector::Embedder;
 use meilisearch_types::milli::{FacetValueHit, OrderBy, SearchForFacetValues, TimeBudget};
-use meilisearch_types::settings::DEFAULT_PAGINATION_MAX_TOTAL_HITS;
+use meilisearch_types::settings::{RankingRuleView, DEFAULT_PAGINATION_MAX_TOTAL_HITS};
 use meilisearch_types::{milli, Document};
 use milli::tokenizer::{Language, TokenizerBuilder};
 use milli::{
@@ -103,6 +103,10 @@ pub struct SearchQuery {
     pub ranking_score_threshold: Option<RankingScoreThreshold>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchLocales>, default)]
     pub locales: Option<Vec<Locale>>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchFieldScopedQuery>, default)]
+    pub field_scoped_query: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchRankingRules>, default)]
+    pub ranking_rules: Option<Vec<RankingRuleView>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Deserr)]
@@ -173,6 +177,8 @@ impl fmt::Debug for SearchQuery {
             attributes_to_search_on,
             ranking_score_threshold,
             locales,
+            field_scoped_query,
+            ranking_rules,
         } = self;
 
         let mut debug = f.debug_struct("SearchQuery");
@@ -257,6 +263,12 @@ impl fmt::Debug for SearchQuery {
         if let Some(locales) = locales {
             debug.field("locales", &locales);
         }
+        if *field_scoped_query {
+            debug.field("field_scoped_query", field_scoped_query);
+        }
+        if let Some(ranking_rules) = ranking_rules {
+            debug.field("ranking_rules", &ranking_rules);
+        }
 
         debug.finish()
     }
@@ -431,6 +443,10 @@ pub struct SearchQueryWithIndex {
     pub ranking_score_threshold: Option<RankingScoreThreshold>,
     #[deserr(default, error = DeserrJsonError<InvalidSearchLocales>, default)]
     pub locales: Option<Vec<Locale>>,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchFieldScopedQuery>, default)]
+    pub field_scoped_query: bool,
+    #[deserr(default, error = DeserrJsonError<InvalidSearchRankingRules>, default)]
+    pub ranking_rules: Option<Vec<RankingRuleView>>,
 
     #[deserr(default)]
     pub federation_options: Option<FederationOptions>,
@@ -485,6 +501,8 @@ impl SearchQueryWithIndex {
             hybrid,
             ranking_score_threshold,
             locales,
+            field_scoped_query,
+            ranking_rules,
         } = self;
         (
             index_uid,
@@ -515,6 +533,8 @@ impl SearchQueryWithIndex {
                 hybrid,
                 ranking_score_threshold,
                 locales,
+                field_scoped_query,
+                ranking_rules,
                 // do not use ..Default::default() here,
                 // rather add any missing field from `SearchQuery` to `SearchQueryWithIndex`
             },
@@ -731,10 +751,37 @@ pub enum HitsInfo {
     OffsetLimit { limit: usize, offset: usize, estimated_total_hits: usize },
 }
 
-#[derive(Serialize, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FacetStats {
     pub min: f64,
     pub max: f64,
+    /// Whether the facet is declared as a date attribute, in which case
+    /// the bounds are timestamps rendered back as RFC 3339 dates.
+    pub datetime: bool,
+}
+
+impl Serialize for FacetStats {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("FacetStats", 2)?;
+        match (self.datetime, format_timestamp(self.min), format_timestamp(self.max)) {
+            (true, Some(min), Some(max)) => {
+                state.serialize_field("min", &min)?;
+                state.serialize_field("max", &max)?;
+            }
+            _ => {
+                state.serialize_field("min", &self.min)?;
+                state.serialize_field("max", &self.max)?;
+            }
+        }
+        state.end()
+    }
+}
+
+fn format_timestamp(timestamp: f64) -> Option<String> {
+    let datetime = time::OffsetDateTime::from_unix_timestamp(timestamp as i64).ok()?;
+    datetime.format(&time::format_description::well_known::Rfc3339).ok()
 }
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
@@ -827,6 +874,12 @@ fn prepare_search<'t>(
 
     let is_finite_pagination = query.is_finite_pagination();
     search.terms_matching_strategy(query.matching_strategy.into());
+    search.field_scoped_query(query.field_scoped_query);
+
+    if let Some(query_ranking_rules) = &query.ranking_rules {
+        let criteria = index.criteria(rtxn).map_err(milli::Error::from)?;
+        search.ranking_rules(ranking_rules::override_criteria(&criteria, query_ranking_rules)?);
+    }
 
     let max_total_hits = index
         .pagination_max_total_hits(rtxn)
@@ -947,6 +1000,8 @@ pub fn perform_search(
         attributes_to_search_on: _,
         filter: _,
         distinct: _,
+        field_scoped_query: _,
+        ranking_rules: _,
     } = query;
 
     let format = AttributesFormat {
@@ -1064,7 +1119,14 @@ fn compute_facet_distribution_stats<S: AsRef<str>>(
             (error, _) => error.into(),
         })?;
     let stats = facet_distribution.compute_stats()?;
-    let stats = stats.into_iter().map(|(k, (min, max))| (k, FacetStats { min, max })).collect();
+    let date_fields = index.date_fields(rtxn).map_err(milli::Error::from)?;
+    let stats = stats
+        .into_iter()
+        .map(|(k, (min, max))| {
+            let datetime = date_fields.contains(&k);
+            (k, FacetStats { min, max, datetime })
+        })
+        .collect();
     Ok(ComputedFacets { distribution, stats })
 }
 
@@ -1848,7 +1910,11 @@ pub(crate) fn parse_filter(
     let filter = match facets {
         Value::String(expr) => Filter::from_str(expr).map_err(|e| e.into()),
         Value::Array(arr) => parse_filter_array(arr).map_err(|e| e.into()),
-        v => Err(MeilisearchHttpError::InvalidExpression(&["String", "Array"], v.clone()).into()),
+        Value::Object(_) => Filter::from_json_object(facets).map(Some).map_err(|e| e.into()),
+        v => {
+            Err(MeilisearchHttpError::InvalidExpression(&["String", "Array", "Object"], v.clone())
+                .into())
+        }
     };
     let filter = filter.map_err(|err: ResponseError| {
         ResponseError::from_msg(err.to_string(), filter_parsing_error_code)
@@ -4,6 +4,7 @@ use std::fmt::Write;
 use itertools::Itertools as _;
 use meilisearch_types::error::{Code, ResponseError};
 use meilisearch_types::milli::{AscDesc, Criterion, Member, TermsMatchingStrategy};
+use meilisearch_types::settings::RankingRuleView;
 
 pub struct RankingRules {
     canonical_criteria: Vec<Criterion>,
@@ -236,6 +237,7 @@ impl RankingRules {
                         })
                     }
                 }
+                Criterion::Function(_) => canonical_criteria.push(criterion.clone()),
                 Criterion::Asc(s) | Criterion::Desc(s) => match sorted_fields.entry(s) {
                     std::collections::hash_map::Entry::Occupied(entry) => canonicalization_actions
                         .push(CanonicalizationAction::RemovedDuplicate {
@@ -316,6 +318,7 @@ impl RankingRules {
                         })
                     }
                 }
+                Criterion::Function(_) => canonical_criteria.push(criterion.clone()),
                 Criterion::Asc(s) | Criterion::Desc(s) => match sorted_fields.entry(s) {
                     std::collections::hash_map::Entry::Occupied(entry) => canonicalization_actions
                         .push(CanonicalizationAction::RemovedDuplicate {
@@ -450,6 +453,7 @@ impl RankingRules {
                         })
                     }
                 }
+                Criterion::Function(_) => canonical_criteria.push(criterion),
                 Criterion::Asc(s) | Criterion::Desc(s) => match sorted_fields.entry(s) {
                     std::collections::hash_map::Entry::Occupied(entry) => canonicalization_actions
                         .push(CanonicalizationAction::RemovedDuplicate {
@@ -566,6 +570,39 @@ removed_occurrence.rule_position()
     }
 }
 
+/// Returns the ranking rules to use for a search given the `rankingRules` of the query.
+///
+/// The ranking rules of the query can only reorder or drop the ranking rules of the settings,
+/// with the exception of the `sort` ranking rule that can be placed anywhere.
+pub fn override_criteria(
+    settings_criteria: &[Criterion],
+    query_ranking_rules: &[RankingRuleView],
+) -> Result<Vec<Criterion>, ResponseError> {
+    let mut criteria: Vec<Criterion> = Vec::with_capacity(query_ranking_rules.len());
+    for (index, ranking_rule) in query_ranking_rules.iter().enumerate() {
+        let criterion = Criterion::from(ranking_rule.clone());
+        if let Some(previous_index) = criteria.iter().position(|c| *c == criterion) {
+            return Err(ResponseError::from_msg(
+                format!(
+                    "Invalid value in `rankingRules`: the rule `{criterion}` at position #{index} already appears at position #{previous_index}."
+                ),
+                Code::InvalidSearchRankingRules,
+            ));
+        }
+        if criterion != Criterion::Sort && !settings_criteria.contains(&criterion) {
+            return Err(ResponseError::from_msg(
+                format!(
+                    "Invalid value in `rankingRules`: the rule `{criterion}` at position #{index} is not one of the ranking rules of the index: `[{}]`.\n - Hint: the ranking rules of a search can only reorder or remove the ranking rules of the index, or add the `sort` rule.",
+                    settings_criteria.iter().join(", ")
+                ),
+                Code::InvalidSearchRankingRules,
+            ));
+        }
+        criteria.push(criterion);
+    }
+    Ok(criteria)
+}
+
 fn canonicalize_sort(
     sorted_fields: &mut HashMap<String, RankingRuleSource>,
     sort_query: &[AscDesc],
@@ -723,7 +760,8 @@ impl RankingRule {
             | Criterion::Typo
             | Criterion::Proximity
             | Criterion::Attribute
-            | Criterion::Exactness => RankingRuleKind::Relevancy,
+            | Criterion::Exactness
+            | Criterion::Function(_) => RankingRuleKind::Relevancy,
             Criterion::Asc(s) if s == "_geo" => RankingRuleKind::AscendingGeoSort,
 
             Criterion::Asc(_) => RankingRuleKind::AscendingSort,
@@ -141,7 +141,7 @@ impl<'a> Index<'a, Owned> {
         self.service.get(url).await
     }
 
-    pub async fn delete_document(&self, id: u64) -> (Value, StatusCode) {
+    pub async fn delete_document(&self, id: impl std::fmt::Display) -> (Value, StatusCode) {
         let url = format!("/indexes/{}/documents/{}", urlencode(self.uid.as_ref()), id);
         self.service.delete(url).await
     }
@@ -403,7 +403,11 @@ impl<State> Index<'_, State> {
         self.service.get(url).await
     }
 
-    pub async fn get_document(&self, id: u64, options: Option<Value>) -> (Value, StatusCode) {
+    pub async fn get_document(
+        &self,
+        id: impl std::fmt::Display,
+        options: Option<Value>,
+    ) -> (Value, StatusCode) {
         let mut url = format!("/indexes/{}/documents/{}", urlencode(self.uid.as_ref()), id);
         if let Some(options) = options {
             write!(url, "{}", yaup::to_string(&options).unwrap()).unwrap();
@@ -436,6 +440,21 @@ impl<State> Index<'_, State> {
         self.service.get(url).await
     }
 
+    pub async fn settings_history(&self) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/settings/history", urlencode(self.uid.as_ref()));
+        self.service.get(url).await
+    }
+
+    pub async fn rollback_settings(&self, body: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/settings/rollback", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, body, self.encoder).await
+    }
+
+    pub async fn changes(&self, query: &str) -> (String, StatusCode) {
+        let url = format!("/indexes/{}/changes?{query}", urlencode(self.uid.as_ref()));
+        self.service.get_raw(url).await
+    }
+
     pub async fn stats(&self) -> (Value, StatusCode) {
         let url = format!("/indexes/{}/stats", urlencode(self.uid.as_ref()));
         self.service.get(url).await
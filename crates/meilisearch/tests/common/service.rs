@@ -163,6 +163,22 @@ impl Service {
         (response, status_code)
     }
 
+    /// Sends a get request and returns the body of the response as is,
+    /// for the routes that do not answer with a single JSON value.
+    pub async fn get_raw(&self, url: impl AsRef<str>) -> (String, StatusCode) {
+        let app = self.init_web_app().await;
+
+        let mut req = test::TestRequest::get().uri(url.as_ref());
+        if let Some(api_key) = &self.api_key {
+            req = req.insert_header(("Authorization", ["Bearer ", api_key].concat()));
+        }
+        let res = test::call_service(&app, req.to_request()).await;
+        let status_code = res.status();
+
+        let body = test::read_body(res).await;
+        (String::from_utf8(body.to_vec()).unwrap(), status_code)
+    }
+
     fn encode(&self, req: TestRequest, body: Value, encoder: Encoder) -> TestRequest {
         let bytes = serde_json::to_string(&body).expect("Failed to serialize test data to json");
         let encoded_body = encoder.encode(bytes);
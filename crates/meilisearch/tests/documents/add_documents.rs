@@ -2561,3 +2561,561 @@ async fn dry_register_file() {
     "###);
     snapshot!(code, @"404 Not Found");
 }
+
+#[actix_rt::test]
+async fn add_documents_with_version_attribute() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.update_settings(json!({ "versionAttribute": "version" })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "id": 1, "title": "Carol", "version": 2 },
+        { "id": 2, "title": "Dune", "version": 1 },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    let response = index.wait_task(task.uid()).await;
+    response.succeeded();
+    snapshot!(json_string!(response["details"]), @r###"
+    {
+      "receivedDocuments": 2,
+      "indexedDocuments": 2,
+      "skippedDocuments": 0
+    }
+    "###);
+
+    // the first document is outdated and the second one is sent twice
+    let documents = json!([
+        { "id": 1, "title": "Carol (old)", "version": 1 },
+        { "id": 2, "title": "Dune (new)", "version": 3 },
+        { "id": 2, "title": "Dune (newer)", "version": 2 },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    let response = index.wait_task(task.uid()).await;
+    response.succeeded();
+    snapshot!(json_string!(response["details"]), @r###"
+    {
+      "receivedDocuments": 3,
+      "indexedDocuments": 1,
+      "skippedDocuments": 2
+    }
+    "###);
+
+    let (response, _code) = index.get_document(1, None).await;
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 1,
+      "title": "Carol",
+      "version": 2
+    }
+    "###);
+    let (response, _code) = index.get_document(2, None).await;
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 2,
+      "title": "Dune (new)",
+      "version": 3
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn add_documents_with_nested_version_attribute() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.update_settings(json!({ "versionAttribute": "meta.version" })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) =
+        index.add_documents(json!([{ "id": 1, "meta": { "version": 2 } }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "id": 1, "title": "old", "meta": { "version": 1 } },
+        { "id": 1, "title": "new", "meta.version": 3 },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    let response = index.wait_task(task.uid()).await;
+    response.succeeded();
+    snapshot!(json_string!(response["details"]), @r###"
+    {
+      "receivedDocuments": 2,
+      "indexedDocuments": 1,
+      "skippedDocuments": 1
+    }
+    "###);
+
+    let (response, _code) = index.get_document(1, None).await;
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 1,
+      "title": "new",
+      "meta.version": 3
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn error_add_documents_invalid_version() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.update_settings(json!({ "versionAttribute": "version" })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = index.add_documents(json!([{ "id": 1, "version": -1 }]), None).await;
+    let response = index.wait_task(task.uid()).await;
+    snapshot!(json_string!(response["error"]), @r###"
+    {
+      "message": "The version attribute `version` of the document `1` must be a positive integer, but found `-1`.",
+      "code": "invalid_document_version",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_document_version"
+    }
+    "###);
+
+    let (task, _code) = index.add_documents(json!([{ "id": 1 }]), None).await;
+    let response = index.wait_task(task.uid()).await;
+    snapshot!(json_string!(response["error"]["message"]), @r###""The version attribute `version` of the document `1` must be a positive integer, but found `nothing`.""###);
+}
+
+#[actix_rt::test]
+async fn add_documents_with_auto_generated_ids() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.update_settings(json!({ "autoGenerateIds": true })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // there is no primary key candidate, the default one is used
+    let documents = json!([
+        { "title": "Carol" },
+        { "id": "dune", "title": "Dune" },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    let response = index.wait_task(task.uid()).await;
+    response.succeeded();
+    snapshot!(json_string!(response["details"], { ".generatedDocumentIds[]" => "[uuid]" }), @r###"
+    {
+      "receivedDocuments": 2,
+      "indexedDocuments": 2,
+      "generatedDocumentIds": [
+        "[uuid]"
+      ]
+    }
+    "###);
+
+    let generated_id = response["details"]["generatedDocumentIds"][0].as_str().unwrap().to_string();
+    let (response, _code) = index.get().await;
+    snapshot!(response["primaryKey"], @r###""id""###);
+    let (response, code) = index.get_all_documents_raw("").await;
+    snapshot!(code, @"200 OK");
+    assert!(response["results"]
+        .as_array()
+        .unwrap()
+        .contains(&json!({ "id": generated_id, "title": "Carol" })));
+
+    // documents with an id are not reported
+    let (task, _code) =
+        index.add_documents(json!([{ "id": "carol", "title": "Carol" }]), None).await;
+    let response = index.wait_task(task.uid()).await;
+    response.succeeded();
+    snapshot!(json_string!(response["details"]), @r###"
+    {
+      "receivedDocuments": 1,
+      "indexedDocuments": 1,
+      "generatedDocumentIds": []
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn add_documents_with_auto_generated_ids_query_parameter() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.create(Some("id")).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = r#"[{ "title": "Carol" }, { "id": "dune", "title": "Dune" }]"#;
+    let (task, code) = index
+        .raw_add_documents(
+            documents,
+            vec![("Content-Type", "application/json")],
+            "?autoGenerateId=true",
+        )
+        .await;
+    snapshot!(code, @"202 Accepted");
+    let response = index.wait_task(task.uid()).await;
+    response.succeeded();
+    snapshot!(json_string!(response["details"], { ".generatedDocumentIds[]" => "[uuid]" }), @r###"
+    {
+      "receivedDocuments": 2,
+      "indexedDocuments": 2,
+      "generatedDocumentIds": [
+        "[uuid]"
+      ]
+    }
+    "###);
+
+    // without the parameter the index still requires the ids
+    let (task, _code) = index.add_documents(json!([{ "title": "Hyperion" }]), None).await;
+    let response = index.wait_task(task.uid()).await;
+    snapshot!(response["error"]["code"], @r###""missing_document_id""###);
+
+    let (response, code) = index
+        .raw_add_documents(
+            documents,
+            vec![("Content-Type", "application/json")],
+            "?autoGenerateId=maybe",
+        )
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value in parameter `autoGenerateId`: could not parse `maybe` as a boolean, expected either `true` or `false`",
+      "code": "invalid_document_auto_generate_id",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_document_auto_generate_id"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn add_documents_with_composite_primary_key() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (task, _code) = index.create(Some("sku,locale")).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "sku": "a-42", "locale": "en-US", "title": "Hat" },
+        { "sku": "a-42", "locale": "fr-FR", "title": "Chapeau" },
+        { "sku": 43, "locale": "en-US", "title": "Scarf" },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // the external id is made of the values of the fields joined by `:`
+    let (response, code) = index.get_document("a-42:fr-FR", None).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response), @r###"
+    {
+      "sku": "a-42",
+      "locale": "fr-FR",
+      "title": "Chapeau"
+    }
+    "###);
+
+    let (task, _code) = index.delete_document("43:en-US").await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, code) = index.get_document("43:en-US", None).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response["code"], @r###""document_not_found""###);
+
+    let (task, _code) =
+        index.add_documents(json!([{ "sku": "a-43", "title": "Boots" }]), None).await;
+    let response = index.wait_task(task.uid()).await;
+    snapshot!(response["error"]["message"], @r###""Document doesn't have a `locale` attribute: `{\"sku\":\"a-43\",\"title\":\"Boots\"}`.""###);
+}
+
+#[actix_rt::test]
+async fn add_documents_with_document_schema() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let schema = json!({
+        "fields": {
+            "title": { "type": "string", "required": true },
+            "price": { "type": "number" },
+        }
+    });
+    let (task, _code) = index.update_settings(json!({ "documentSchema": schema })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (response, _code) = index.settings().await;
+    snapshot!(json_string!(response["documentSchema"]), @r###"
+    {
+      "mode": "strict",
+      "fields": {
+        "price": {
+          "type": "number",
+          "required": false
+        },
+        "title": {
+          "type": "string",
+          "required": true
+        }
+      }
+    }
+    "###);
+
+    let documents = json!([
+        { "id": 1, "title": "Hat", "price": 10 },
+        { "id": 2, "title": "Scarf", "price": "12" },
+        { "id": 3, "price": 5 },
+    ]);
+    let (task, _code) = index.add_documents(documents.clone(), None).await;
+    let response = index.wait_task(task.uid()).await;
+    snapshot!(json_string!(response["error"]), @r###"
+    {
+      "message": "2 document(s) do not conform to the document schema of the index: document `2`: the `price` field must be a number but found `\"12\"`; document `3`: the `title` field is required.",
+      "code": "invalid_document_schema",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_document_schema"
+    }
+    "###);
+
+    // partial updates don't have to contain the required fields
+    let (task, _code) = index.update_documents(json!([{ "id": 3, "price": 5 }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let schema =
+        json!({ "mode": "lenient", "fields": { "title": { "type": "string", "required": true } } });
+    let (task, _code) = index.update_settings(json!({ "documentSchema": schema })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "id": 1, "title": "Hat" },
+        { "id": 2, "title": ["Scarf"] },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    let response = index.wait_task(task.uid()).await;
+    response.succeeded();
+    snapshot!(json_string!(response["details"]), @r###"
+    {
+      "receivedDocuments": 2,
+      "indexedDocuments": 1,
+      "rejectedDocuments": [
+        "document `2`: the `title` field must be a string but found `[\"Scarf\"]`"
+      ]
+    }
+    "###);
+
+    // the ids generated for the rejected documents are not reported
+    let documents = r#"[{ "title": "Gloves" }, { "title": ["Socks"] }]"#;
+    let (task, _code) = index
+        .raw_add_documents(
+            documents,
+            vec![("Content-Type", "application/json")],
+            "?autoGenerateId=true",
+        )
+        .await;
+    let response = index.wait_task(task.uid()).await;
+    response.succeeded();
+    snapshot!(json_string!(response["details"], { ".generatedDocumentIds[]" => "[uuid]", ".rejectedDocuments[]" => "[rejected]" }), @r###"
+    {
+      "receivedDocuments": 2,
+      "indexedDocuments": 1,
+      "generatedDocumentIds": [
+        "[uuid]"
+      ],
+      "rejectedDocuments": [
+        "[rejected]"
+      ]
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn add_documents_with_ingest_pipeline() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let pipeline = json!([
+        { "type": "rename", "field": "ref", "to": "id" },
+        { "type": "split", "field": "tags", "separator": "," },
+        { "type": "lowercase", "field": "tags" },
+        { "type": "parseDate", "field": "releaseDate" },
+        { "type": "setDefault", "field": "stock", "value": 0 },
+        { "type": "geo", "lat": "latitude", "lng": "longitude" },
+        { "type": "remove", "field": "latitude" },
+        { "type": "remove", "field": "longitude" },
+    ]);
+    let (task, _code) = index.update_settings(json!({ "ingestPipeline": pipeline })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        {
+            "ref": 1,
+            "title": "Dune",
+            "tags": "SF, Classic",
+            "releaseDate": "1965-08-01",
+            "latitude": 48.8,
+            "longitude": 2.3,
+        },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) = index.get_document(1, None).await;
+    snapshot!(json_string!(response), @r###"
+    {
+      "title": "Dune",
+      "tags": [
+        "sf",
+        "classic"
+      ],
+      "releaseDate": -139449600,
+      "id": 1,
+      "stock": 0,
+      "_geo": {
+        "lat": 48.8,
+        "lng": 2.3
+      }
+    }
+    "###);
+
+    // a partial update only goes through the pipeline for the fields it contains
+    let (task, _code) = index.update_documents(json!([{ "ref": 1, "stock": 12 }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) =
+        index.update_documents(json!([{ "ref": 1, "tags": "Space, Opera" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) =
+        index.get_document(1, Some(json!({ "fields": ["tags", "stock"] }))).await;
+    snapshot!(json_string!(response), @r###"
+    {
+      "tags": [
+        "space",
+        "opera"
+      ],
+      "stock": 12
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn error_invalid_ingest_pipeline() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (response, code) = index
+        .update_settings(json!({ "ingestPipeline": [{ "type": "uppercase", "field": "title" }] }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_settings_ingest_pipeline""###);
+}
+
+#[actix_rt::test]
+async fn add_documents_with_computed_fields() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (task, _code) = index
+        .update_settings(json!({
+            "filterableAttributes": ["discount_ratio"],
+            "computedFields": { "discount_ratio": "doc.price / doc.msrp" },
+        }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "id": 1, "price": 6.0, "msrp": 8.0 },
+        { "id": 2, "price": 9.0, "msrp": 10.0 },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // the fields are computed on the whole document when it is partially updated
+    let (task, _code) = index.update_documents(json!([{ "id": 1, "price": 4.0 }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) = index.get_document(1, None).await;
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 1,
+      "price": 4.0,
+      "msrp": 8.0,
+      "discount_ratio": 0.5
+    }
+    "###);
+
+    let (response, code) =
+        index.search_post(json!({ "filter": "discount_ratio > 0.6", "q": "" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 2,
+        "price": 9.0,
+        "msrp": 10.0,
+        "discount_ratio": 0.9
+      }
+    ]
+    "###);
+
+    let (task, _code) = index.add_documents(json!([{ "id": 3, "price": "cheap" }]), None).await;
+    let response = index.wait_task(task.uid()).await;
+    snapshot!(response["error"]["code"], @r###""invalid_computed_field""###);
+}
+
+#[actix_rt::test]
+async fn computed_fields_are_reevaluated_on_the_existing_documents() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    server.set_features(json!({ "editDocumentsByFunction": true })).await;
+
+    let (task, _code) = index
+        .update_settings(json!({
+            "filterableAttributes": ["discount_ratio", "discount"],
+            "computedFields": { "discount_ratio": "doc.price / doc.msrp" },
+        }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) =
+        index.add_documents(json!([{ "id": 1, "price": 6.0, "msrp": 8.0 }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // the documents are reindexed with the new computed fields
+    let (task, _code) = index
+        .update_settings(json!({ "computedFields": { "discount": "doc.msrp - doc.price" } }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) = index.get_document(1, None).await;
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 1,
+      "price": 6.0,
+      "msrp": 8.0,
+      "discount": 2.0
+    }
+    "###);
+    let (response, _code) = index.search_post(json!({ "filter": "discount = 2" })).await;
+    snapshot!(response["hits"].as_array().unwrap().len(), @"1");
+    let (response, _code) = index.search_post(json!({ "filter": "discount_ratio EXISTS" })).await;
+    snapshot!(response["hits"].as_array().unwrap().len(), @"0");
+
+    // the edited documents are computed again too
+    let (task, _code) = index
+        .service
+        .post("/indexes/test/documents/edit", json!({ "function": "doc.price = 5.0" }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) = index.get_document(1, None).await;
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 1,
+      "price": 5.0,
+      "msrp": 8.0,
+      "discount": 3.0
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn error_invalid_computed_fields() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (task, _code) =
+        index.update_settings(json!({ "computedFields": { "ratio": "doc.price / " } })).await;
+    let response = index.wait_task(task.uid()).await;
+    snapshot!(response["status"], @r###""failed""###);
+    snapshot!(response["error"]["code"], @r###""invalid_settings_computed_fields""###);
+}
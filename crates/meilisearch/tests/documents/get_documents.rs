@@ -526,7 +526,8 @@ async fn get_document_with_vectors() {
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
@@ -298,3 +298,54 @@ async fn update_faceted_document() {
         })
         .await;
 }
+
+#[actix_rt::test]
+async fn patch_documents() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let documents = json!([
+        {
+            "id": 1,
+            "tags": ["fantasy", "old"],
+            "stats": { "views": 10, "likes": 2 },
+            "meta": { "author": "Tolkien", "year": 1954 },
+        }
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let patch = r#"[{
+        "id": 1,
+        "tags": { "$append": "classic" },
+        "stats": { "views": { "$increment": 5 } },
+        "meta": { "year": 1955, "publisher": "Allen & Unwin" }
+    }, {
+        "id": 1,
+        "tags": { "$remove": "old" },
+        "stats": { "likes": null }
+    }]"#;
+    let (task, code) =
+        index.raw_update_documents(patch, Some("application/merge-patch+json"), "").await;
+    snapshot!(code, @"202 Accepted");
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) = index.get_document(1, None).await;
+    snapshot!(json_string!(response), @r###"
+    {
+      "id": 1,
+      "tags": [
+        "fantasy",
+        "classic"
+      ],
+      "stats": {
+        "views": 15
+      },
+      "meta": {
+        "author": "Tolkien",
+        "year": 1955,
+        "publisher": "Allen & Unwin"
+      }
+    }
+    "###);
+}
@@ -67,7 +67,9 @@ async fn import_dump_v1_movie_raw() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -79,7 +81,15 @@ async fn import_dump_v1_movie_raw() {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###
     );
@@ -230,7 +240,9 @@ async fn import_dump_v1_movie_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -242,7 +254,15 @@ async fn import_dump_v1_movie_with_settings() {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###
     );
@@ -379,7 +399,9 @@ async fn import_dump_v1_rubygems_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -391,7 +413,15 @@ async fn import_dump_v1_rubygems_with_settings() {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###
     );
@@ -514,7 +544,9 @@ async fn import_dump_v2_movie_raw() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -526,7 +558,15 @@ async fn import_dump_v2_movie_raw() {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###
     );
@@ -661,7 +701,9 @@ async fn import_dump_v2_movie_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -673,7 +715,15 @@ async fn import_dump_v2_movie_with_settings() {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###
     );
@@ -807,7 +857,9 @@ async fn import_dump_v2_rubygems_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -819,7 +871,15 @@ async fn import_dump_v2_rubygems_with_settings() {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###
     );
@@ -942,7 +1002,9 @@ async fn import_dump_v3_movie_raw() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -954,7 +1016,15 @@ async fn import_dump_v3_movie_raw() {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###
     );
@@ -1089,7 +1159,9 @@ async fn import_dump_v3_movie_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -1101,7 +1173,15 @@ async fn import_dump_v3_movie_with_settings() {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###
     );
@@ -1235,7 +1315,9 @@ async fn import_dump_v3_rubygems_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -1247,7 +1329,15 @@ async fn import_dump_v3_rubygems_with_settings() {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###
     );
@@ -1370,7 +1460,9 @@ async fn import_dump_v4_movie_raw() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -1382,7 +1474,15 @@ async fn import_dump_v4_movie_raw() {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###
     );
@@ -1517,7 +1617,9 @@ async fn import_dump_v4_movie_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -1529,7 +1631,15 @@ async fn import_dump_v4_movie_with_settings() {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###
     );
@@ -1663,7 +1773,9 @@ async fn import_dump_v4_rubygems_with_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -1675,7 +1787,15 @@ async fn import_dump_v4_rubygems_with_settings() {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###
     );
@@ -1873,7 +1993,8 @@ async fn import_dump_v6_containing_experimental_features() {
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
@@ -1910,7 +2031,9 @@ async fn import_dump_v6_containing_experimental_features() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -1922,7 +2045,15 @@ async fn import_dump_v6_containing_experimental_features() {
         "maxTotalHits": 1000
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###);
 
@@ -1969,7 +2100,8 @@ async fn generate_and_import_dump_containing_vectors() {
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
     let index = server.index("pets");
@@ -2041,7 +2173,8 @@ async fn generate_and_import_dump_containing_vectors() {
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
@@ -2081,7 +2214,9 @@ async fn generate_and_import_dump_containing_vectors() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -2102,7 +2237,15 @@ async fn generate_and_import_dump_containing_vectors() {
         }
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###);
 
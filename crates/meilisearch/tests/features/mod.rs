@@ -22,7 +22,8 @@ async fn experimental_features() {
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
@@ -35,7 +36,8 @@ async fn experimental_features() {
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
@@ -48,7 +50,8 @@ async fn experimental_features() {
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
@@ -62,7 +65,8 @@ async fn experimental_features() {
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
@@ -76,7 +80,8 @@ async fn experimental_features() {
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 }
@@ -97,7 +102,8 @@ async fn experimental_feature_metrics() {
       "metrics": true,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
@@ -142,6 +148,62 @@ async fn experimental_feature_metrics() {
     meili_snap::snapshot!(response, @"null");
 }
 
+#[actix_rt::test]
+async fn experimental_feature_read_only() {
+    let dir = TempDir::new().unwrap();
+    let server = Server::new_with_options(default_settings(dir.path())).await.unwrap();
+    let index = server.index("doggos");
+
+    let (task, _code) = index.add_documents(json!([{ "id": 1, "doggo": "kefir" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = server.set_features(json!({"readOnly": true})).await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(response["readOnly"], @"true");
+
+    // the routes creating a task are refused
+    let (response, code) = index.add_documents(json!([{ "id": 2, "doggo": "bork" }]), None).await;
+    meili_snap::snapshot!(code, @"403 Forbidden");
+    meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
+    {
+      "message": "This instance is in read-only mode and cannot register tasks. Disable the `readOnly` experimental feature to perform write operations again.",
+      "code": "read_only_instance",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#read_only_instance"
+    }
+    "###);
+    let (response, code) = server.create_dump().await;
+    meili_snap::snapshot!(code, @"403 Forbidden");
+    meili_snap::snapshot!(response["code"], @r###""read_only_instance""###);
+
+    // but reading is still possible
+    let (response, code) = index.search_post(json!({ "q": "kefir" })).await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(response["hits"].as_array().unwrap().len(), @"1");
+    let (_response, code) = index.get_all_documents(Default::default()).await;
+    meili_snap::snapshot!(code, @"200 OK");
+    let (_response, code) = server.stats().await;
+    meili_snap::snapshot!(code, @"200 OK");
+
+    let (response, code) = server.set_features(json!({"readOnly": false})).await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(response["readOnly"], @"false");
+
+    let (task, code) = index.add_documents(json!([{ "id": 2, "doggo": "bork" }]), None).await;
+    meili_snap::snapshot!(code, @"202 Accepted");
+    index.wait_task(task.uid()).await.succeeded();
+
+    // the instance flag enables the read-only mode at startup
+    drop(server);
+    let read_only = Opt { read_only: true, ..default_settings(dir.path()) };
+    let server = Server::new_with_options(read_only).await.unwrap();
+    let (response, _code) = server.get_features().await;
+    meili_snap::snapshot!(response["readOnly"], @"true");
+    let (response, code) = server.index("doggos").delete().await;
+    meili_snap::snapshot!(code, @"403 Forbidden");
+    meili_snap::snapshot!(response["code"], @r###""read_only_instance""###);
+}
+
 #[actix_rt::test]
 async fn errors() {
     let server = Server::new().await;
@@ -152,7 +214,7 @@ async fn errors() {
     meili_snap::snapshot!(code, @"400 Bad Request");
     meili_snap::snapshot!(meili_snap::json_string!(response), @r###"
     {
-      "message": "Unknown field `NotAFeature`: expected one of `vectorStore`, `metrics`, `logsRoute`, `editDocumentsByFunction`, `containsFilter`",
+      "message": "Unknown field `NotAFeature`: expected one of `vectorStore`, `metrics`, `logsRoute`, `editDocumentsByFunction`, `containsFilter`, `readOnly`",
       "code": "bad_request",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#bad_request"
@@ -0,0 +1,104 @@
+use meili_snap::snapshot;
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn changes_are_only_recorded_when_the_changelog_is_enabled() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.add_documents(json!([{ "id": 1, "title": "Carol" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.changes("").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response, @"");
+
+    let (task, _code) = index.update_settings(json!({ "changelog": true })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = index
+        .add_documents(json!([{ "id": 1, "title": "Carol" }, { "id": 2, "title": "Dune" }]), None)
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index.delete_document(1).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.changes("").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response, @r###"
+    {"taskUid":2,"documentId":"1","operation":"update"}
+    {"taskUid":2,"documentId":"2","operation":"add"}
+    {"taskUid":3,"documentId":"1","operation":"delete"}
+    "###);
+}
+
+#[actix_rt::test]
+async fn changes_since_a_task() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, _code) = index.update_settings(json!({ "changelog": true })).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = index.add_documents(json!([{ "id": 1 }, { "id": 2 }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index.delete_batch(vec![1, 3]).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index.clear_all_documents().await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) = index.changes("since=1").await;
+    snapshot!(response, @r###"
+    {"taskUid":2,"documentId":"1","operation":"delete"}
+    {"taskUid":3,"documentId":"2","operation":"delete"}
+    "###);
+
+    let (response, _code) = index.changes("since=3").await;
+    snapshot!(response, @"");
+}
+
+#[actix_rt::test]
+async fn changes_are_recorded_for_edit_documents_by_function() {
+    let server = Server::new().await;
+    let index = server.index("test");
+    server.set_features(json!({ "editDocumentsByFunction": true })).await;
+
+    let (task, _code) = index.update_settings(json!({ "changelog": true })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index.add_documents(json!([{ "id": 1 }, { "id": 2 }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = index
+        .service
+        .post(
+            "/indexes/test/documents/edit",
+            json!({ "function": "if doc.id == 1 { doc = () } else { doc.title = \"Dune\" }" }),
+        )
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) = index.changes("since=1").await;
+    snapshot!(response, @r###"
+    {"taskUid":2,"documentId":"1","operation":"delete"}
+    {"taskUid":2,"documentId":"2","operation":"update"}
+    "###);
+}
+
+#[actix_rt::test]
+async fn changes_errors() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.changes("").await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response, @r###"{"message":"Index `test` not found.","code":"index_not_found","type":"invalid_request","link":"https://docs.meilisearch.com/errors#index_not_found"}"###);
+
+    let (task, _code) = index.create(None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.changes("since=yesterday").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response, @r###"{"message":"Invalid value in parameter `since`: could not parse `yesterday` as a positive integer","code":"invalid_index_changes_since","type":"invalid_request","link":"https://docs.meilisearch.com/errors#invalid_index_changes_since"}"###);
+}
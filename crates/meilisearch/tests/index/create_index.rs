@@ -214,6 +214,31 @@ async fn error_create_with_invalid_index_uid() {
     "###);
 }
 
+#[actix_rt::test]
+async fn error_create_with_invalid_composite_primary_key() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+    let (response, code) = index.create(Some("sku,,locale")).await;
+
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "The composite primary key `sku,,locale` is invalid: a field name cannot be empty.",
+      "code": "invalid_index_primary_key",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_index_primary_key"
+    }
+    "###);
+
+    let (response, code) = index.create(Some("sku,locale,sku")).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["message"], @r###""The composite primary key `sku,locale,sku` is invalid: a field cannot be used more than once.""###);
+
+    let (response, code) = index.create(Some("sku, locale")).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["message"], @r###""The composite primary key `sku, locale` is invalid: a field name cannot start or end with whitespace.""###);
+}
+
 #[actix_rt::test]
 async fn send_task_id() {
     let temp = tempfile::tempdir().unwrap();
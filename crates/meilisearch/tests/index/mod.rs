@@ -1,3 +1,4 @@
+mod changes;
 mod create_index;
 mod delete_index;
 mod errors;
@@ -20,7 +20,8 @@ async fn index_with_documents_user_provided<'a>(
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
@@ -50,7 +51,8 @@ async fn index_with_documents_hf<'a>(server: &'a Server, documents: &Value) -> I
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
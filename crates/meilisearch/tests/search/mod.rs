@@ -436,6 +436,218 @@ async fn search_with_multiple_sort() {
     assert_eq!(response["hits"].as_array().unwrap().len(), 5);
 }
 
+#[actix_rt::test]
+async fn search_with_ranking_rules() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (task, _code) = index
+        .update_settings(json!({
+            "rankingRules": ["words", "rank:desc"],
+            "sortableAttributes": ["price"],
+        }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "id": 0, "text": "the book", "rank": 3, "price": 20 },
+        { "id": 1, "text": "the red book", "rank": 1, "price": 30 },
+        { "id": 2, "text": "book", "rank": 2, "price": 10 },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) =
+        index.search_post(json!({ "q": "book red", "attributesToRetrieve": ["id"] })).await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1
+      },
+      {
+        "id": 0
+      },
+      {
+        "id": 2
+      }
+    ]
+    "###);
+
+    let (response, code) = index
+        .search_post(json!({
+            "q": "book red",
+            "rankingRules": ["rank:desc"],
+            "attributesToRetrieve": ["id"],
+        }))
+        .await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 0
+      },
+      {
+        "id": 2
+      },
+      {
+        "id": 1
+      }
+    ]
+    "###);
+
+    let (response, code) = index
+        .search_get("?q=book%20red&rankingRules=sort,words&sort=price:asc&attributesToRetrieve=id")
+        .await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 2
+      },
+      {
+        "id": 0
+      },
+      {
+        "id": 1
+      }
+    ]
+    "###);
+}
+
+#[actix_rt::test]
+async fn search_get_with_function_ranking_rules() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (task, _code) = index
+        .update_settings(json!({
+            "rankingRules": ["words", "function(max(rank, bonus))"],
+            "sortableAttributes": ["rank", "bonus"],
+        }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "id": 0, "text": "the book", "rank": 30, "bonus": 1 },
+        { "id": 1, "text": "the red book", "rank": 10, "bonus": 50 },
+        { "id": 2, "text": "book", "rank": 20, "bonus": 0 },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // the commas of the function rules don't separate the ranking rules
+    let (response, code) = index
+        .search_get(
+            "?q=book&rankingRules=function(max(rank,%20bonus)),words&attributesToRetrieve=id",
+        )
+        .await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1
+      },
+      {
+        "id": 0
+      },
+      {
+        "id": 2
+      }
+    ]
+    "###);
+}
+
+#[actix_rt::test]
+async fn search_with_date_attributes() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (task, _code) = index
+        .update_settings(json!({
+            "filterableAttributes": ["published_at"],
+            "sortableAttributes": ["published_at"],
+            "dateAttributes": ["published_at"],
+        }))
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let documents = json!([
+        { "id": 1, "published_at": "2023-06-15" },
+        { "id": 2, "published_at": "2024-01-01T12:00:00+02:00" },
+        { "id": 3, "published_at": "2024-03-10T08:30:00Z" },
+        { "id": 4, "published_at": "not a date" },
+        { "id": 5, "published_at": "9999-12-31T23:59:59Z" },
+    ]);
+    let (task, _code) = index.add_documents(documents, None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index
+        .search_post(json!({
+            "filter": "published_at > 2024-01-01T00:00:00Z",
+            "sort": ["published_at:asc"],
+            "attributesToRetrieve": ["id"],
+        }))
+        .await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 2
+      },
+      {
+        "id": 3
+      },
+      {
+        "id": 5
+      }
+    ]
+    "###);
+
+    let (response, code) = index
+        .search_post(json!({
+            "filter": "published_at 2023-01-01 TO now",
+            "attributesToRetrieve": ["id"],
+        }))
+        .await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1
+      },
+      {
+        "id": 2
+      },
+      {
+        "id": 3
+      }
+    ]
+    "###);
+
+    let (response, code) = index
+        .search_post(json!({
+            "filter": "published_at < 2024-03-01",
+            "facets": ["published_at"],
+            "attributesToRetrieve": ["id"],
+        }))
+        .await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["facetStats"]), @r###"
+    {
+      "published_at": {
+        "min": "2023-06-15T00:00:00Z",
+        "max": "2024-01-01T10:00:00Z"
+      }
+    }
+    "###);
+
+    let (response, code) =
+        index.search_post(json!({ "filter": "published_at > 2024-13-01" })).await;
+    meili_snap::snapshot!(code, @"400 Bad Request");
+    meili_snap::snapshot!(response["message"], @r###""The date `2024-13-01` is malformed, it must be an RFC 3339 date-time, a `YYYY-MM-DD` date or a date relative to `now` like `now-7d`.\n16:26 published_at > 2024-13-01""###);
+}
+
 #[actix_rt::test]
 async fn search_facet_distribution() {
     let index = shared_index_with_documents().await;
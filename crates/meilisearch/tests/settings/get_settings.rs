@@ -37,6 +37,14 @@ static DEFAULT_SETTINGS_VALUES: Lazy<HashMap<&'static str, Value>> = Lazy::new(|
         }),
     );
     map.insert("search_cutoff_ms", json!(null));
+    map.insert("changelog", json!(false));
+    map.insert("version_attribute", json!(null));
+    map.insert("auto_generate_ids", json!(false));
+    map.insert("document_schema", json!(null));
+    map.insert("ingest_pipeline", json!([]));
+    map.insert("computed_fields", json!({}));
+    map.insert("date_attributes", json!([]));
+    map.insert("searchable_attributes_weights", json!({}));
     map
 });
 
@@ -56,7 +64,7 @@ async fn get_settings() {
     let (response, code) = index.settings().await;
     assert_eq!(code, 200);
     let settings = response.as_object().unwrap();
-    assert_eq!(settings.keys().len(), 17);
+    assert_eq!(settings.keys().len(), 25);
     assert_eq!(settings["displayedAttributes"], json!(["*"]));
     assert_eq!(settings["searchableAttributes"], json!(["*"]));
     assert_eq!(settings["filterableAttributes"], json!([]));
@@ -87,6 +95,14 @@ async fn get_settings() {
     );
     assert_eq!(settings["proximityPrecision"], json!("byWord"));
     assert_eq!(settings["searchCutoffMs"], json!(null));
+    assert_eq!(settings["changelog"], json!(false));
+    assert_eq!(settings["versionAttribute"], json!(null));
+    assert_eq!(settings["autoGenerateIds"], json!(false));
+    assert_eq!(settings["documentSchema"], json!(null));
+    assert_eq!(settings["ingestPipeline"], json!([]));
+    assert_eq!(settings["computedFields"], json!({}));
+    assert_eq!(settings["dateAttributes"], json!([]));
+    assert_eq!(settings["searchableAttributesWeights"], json!({}));
 }
 
 #[actix_rt::test]
@@ -101,7 +117,8 @@ async fn secrets_are_hidden_in_settings() {
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
@@ -174,7 +191,9 @@ async fn secrets_are_hidden_in_settings() {
           "twoTypos": 9
         },
         "disableOnWords": [],
-        "disableOnAttributes": []
+        "disableOnAttributes": [],
+        "phoneticOnAttributes": [],
+        "phoneticAlgorithm": "doubleMetaphone"
       },
       "faceting": {
         "maxValuesPerFacet": 100,
@@ -199,7 +218,15 @@ async fn secrets_are_hidden_in_settings() {
         }
       },
       "searchCutoffMs": null,
-      "localizedAttributes": null
+      "localizedAttributes": null,
+      "changelog": false,
+      "versionAttribute": null,
+      "autoGenerateIds": false,
+      "documentSchema": null,
+      "ingestPipeline": [],
+      "computedFields": {},
+      "dateAttributes": [],
+      "searchableAttributesWeights": {}
     }
     "###);
 
@@ -422,7 +449,15 @@ test_setting_routes!(
     synonyms put,
     pagination patch,
     faceting patch,
-    search_cutoff_ms put
+    search_cutoff_ms put,
+    changelog put,
+    version_attribute put,
+    auto_generate_ids put,
+    document_schema put,
+    ingest_pipeline put,
+    computed_fields put,
+    date_attributes put,
+    searchable_attributes_weights put
 );
 
 #[actix_rt::test]
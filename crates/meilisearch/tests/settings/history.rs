@@ -0,0 +1,116 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn settings_updates_are_recorded_in_the_history() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, _code) =
+        index.update_settings(json!({ "filterableAttributes": ["genre"] })).await;
+    index.wait_task(response.uid()).await.succeeded();
+    let (response, _code) = index.update_settings(json!({ "sortableAttributes": ["year"] })).await;
+    index.wait_task(response.uid()).await.succeeded();
+
+    let (response, code) = index.settings_history().await;
+    snapshot!(code, @"200 OK");
+    let results = response["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+
+    // the most recent version comes first
+    assert_eq!(results[0]["version"], json!(2));
+    assert_eq!(results[0]["taskUid"], json!(1));
+    assert_eq!(results[0]["settings"]["filterableAttributes"], json!(["genre"]));
+    assert_eq!(results[0]["settings"]["sortableAttributes"], json!(["year"]));
+
+    assert_eq!(results[1]["version"], json!(1));
+    assert_eq!(results[1]["taskUid"], json!(0));
+    assert_eq!(results[1]["settings"]["filterableAttributes"], json!(["genre"]));
+    assert_eq!(results[1]["settings"]["sortableAttributes"], json!([]));
+
+    // the settings the index had before its first update
+    assert_eq!(results[2]["version"], json!(0));
+    assert_eq!(results[2]["taskUid"], json!(null));
+    assert_eq!(results[2]["settings"]["filterableAttributes"], json!([]));
+}
+
+#[actix_rt::test]
+async fn rollback_restores_a_previous_version() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, _code) = index
+        .update_settings(json!({ "filterableAttributes": ["genre"], "stopWords": ["the"] }))
+        .await;
+    index.wait_task(response.uid()).await.succeeded();
+    let (response, _code) =
+        index.update_settings(json!({ "filterableAttributes": ["genre", "year"] })).await;
+    index.wait_task(response.uid()).await.succeeded();
+
+    let (response, code) = index.rollback_settings(json!({ "version": 1 })).await;
+    snapshot!(code, @"202 Accepted");
+    let task = index.wait_task(response.uid()).await;
+    snapshot!(task["status"], @r###""succeeded""###);
+    snapshot!(task["type"], @r###""settingsUpdate""###);
+
+    let (settings, _code) = index.settings().await;
+    assert_eq!(settings["filterableAttributes"], json!(["genre"]));
+    assert_eq!(settings["stopWords"], json!(["the"]));
+
+    // rolling back to the very first version resets everything
+    let (response, _code) = index.rollback_settings(json!({ "version": 0 })).await;
+    index.wait_task(response.uid()).await.succeeded();
+
+    let (settings, _code) = index.settings().await;
+    assert_eq!(settings["filterableAttributes"], json!([]));
+    assert_eq!(settings["stopWords"], json!([]));
+
+    // the rollbacks are recorded as new versions
+    let (response, _code) = index.settings_history().await;
+    assert_eq!(response["results"].as_array().unwrap().len(), 5);
+    assert_eq!(response["results"][0]["version"], json!(4));
+}
+
+#[actix_rt::test]
+async fn rollback_errors() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (response, code) = index.rollback_settings(json!({ "version": 0 })).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Index `test` not found.",
+      "code": "index_not_found",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#index_not_found"
+    }
+    "###);
+
+    let (response, _code) = index.create(None).await;
+    index.wait_task(response.uid()).await.succeeded();
+
+    let (response, code) = index.rollback_settings(json!({ "version": 42 })).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Settings version `42` not found.",
+      "code": "settings_version_not_found",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#settings_version_not_found"
+    }
+    "###);
+
+    let (response, code) = index.rollback_settings(json!({ "version": "latest" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.version`: expected a positive integer, but found a string: `\"latest\"`",
+      "code": "invalid_settings_rollback_version",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_rollback_version"
+    }
+    "###);
+}
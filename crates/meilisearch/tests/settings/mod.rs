@@ -1,5 +1,6 @@
 mod distinct;
 mod errors;
 mod get_settings;
+mod history;
 mod proximity_settings;
 mod tokenizer_customization;
@@ -1,4 +1,5 @@
 mod errors;
+mod replication;
 mod webhook;
 
 use meili_snap::insta::assert_json_snapshot;
@@ -0,0 +1,221 @@
+use std::time::Duration;
+
+use meili_snap::{json_string, snapshot};
+use meilisearch::Opt;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::common::{default_settings, Server, Value};
+use crate::json;
+
+#[actix_rt::test]
+async fn leader_exposes_the_content_of_the_tasks() {
+    let temp = tempfile::tempdir().unwrap();
+    let options =
+        Opt { experimental_replication_parameters: true, ..default_settings(temp.path()) };
+    let server = Server::new_with_options(options).await.unwrap();
+    let index = server.index("doggos");
+
+    let (task, _code) = index.add_documents(json!([{ "id": 1, "doggo": "kefir" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = server.service.get(format!("/tasks/{}/content", task.uid())).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response, { ".documentAdditionOrUpdate.content_file" => "[uuid]" }), @r###"
+    {
+      "documentAdditionOrUpdate": {
+        "index_uid": "doggos",
+        "primary_key": null,
+        "method": "ReplaceDocuments",
+        "content_file": "[uuid]",
+        "documents_count": 1,
+        "allow_index_creation": true,
+        "auto_generate_ids": false
+      }
+    }
+    "###);
+
+    // the update file is kept after the task has been processed
+    let (content, code) =
+        server.service.get_raw(format!("/tasks/{}/content-file", task.uid())).await;
+    snapshot!(code, @"200 OK");
+    let documents: Vec<Value> = serde_json::Deserializer::from_str(&content)
+        .into_iter::<serde_json::Value>()
+        .map(|document| Value(document.unwrap()))
+        .collect();
+    assert_eq!(documents, vec![json!({ "id": 1, "doggo": "kefir" })]);
+}
+
+#[actix_rt::test]
+async fn content_file_not_found() {
+    let server = Server::new().await;
+    let index = server.index("doggos");
+
+    let (task, _code) = index.add_documents(json!([{ "id": 1, "doggo": "kefir" }]), None).await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // without the replication parameters the update file is deleted once processed
+    let (response, code) = server.service.get(format!("/tasks/{}/content-file", task.uid())).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Task `0` has no content file.",
+      "code": "task_content_file_not_found",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#task_content_file_not_found"
+    }
+    "###);
+
+    let (task, _code) = index.delete().await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = server.service.get(format!("/tasks/{}/content-file", task.uid())).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response["code"], @r###""task_content_file_not_found""###);
+
+    let (response, code) = server.service.get("/tasks/42/content").await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response["code"], @r###""task_not_found""###);
+}
+
+#[actix_rt::test]
+async fn follower_refuses_to_register_tasks() {
+    let temp = tempfile::tempdir().unwrap();
+    let options = Opt {
+        experimental_replication_leader_url: Some("http://localhost:7700".to_string()),
+        ..default_settings(temp.path())
+    };
+    let server = Server::new_with_options(options).await.unwrap();
+    let index = server.index("doggos");
+
+    let (response, code) = index.add_documents(json!([{ "id": 1, "doggo": "kefir" }]), None).await;
+    snapshot!(code, @"403 Forbidden");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "This instance is a follower of `http://localhost:7700` and cannot register tasks. Send your write operations to the leader instead.",
+      "code": "follower_instance",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#follower_instance"
+    }
+    "###);
+
+    // reading is still allowed
+    let (_response, code) = server.tasks().await;
+    snapshot!(code, @"200 OK");
+}
+
+#[actix_rt::test]
+async fn leader_hides_the_api_keys_of_the_embedders() {
+    let temp = tempfile::tempdir().unwrap();
+    let options =
+        Opt { experimental_replication_parameters: true, ..default_settings(temp.path()) };
+    let server = Server::new_with_options(options).await.unwrap();
+    server.set_features(json!({ "vectorStore": true })).await;
+    let index = server.index("doggos");
+
+    let (task, _code) = index
+        .update_settings(json!({
+            "embedders": {
+                "default": {
+                    "source": "openAi",
+                    "apiKey": "sk-a-very-secret-key-of-the-leader",
+                    "dimensions": 3,
+                },
+            },
+        }))
+        .await;
+
+    let (response, code) = server.service.get(format!("/tasks/{}/content", task.uid())).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response["settingsUpdate"]["new_settings"]["embedders"]["default"]["apiKey"], @r###""sk-a-XXXXXX...""###);
+}
+
+#[actix_rt::test]
+async fn follower_replays_the_tasks_of_the_leader() {
+    let temp = tempfile::tempdir().unwrap();
+    let options =
+        Opt { experimental_replication_parameters: true, ..default_settings(temp.path()) };
+    let leader = Server::new_with_options(options).await.unwrap();
+    let index = leader.index("doggos");
+
+    let (task, _code) = index.update_settings(json!({ "filterableAttributes": ["age"] })).await;
+    index.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index
+        .add_documents(
+            json!([{ "id": 1, "doggo": "kefir", "age": 4 }, { "id": 2, "doggo": "intel", "age": 2 }]),
+            None,
+        )
+        .await;
+    index.wait_task(task.uid()).await.succeeded();
+
+    // the follower talks to the leader over HTTP, so we expose the responses of the leader through a mock server
+    let mock_server = MockServer::start().await;
+    let (tasks, _code) = leader.service.get("/tasks?from=0&reverse=true&limit=100").await;
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .and(query_param("from", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(tasks))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "results": [] })))
+        .with_priority(10)
+        .mount(&mock_server)
+        .await;
+    for uid in 0..=1 {
+        let (content, _code) = leader.service.get(format!("/tasks/{uid}/content")).await;
+        Mock::given(method("GET"))
+            .and(path(format!("/tasks/{uid}/content")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(content))
+            .mount(&mock_server)
+            .await;
+    }
+    let (content_file, _code) = leader.service.get_raw("/tasks/1/content-file").await;
+    Mock::given(method("GET"))
+        .and(path("/tasks/1/content-file"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(content_file))
+        .mount(&mock_server)
+        .await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let options = Opt {
+        experimental_replication_leader_url: Some(mock_server.uri()),
+        ..default_settings(temp.path())
+    };
+    let follower = Server::new_with_options(options).await.unwrap();
+    meilisearch::replication::spawn_follower(
+        follower.service.index_scheduler.clone(),
+        mock_server.uri(),
+        None,
+    )
+    .unwrap();
+
+    // wait for the follower to register the last task of the leader
+    for _ in 0..100 {
+        let (_response, code) = follower.get_task(1).await;
+        if code == 200 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    let index = follower.index("doggos");
+    let task = index.wait_task(0).await;
+    task.succeeded();
+    snapshot!(task["type"], @r###""settingsUpdate""###);
+    let task = index.wait_task(1).await;
+    task.succeeded();
+    snapshot!(task["type"], @r###""documentAdditionOrUpdate""###);
+
+    let (response, code) = index.search_post(json!({ "filter": "age > 3" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r###"
+    [
+      {
+        "id": 1,
+        "doggo": "kefir",
+        "age": 4
+      }
+    ]
+    "###);
+}
@@ -22,7 +22,8 @@ async fn get_server_vector() -> Server {
     "metrics": false,
     "logsRoute": false,
     "editDocumentsByFunction": false,
-    "containsFilter": false
+    "containsFilter": false,
+    "readOnly": false
   }
   "###);
     server
@@ -40,7 +41,8 @@ async fn add_remove_user_provided() {
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
@@ -195,7 +197,8 @@ async fn generate_default_user_provided_documents(server: &Server) -> Index {
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
@@ -662,7 +665,8 @@ async fn add_remove_one_vector_4588() {
       "metrics": false,
       "logsRoute": false,
       "editDocumentsByFunction": false,
-      "containsFilter": false
+      "containsFilter": false,
+      "readOnly": false
     }
     "###);
 
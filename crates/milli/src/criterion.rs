@@ -4,6 +4,7 @@ use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::function_score::{Expression, ExpressionError};
 use crate::{AscDesc, Member};
 
 #[derive(Error, Debug)]
@@ -22,6 +23,8 @@ pub enum CriterionError {
 `{name}` can only be used for filtering at search time"
     )]
     ReservedNameForFilter { name: String },
+    #[error("`function({expression})` ranking rule is invalid: {error}")]
+    InvalidFunction { expression: String, error: ExpressionError },
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -45,6 +48,9 @@ pub enum Criterion {
     Asc(String),
     /// Sorted by the decreasing value of the field specified.
     Desc(String),
+    /// Sorted by the decreasing score computed by the expression over the numeric
+    /// values of the fields specified, e.g. `function(log1p(sales) * rating)`.
+    Function(String),
 }
 
 impl Criterion {
@@ -68,6 +74,16 @@ impl FromStr for Criterion {
             "attribute" => Ok(Criterion::Attribute),
             "sort" => Ok(Criterion::Sort),
             "exactness" => Ok(Criterion::Exactness),
+            text if text.starts_with("function(") && text.ends_with(')') => {
+                let expression = &text["function(".len()..text.len() - 1];
+                match Expression::from_str(expression) {
+                    Ok(_) => Ok(Criterion::Function(expression.to_string())),
+                    Err(error) => Err(CriterionError::InvalidFunction {
+                        expression: expression.to_string(),
+                        error,
+                    }),
+                }
+            }
             text => match AscDesc::from_str(text)? {
                 AscDesc::Asc(Member::Field(field)) => Ok(Criterion::Asc(field)),
                 AscDesc::Desc(Member::Field(field)) => Ok(Criterion::Desc(field)),
@@ -103,6 +119,7 @@ impl fmt::Display for Criterion {
             Exactness => f.write_str("exactness"),
             Asc(attr) => write!(f, "{}:asc", attr),
             Desc(attr) => write!(f, "{}:desc", attr),
+            Function(expression) => write!(f, "function({})", expression),
         }
     }
 }
@@ -129,6 +146,7 @@ mod tests {
             ("truc:machin:desc", Criterion::Desc(S("truc:machin"))),
             ("hello-world!:desc", Criterion::Desc(S("hello-world!"))),
             ("it's spacy over there:asc", Criterion::Asc(S("it's spacy over there"))),
+            ("function(log1p(sales) * rating)", Criterion::Function(S("log1p(sales) * rating"))),
         ];
 
         for (input, expected) in valid_criteria {
@@ -153,6 +171,13 @@ mod tests {
             ("price:aasc", InvalidName { name: S("price:aasc") }),
             ("price:asc and desc", InvalidName { name: S("price:asc and desc") }),
             ("price:asc:truc", InvalidName { name: S("price:asc:truc") }),
+            (
+                "function(log1p(sales) *)",
+                InvalidFunction {
+                    expression: S("log1p(sales) *"),
+                    error: ExpressionError::UnexpectedEnd,
+                },
+            ),
             ("_geo:asc", ReservedName { name: S("_geo") }),
             ("_geoDistance:asc", ReservedName { name: S("_geoDistance") }),
             ("_geoPoint:asc", ReservedNameForSort { name: S("_geoPoint") }),
@@ -0,0 +1,99 @@
+//! An optional declaration of the types of the fields of the documents of an index.
+//!
+//! The documents sent to the index are checked against it when they are added or updated,
+//! the ones that don't conform are either rejected with their payload or skipped and
+//! reported in the task details, depending on the [`SchemaMode`].
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use raw_collections::RawMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSchema {
+    pub mode: SchemaMode,
+    pub fields: BTreeMap<String, FieldSchema>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SchemaMode {
+    /// A payload containing a non-conforming document is rejected as a whole.
+    #[default]
+    Strict,
+    /// The non-conforming documents are skipped and reported, the others are indexed.
+    Lenient,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldSchema {
+    #[serde(rename = "type")]
+    pub field_type: FieldType,
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FieldType {
+    String,
+    Number,
+    Boolean,
+    Array,
+    Object,
+}
+
+impl FieldType {
+    fn matches(&self, value: &Value) -> bool {
+        match self {
+            FieldType::String => value.is_string(),
+            FieldType::Number => value.is_number(),
+            FieldType::Boolean => value.is_boolean(),
+            FieldType::Array => value.is_array(),
+            FieldType::Object => value.is_object(),
+        }
+    }
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldType::String => f.write_str("a string"),
+            FieldType::Number => f.write_str("a number"),
+            FieldType::Boolean => f.write_str("a boolean"),
+            FieldType::Array => f.write_str("an array"),
+            FieldType::Object => f.write_str("an object"),
+        }
+    }
+}
+
+impl DocumentSchema {
+    /// Returns the reasons why the top-level fields of the document don't conform to the schema.
+    ///
+    /// `null` values are considered missing. The presence of the required fields is only
+    /// checked on complete documents as partial updates are merged with the stored ones.
+    pub fn violations(&self, document: &RawMap, complete: bool) -> serde_json::Result<Vec<String>> {
+        let mut violations = Vec::new();
+        for (name, field) in &self.fields {
+            let value = match document.get(name) {
+                Some(value) => serde_json::from_str(value.get())?,
+                None => Value::Null,
+            };
+
+            if value.is_null() {
+                if field.required && complete {
+                    violations.push(format!("the `{name}` field is required"));
+                }
+            } else if !field.field_type.matches(&value) {
+                violations.push(format!(
+                    "the `{name}` field must be {} but found `{value}`",
+                    field.field_type
+                ));
+            }
+        }
+        Ok(violations)
+    }
+}
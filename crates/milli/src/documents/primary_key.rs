@@ -7,6 +7,7 @@ use serde_json::value::RawValue;
 use serde_json::Value;
 
 use crate::fields_ids_map::MutFieldIdMapper;
+use crate::update::new::document::object_to_raw_value;
 use crate::update::new::indexer::de::{match_component, DeOrBumpStr};
 use crate::update::new::KvReaderFieldId;
 use crate::{FieldId, InternalError, Object, Result, UserError};
@@ -14,6 +15,15 @@ use crate::{FieldId, InternalError, Object, Result, UserError};
 /// The symbol used to define levels in a nested primary key.
 const PRIMARY_KEY_SPLIT_SYMBOL: char = '.';
 
+/// The symbol used to separate the fields of a composite primary key.
+const PRIMARY_KEY_COMPOSITE_SYMBOL: char = ',';
+
+/// The symbol used to join the values of the fields of a composite primary key
+/// into a single external document id, e.g. `sku-42:en-US` for `sku,locale`.
+///
+/// It can't appear in the values themselves as they must be valid document ids.
+pub const COMPOSITE_DOCUMENT_ID_SEPARATOR: char = ':';
+
 /// The default primary that is used when not specified.
 pub const DEFAULT_PRIMARY_KEY: &str = "id";
 
@@ -41,11 +51,13 @@ where
 }
 
 /// A type that represent the type of primary key that has been set
-/// for this index, a classic flat one or a nested one.
+/// for this index, a classic flat one, a nested one or a composite one
+/// made of several comma-separated fields.
 #[derive(Debug, Clone, Copy)]
 pub enum PrimaryKey<'a> {
     Flat { name: &'a str, field_id: FieldId },
     Nested { name: &'a str },
+    Composite { name: &'a str },
 }
 
 pub enum DocumentIdExtractionError {
@@ -56,7 +68,9 @@ pub enum DocumentIdExtractionError {
 
 impl<'a> PrimaryKey<'a> {
     pub fn new(path: &'a str, fields: &impl FieldIdMapper) -> Option<Self> {
-        Some(if path.contains(PRIMARY_KEY_SPLIT_SYMBOL) {
+        Some(if path.contains(PRIMARY_KEY_COMPOSITE_SYMBOL) {
+            Self::Composite { name: path }
+        } else if path.contains(PRIMARY_KEY_SPLIT_SYMBOL) {
             Self::Nested { name: path }
         } else {
             let field_id = fields.id(path)?;
@@ -68,7 +82,9 @@ impl<'a> PrimaryKey<'a> {
         path: &'a str,
         fields: &mut impl MutFieldIdMapper,
     ) -> StdResult<Self, UserError> {
-        Ok(if path.contains(PRIMARY_KEY_SPLIT_SYMBOL) {
+        Ok(if path.contains(PRIMARY_KEY_COMPOSITE_SYMBOL) {
+            Self::Composite { name: path }
+        } else if path.contains(PRIMARY_KEY_SPLIT_SYMBOL) {
             Self::Nested { name: path }
         } else {
             let field_id = fields.insert(path).ok_or(UserError::AttributeLimitReached)?;
@@ -80,9 +96,24 @@ impl<'a> PrimaryKey<'a> {
         match self {
             PrimaryKey::Flat { name, .. } => name,
             PrimaryKey::Nested { name } => name,
+            PrimaryKey::Composite { name } => name,
         }
     }
 
+    /// Returns the primary keys of the fields composing a composite primary key.
+    ///
+    /// The fields are always represented as nested primary keys as they
+    /// also handle flat fields and don't require to know their field ids.
+    pub fn components(&self) -> impl Iterator<Item = PrimaryKey<'a>> {
+        let name = match self {
+            PrimaryKey::Composite { name } => Some(*name),
+            _ => None,
+        };
+        name.into_iter()
+            .flat_map(|name| name.split(PRIMARY_KEY_COMPOSITE_SYMBOL))
+            .map(|name| PrimaryKey::Nested { name })
+    }
+
     pub fn document_id(
         &self,
         document: &obkv::KvReader<FieldId>,
@@ -130,6 +161,16 @@ impl<'a> PrimaryKey<'a> {
                     None => Ok(Err(DocumentIdExtractionError::MissingDocumentId)),
                 }
             }
+            PrimaryKey::Composite { .. } => {
+                let mut values = Vec::new();
+                for component in self.components() {
+                    match component.document_id(document, fields)? {
+                        Ok(value) => values.push(value),
+                        Err(error) => return Ok(Err(error)),
+                    }
+                }
+                Ok(join_composite_document_id(values.iter().map(String::as_str)))
+            }
         }
     }
 
@@ -191,6 +232,21 @@ impl<'a> PrimaryKey<'a> {
                     crate::documents::Error::InvalidDocumentFormat,
                 ))?)
             }
+            PrimaryKey::Composite { .. } => {
+                let mut values = Vec::new();
+                for component in self.components() {
+                    let value =
+                        component.extract_docid_from_db(document, db_fields_ids_map, indexer)?;
+                    values.push(value.to_bump(indexer));
+                }
+                match join_composite_document_id(values) {
+                    Ok(document_id) => Ok(DeOrBumpStr::Bump(indexer.alloc_str(&document_id))),
+                    Err(_) => Err(InternalError::DocumentsError(
+                        crate::documents::Error::InvalidDocumentFormat,
+                    )
+                    .into()),
+                }
+            }
         }
     }
 
@@ -201,6 +257,21 @@ impl<'a> PrimaryKey<'a> {
         indexer: &'bump Bump,
     ) -> Result<DeOrBumpStr<'pl, 'bump>> {
         use serde::Deserializer as _;
+
+        if let PrimaryKey::Composite { .. } = self {
+            let mut values = Vec::new();
+            for component in self.components() {
+                let value =
+                    component.extract_fields_and_docid(document, new_fields_ids_map, indexer)?;
+                values.push(value.to_bump(indexer));
+            }
+            return match join_composite_document_id(values) {
+                Ok(document_id) => Ok(DeOrBumpStr::Bump(indexer.alloc_str(&document_id))),
+                Err(DocumentIdExtractionError::InvalidDocumentId(e)) => Err(e.into()),
+                Err(_) => unreachable!("joining document ids only fails on invalid ids"),
+            };
+        }
+
         let res = document
             .deserialize_map(crate::update::new::indexer::de::FieldAndDocidExtractor::new(
                 new_fields_ids_map,
@@ -229,6 +300,21 @@ impl<'a> PrimaryKey<'a> {
         Ok(external_document_id)
     }
 
+    /// Returns a copy of the document with a newly generated document id.
+    ///
+    /// The ids are UUIDv7 so that the generated ids are ordered by creation time.
+    pub fn generate_document_id<'bump>(
+        &self,
+        document: &RawValue,
+        bump: &'bump Bump,
+    ) -> Result<&'bump RawValue> {
+        let mut document: Object =
+            serde_json::from_str(document.get()).map_err(InternalError::SerdeJson)?;
+        let document_id = uuid::Uuid::now_v7().to_string();
+        document.insert(self.name().to_string(), Value::String(document_id));
+        object_to_raw_value(&document, bump)
+    }
+
     /// Returns an `Iterator` that gives all the possible fields names the primary key
     /// can have depending of the first level name and depth of the objects.
     pub fn possible_level_names(&self) -> impl Iterator<Item = (&'a str, &'a str)> + '_ {
@@ -239,6 +325,23 @@ impl<'a> PrimaryKey<'a> {
     }
 }
 
+/// Returns the values of a field of a document, resolving the dotted names through the
+/// nested objects like a nested primary key. The values of the top-level fields of the
+/// document are returned by `top_level_field`.
+pub fn fetch_nested_values(
+    name: &str,
+    mut top_level_field: impl FnMut(&str) -> Result<Option<Value>>,
+) -> Result<Vec<Value>> {
+    let nested = PrimaryKey::Nested { name };
+    let mut values = Vec::new();
+    for (first_level_name, right) in nested.possible_level_names() {
+        if let Some(value) = top_level_field(first_level_name)? {
+            fetch_matching_values(value, right, &mut values);
+        }
+    }
+    Ok(values)
+}
+
 fn fetch_matching_values(value: Value, selector: &str, output: &mut Vec<Value>) {
     match value {
         Value::Object(object) => fetch_matching_values_in_object(object, selector, "", output),
@@ -276,6 +379,55 @@ fn starts_with(selector: &str, key: &str) -> bool {
     })
 }
 
+/// Joins the values of the fields of a composite primary key into an external document id.
+fn join_composite_document_id<'v>(
+    values: impl IntoIterator<Item = &'v str>,
+) -> StdResult<String, DocumentIdExtractionError> {
+    let mut document_id = String::new();
+    for (i, value) in values.into_iter().enumerate() {
+        if i != 0 {
+            document_id.push(COMPOSITE_DOCUMENT_ID_SEPARATOR);
+        }
+        document_id.push_str(value);
+    }
+
+    if document_id.len() > 512 {
+        Err(DocumentIdExtractionError::InvalidDocumentId(UserError::InvalidDocumentId {
+            document_id: Value::String(document_id),
+        }))
+    } else {
+        Ok(document_id)
+    }
+}
+
+/// Checks that the fields of a composite primary key are neither empty, surrounded by
+/// whitespace nor duplicated.
+pub fn validate_primary_key(primary_key: &str) -> StdResult<(), UserError> {
+    if !primary_key.contains(PRIMARY_KEY_COMPOSITE_SYMBOL) {
+        return Ok(());
+    }
+
+    let mut fields = Vec::new();
+    for field in primary_key.split(PRIMARY_KEY_COMPOSITE_SYMBOL) {
+        let reason = if field.trim().is_empty() {
+            "a field name cannot be empty"
+        } else if field.trim() != field {
+            "a field name cannot start or end with whitespace"
+        } else if fields.contains(&field) {
+            "a field cannot be used more than once"
+        } else {
+            fields.push(field);
+            continue;
+        };
+        return Err(UserError::InvalidCompositePrimaryKey {
+            primary_key: primary_key.to_string(),
+            reason,
+        });
+    }
+
+    Ok(())
+}
+
 // FIXME: move to a DocumentId struct
 
 pub fn validate_document_id_str(document_id: &str) -> Option<&str> {
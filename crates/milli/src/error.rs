@@ -112,6 +112,21 @@ only composed of alphanumeric characters (a-z A-Z 0-9), hyphens (-) and undersco
 and can not be more than 512 bytes.", .document_id.to_string()
     )]
     InvalidDocumentId { document_id: Value },
+    #[error(
+        "The version attribute `{version_attribute}` of the document `{document_id}` must be a positive integer, but found `{}`.",
+        match .value { Some(value) => value.to_string(), None => "nothing".to_string() }
+    )]
+    InvalidDocumentVersion { document_id: String, version_attribute: String, value: Option<Value> },
+    #[error("Could not apply the patch on the document `{document_id}`: {error}.")]
+    InvalidDocumentPatch { document_id: String, error: crate::update::new::indexer::PatchError },
+    #[error("Patching documents is only supported by the new indexer.")]
+    UnsupportedDocumentPatch,
+    #[error("{} document(s) do not conform to the document schema of the index: {}.", .reports.len(), .reports.join("; "))]
+    InvalidDocumentSchema { reports: Vec<String> },
+    #[error("The expression of the computed field `{field}` is invalid: {error}")]
+    InvalidComputedField { field: String, error: rhai::ParseError },
+    #[error("Could not compute the `{field}` field of the document `{document_id}`: {error}")]
+    ComputedFieldRuntimeError { field: String, document_id: String, error: Box<EvalAltResult> },
     #[error("Invalid facet distribution, {}", format_invalid_filter_distribution(.invalid_facets_name, .valid_facets_name))]
     InvalidFacetsDistribution {
         invalid_facets_name: BTreeSet<String>,
@@ -196,6 +211,8 @@ and can not be more than 512 bytes.", .document_id.to_string()
     NoSpaceLeftOnDevice,
     #[error("Index already has a primary key: `{0}`.")]
     PrimaryKeyCannotBeChanged(String),
+    #[error("The composite primary key `{primary_key}` is invalid: {reason}.")]
+    InvalidCompositePrimaryKey { primary_key: String, reason: &'static str },
     #[error(transparent)]
     SerdeJson(serde_json::Error),
     #[error(transparent)]
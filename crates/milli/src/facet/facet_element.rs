@@ -0,0 +1,210 @@
+use std::collections::BTreeSet;
+
+use heed::BytesEncode;
+use serde_json::Value;
+
+use crate::heed_codec::facet::{FacetElementKey, FacetElementKeyCodec, FacetElementValue};
+use crate::{normalize_facet, parse_date, FieldId, Result, MAX_FACET_VALUE_LENGTH};
+
+/// Returns the keys of the [`facet_id_element_docids`][crate::Index::facet_id_element_docids]
+/// database derived from the array stored in the faceted `field`.
+///
+/// The objects of the array are flattened and their values are faceted under the fields of
+/// the array, e.g. `variants.color`, along with the index of the object in the array, for the
+/// nested filters to only match the values of a single object. The number of elements of the
+/// array, and of the arrays of its objects, are also faceted for the `SIZE` filters.
+/// The fields are resolved with `field_id`, the ones without an id are ignored.
+pub fn facet_element_keys(
+    field: &str,
+    value: &Value,
+    mut field_id: impl FnMut(&str) -> Result<Option<FieldId>>,
+    is_date_field: impl Fn(&str) -> bool,
+) -> Result<BTreeSet<Vec<u8>>> {
+    let mut keys = BTreeSet::new();
+    let Value::Array(array) = value else { return Ok(keys) };
+    let Some(array_field_id) = field_id(field)? else { return Ok(keys) };
+    insert_key(&mut keys, array_field_id, None, FacetElementValue::Size(array.len() as u32));
+
+    for (element, value) in array.iter().enumerate() {
+        let Value::Object(object) = value else { continue };
+        let element = Some(element as u32);
+        insert_key(&mut keys, array_field_id, element, FacetElementValue::Exists);
+
+        for (key, value) in flatten_serde_json::flatten(object) {
+            let name = format!("{field}.{key}");
+            let Some(field_id) = field_id(&name)? else { continue };
+            let date_field = is_date_field(&name);
+
+            insert_key(&mut keys, field_id, element, FacetElementValue::Exists);
+            match value {
+                Value::Null => insert_key(&mut keys, field_id, element, FacetElementValue::Null),
+                Value::String(s) if s.is_empty() => {
+                    insert_key(&mut keys, field_id, element, FacetElementValue::Empty)
+                }
+                Value::Array(a) if a.is_empty() => {
+                    insert_key(&mut keys, field_id, element, FacetElementValue::Size(0));
+                    insert_key(&mut keys, field_id, element, FacetElementValue::Empty)
+                }
+                Value::Object(o) if o.is_empty() => {
+                    insert_key(&mut keys, field_id, element, FacetElementValue::Empty)
+                }
+                Value::Array(values) => {
+                    let size = FacetElementValue::Size(values.len() as u32);
+                    insert_key(&mut keys, field_id, element, size);
+                    for value in &values {
+                        insert_leaf_value(&mut keys, field_id, element, value, date_field);
+                    }
+                }
+                value => insert_leaf_value(&mut keys, field_id, element, &value, date_field),
+            }
+        }
+    }
+
+    Ok(keys)
+}
+
+/// Returns the keys of the [`facet_id_element_docids`][crate::Index::facet_id_element_docids]
+/// database of the strings and booleans, as they are written in the document, of the `value`
+/// of a faceted field, for the case-sensitive equality filters to not read the documents.
+pub fn original_string_keys(field_id: FieldId, value: &Value) -> Vec<Vec<u8>> {
+    let mut keys = BTreeSet::new();
+    insert_original_strings(&mut keys, field_id, value);
+    keys.into_iter().collect()
+}
+
+fn insert_original_strings(keys: &mut BTreeSet<Vec<u8>>, field_id: FieldId, value: &Value) {
+    match value {
+        Value::Bool(b) => {
+            let b = if *b { "true" } else { "false" };
+            insert_key(keys, field_id, None, FacetElementValue::OriginalString(b));
+        }
+        Value::String(original) if !original.is_empty() => {
+            let value = FacetElementValue::OriginalString(truncate_str(original));
+            insert_key(keys, field_id, None, value);
+        }
+        Value::Array(values) => {
+            values.iter().for_each(|value| insert_original_strings(keys, field_id, value))
+        }
+        _ => (),
+    }
+}
+
+/// Inserts the keys of a number, a string or a boolean, the other values are ignored.
+fn insert_leaf_value(
+    keys: &mut BTreeSet<Vec<u8>>,
+    field_id: FieldId,
+    element: Option<u32>,
+    value: &Value,
+    date_field: bool,
+) {
+    match value {
+        Value::Bool(b) => {
+            let b = if *b { "true" } else { "false" };
+            insert_key(keys, field_id, element, FacetElementValue::String(b));
+            insert_key(keys, field_id, element, FacetElementValue::OriginalString(b));
+        }
+        Value::Number(number) => {
+            if let Some(number) = number.as_f64() {
+                insert_key(keys, field_id, element, FacetElementValue::Number(number));
+            }
+        }
+        Value::String(original) => {
+            // the dates are also faceted as timestamps to be filtered chronologically
+            if date_field {
+                if let Some(timestamp) = parse_date(original) {
+                    let value = FacetElementValue::Number(timestamp as f64);
+                    insert_key(keys, field_id, element, value);
+                }
+            }
+            let normalized = normalize_facet(original);
+            if !normalized.is_empty() {
+                let value = FacetElementValue::String(truncate_str(&normalized));
+                insert_key(keys, field_id, element, value);
+                let value = FacetElementValue::OriginalString(truncate_str(original));
+                insert_key(keys, field_id, element, value);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn insert_key(
+    keys: &mut BTreeSet<Vec<u8>>,
+    field_id: FieldId,
+    element: Option<u32>,
+    value: FacetElementValue,
+) {
+    let key = FacetElementKey { field_id, element, value };
+    // the non-finite numbers can't be encoded and are never faceted
+    if let Ok(bytes) = FacetElementKeyCodec::bytes_encode(&key) {
+        keys.insert(bytes.into_owned());
+    }
+}
+
+/// Truncates a string to the biggest valid LMDB key size.
+fn truncate_str(s: &str) -> &str {
+    let index = s
+        .char_indices()
+        .map(|(idx, _)| idx)
+        .chain(std::iter::once(s.len()))
+        .take_while(|idx| idx <= &MAX_FACET_VALUE_LENGTH)
+        .last();
+
+    &s[..index.unwrap_or(0)]
+}
+
+#[cfg(test)]
+mod tests {
+    use heed::BytesDecode;
+    use serde_json::json;
+
+    use super::*;
+    use crate::FieldsIdsMap;
+
+    #[test]
+    fn element_keys() {
+        let mut fields_ids_map = FieldsIdsMap::new();
+        let variants = fields_ids_map.insert("variants").unwrap();
+        let color = fields_ids_map.insert("variants.color").unwrap();
+
+        let value = json!([{ "color": "Red", "size": "M" }, "blue", { "color": null }]);
+        let keys =
+            facet_element_keys("variants", &value, |name| Ok(fields_ids_map.id(name)), |_| false)
+                .unwrap();
+
+        let decoded: Vec<_> =
+            keys.iter().map(|key| FacetElementKeyCodec::bytes_decode(key).unwrap()).collect();
+        let key = |field_id, element, value| FacetElementKey { field_id, element, value };
+        assert_eq!(
+            decoded,
+            vec![
+                key(variants, None, FacetElementValue::Size(3)),
+                key(variants, Some(0), FacetElementValue::Exists),
+                key(variants, Some(2), FacetElementValue::Exists),
+                key(color, Some(0), FacetElementValue::Exists),
+                key(color, Some(2), FacetElementValue::Exists),
+                key(color, Some(2), FacetElementValue::Null),
+                key(color, Some(0), FacetElementValue::String("red")),
+                key(color, Some(0), FacetElementValue::OriginalString("Red")),
+            ]
+        );
+    }
+
+    #[test]
+    fn original_strings() {
+        let value = json!(["EF-400", "ef-400", 12, true, ""]);
+        let keys = original_string_keys(3, &value);
+
+        let decoded: Vec<_> =
+            keys.iter().map(|key| FacetElementKeyCodec::bytes_decode(key).unwrap()).collect();
+        let key = |value| FacetElementKey { field_id: 3, element: None, value };
+        assert_eq!(
+            decoded,
+            vec![
+                key(FacetElementValue::OriginalString("EF-400")),
+                key(FacetElementValue::OriginalString("ef-400")),
+                key(FacetElementValue::OriginalString("true")),
+            ]
+        );
+    }
+}
@@ -1,6 +1,8 @@
+mod facet_element;
 mod facet_type;
 mod facet_value;
 pub mod value_encoding;
 
+pub use self::facet_element::{facet_element_keys, original_string_keys};
 pub use self::facet_type::FacetType;
 pub use self::facet_value::FacetValue;
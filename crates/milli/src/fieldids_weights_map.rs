@@ -1,12 +1,16 @@
 //! The fieldids weights map is in charge of storing linking the searchable fields with their weights.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use serde::{Deserialize, Serialize};
 
 use crate::vector::parsed_vectors::RESERVED_VECTORS_FIELD_NAME;
 use crate::{FieldId, FieldsIdsMap, Weight};
 
+/// The weight of the searchable attributes that are not given an explicit weight
+/// in the searchable attributes weights.
+pub const DEFAULT_ATTRIBUTE_WEIGHT: Weight = 1;
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct FieldidsWeightsMap {
     map: HashMap<FieldId, Weight>,
@@ -22,14 +26,38 @@ impl FieldidsWeightsMap {
 
     /// Create the map from the fields ids maps.
     /// Should only be called in the case there are NO searchable attributes.
-    /// All the fields will be inserted in the order of the fields ids map with a weight of 0.
-    pub fn from_field_id_map_without_searchable(fid_map: &FieldsIdsMap) -> Self {
+    /// All the fields will be inserted in the order of the fields ids map with a weight of 0,
+    /// unless the searchable attributes weights give them an explicit weight.
+    pub fn from_field_id_map_without_searchable(
+        fid_map: &FieldsIdsMap,
+        attributes_weights: &BTreeMap<String, Weight>,
+    ) -> Self {
+        let fields = fid_map
+            .iter()
+            .filter(|(_fid, name)| !crate::is_faceted_by(name, RESERVED_VECTORS_FIELD_NAME));
+
+        if attributes_weights.is_empty() {
+            FieldidsWeightsMap { map: fields.map(|(fid, _name)| (fid, 0)).collect() }
+        } else {
+            Self::from_attributes_weights(fields.map(|(fid, name)| {
+                let weight = attributes_weights
+                    .iter()
+                    .find(|(attribute, _)| crate::is_faceted_by(name, attribute))
+                    .map_or(DEFAULT_ATTRIBUTE_WEIGHT, |(_, weight)| *weight);
+                (fid, weight)
+            }))
+        }
+    }
+
+    /// Create the map from the explicit weights of the fields, the most important fields having
+    /// the highest weights.
+    /// The weights are reversed so that, like the positional weights, the most important fields
+    /// have a weight of 0 and the fields with the same explicit weight share the same weight.
+    pub fn from_attributes_weights(fields: impl IntoIterator<Item = (FieldId, Weight)>) -> Self {
+        let fields: Vec<_> = fields.into_iter().collect();
+        let max_weight = fields.iter().map(|(_fid, weight)| *weight).max().unwrap_or_default();
         FieldidsWeightsMap {
-            map: fid_map
-                .iter()
-                .filter(|(_fid, name)| !crate::is_faceted_by(name, RESERVED_VECTORS_FIELD_NAME))
-                .map(|(fid, _name)| (fid, 0))
-                .collect(),
+            map: fields.into_iter().map(|(fid, weight)| (fid, max_weight - weight)).collect(),
         }
     }
 
@@ -0,0 +1,437 @@
+//! The expressions of the `function(...)` ranking rules, blending the numeric facet values
+//! of the documents into a single score, e.g. `log1p(sales) * exp(-(now() - published_at) / 2592000)`.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ExpressionError {
+    #[error("the expression is empty")]
+    Empty,
+    #[error("unexpected `{found}` at position {position}")]
+    UnexpectedToken { found: String, position: usize },
+    #[error("unexpected end of the expression")]
+    UnexpectedEnd,
+    #[error("unknown function `{name}`, expected one of {}", Function::NAMES.join(", "))]
+    UnknownFunction { name: String },
+    #[error("the `{name}` function takes {expected} argument(s) but {found} were given")]
+    InvalidArity { name: &'static str, expected: &'static str, found: usize },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Function {
+    Log1p,
+    Ln,
+    Log10,
+    Exp,
+    Sqrt,
+    Abs,
+    Floor,
+    Ceil,
+    Min,
+    Max,
+    Pow,
+    /// The current Unix timestamp in seconds, like the faceted values of the date attributes.
+    Now,
+}
+
+impl Function {
+    const NAMES: &'static [&'static str] = &[
+        "log1p", "ln", "log10", "exp", "sqrt", "abs", "floor", "ceil", "min", "max", "pow", "now",
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        let function = match name {
+            "log1p" => Function::Log1p,
+            "ln" => Function::Ln,
+            "log10" => Function::Log10,
+            "exp" => Function::Exp,
+            "sqrt" => Function::Sqrt,
+            "abs" => Function::Abs,
+            "floor" => Function::Floor,
+            "ceil" => Function::Ceil,
+            "min" => Function::Min,
+            "max" => Function::Max,
+            "pow" => Function::Pow,
+            "now" => Function::Now,
+            _ => return None,
+        };
+        Some(function)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Function::Log1p => "log1p",
+            Function::Ln => "ln",
+            Function::Log10 => "log10",
+            Function::Exp => "exp",
+            Function::Sqrt => "sqrt",
+            Function::Abs => "abs",
+            Function::Floor => "floor",
+            Function::Ceil => "ceil",
+            Function::Min => "min",
+            Function::Max => "max",
+            Function::Pow => "pow",
+            Function::Now => "now",
+        }
+    }
+
+    fn check_arity(&self, found: usize) -> Result<(), ExpressionError> {
+        let (valid, expected) = match self {
+            Function::Now => (found == 0, "0"),
+            Function::Pow => (found == 2, "2"),
+            Function::Min | Function::Max => (found >= 1, "at least 1"),
+            _ => (found == 1, "1"),
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(ExpressionError::InvalidArity { name: self.name(), expected, found })
+        }
+    }
+}
+
+/// An arithmetic expression over the numeric facet values of a document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    Number(f64),
+    /// The smallest numeric value of the field in the document.
+    Field(String),
+    Neg(Box<Expression>),
+    Binary(BinaryOp, Box<Expression>, Box<Expression>),
+    Call(Function, Vec<Expression>),
+}
+
+impl Expression {
+    /// Returns the fields the expression depends on, they must be faceted.
+    pub fn fields(&self) -> BTreeSet<&str> {
+        let mut fields = BTreeSet::new();
+        self.collect_fields(&mut fields);
+        fields
+    }
+
+    fn collect_fields<'a>(&'a self, fields: &mut BTreeSet<&'a str>) {
+        match self {
+            Expression::Number(_) => (),
+            Expression::Field(name) => {
+                fields.insert(name);
+            }
+            Expression::Neg(expression) => expression.collect_fields(fields),
+            Expression::Binary(_, left, right) => {
+                left.collect_fields(fields);
+                right.collect_fields(fields);
+            }
+            Expression::Call(_, arguments) => {
+                arguments.iter().for_each(|argument| argument.collect_fields(fields))
+            }
+        }
+    }
+
+    /// Evaluates the expression with the values of the fields returned by `field_value`.
+    ///
+    /// Returns `None` when a field has no value or when the result isn't a finite number.
+    pub fn evaluate(&self, field_value: &impl Fn(&str) -> Option<f64>, now: f64) -> Option<f64> {
+        let value = match self {
+            Expression::Number(number) => *number,
+            Expression::Field(name) => field_value(name)?,
+            Expression::Neg(expression) => -expression.evaluate(field_value, now)?,
+            Expression::Binary(op, left, right) => {
+                let left = left.evaluate(field_value, now)?;
+                let right = right.evaluate(field_value, now)?;
+                match op {
+                    BinaryOp::Add => left + right,
+                    BinaryOp::Sub => left - right,
+                    BinaryOp::Mul => left * right,
+                    BinaryOp::Div => left / right,
+                    BinaryOp::Pow => left.powf(right),
+                }
+            }
+            Expression::Call(function, arguments) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| argument.evaluate(field_value, now))
+                    .collect::<Option<Vec<_>>>()?;
+                match function {
+                    Function::Log1p => arguments[0].ln_1p(),
+                    Function::Ln => arguments[0].ln(),
+                    Function::Log10 => arguments[0].log10(),
+                    Function::Exp => arguments[0].exp(),
+                    Function::Sqrt => arguments[0].sqrt(),
+                    Function::Abs => arguments[0].abs(),
+                    Function::Floor => arguments[0].floor(),
+                    Function::Ceil => arguments[0].ceil(),
+                    Function::Min => arguments.into_iter().fold(f64::INFINITY, f64::min),
+                    Function::Max => arguments.into_iter().fold(f64::NEG_INFINITY, f64::max),
+                    Function::Pow => arguments[0].powf(arguments[1]),
+                    Function::Now => now,
+                }
+            }
+        };
+        value.is_finite().then_some(value)
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ExpressionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(s)?;
+        if tokens.is_empty() {
+            return Err(ExpressionError::Empty);
+        }
+        let mut parser = Parser { tokens, next: 0 };
+        let expression = parser.parse_sum()?;
+        match parser.peek() {
+            None => Ok(expression),
+            Some((position, token)) => {
+                Err(ExpressionError::UnexpectedToken { found: token.to_string(), position })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(char),
+    LeftParen,
+    RightParen,
+    Comma,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(number) => write!(f, "{number}"),
+            Token::Ident(ident) => f.write_str(ident),
+            Token::Op(op) => write!(f, "{op}"),
+            Token::LeftParen => f.write_str("("),
+            Token::RightParen => f.write_str(")"),
+            Token::Comma => f.write_str(","),
+        }
+    }
+}
+
+fn tokenize(s: &str) -> Result<Vec<(usize, Token)>, ExpressionError> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+    while let Some((position, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '+' | '-' | '*' | '/' | '^' => Token::Op(c),
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            ',' => Token::Comma,
+            c if c.is_ascii_digit() || c == '.' => {
+                let mut end = position + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if !(c.is_ascii_digit() || c == '.') {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                let number = &s[position..end];
+                match number.parse() {
+                    Ok(number) => Token::Number(number),
+                    Err(_) => {
+                        return Err(ExpressionError::UnexpectedToken {
+                            found: number.to_string(),
+                            position,
+                        })
+                    }
+                }
+            }
+            // dots are allowed in the identifiers to reach the nested fields
+            c if c.is_alphabetic() || c == '_' => {
+                let mut end = position + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_' || c == '.') {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                Token::Ident(s[position..end].to_string())
+            }
+            c => return Err(ExpressionError::UnexpectedToken { found: c.to_string(), position }),
+        };
+        tokens.push((position, token));
+    }
+    Ok(tokens)
+}
+
+/// A recursive descent parser where `^` binds tighter than the unary `-`,
+/// itself binding tighter than `*` and `/`, then `+` and `-`.
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    next: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<(usize, &Token)> {
+        self.tokens.get(self.next).map(|(position, token)| (*position, token))
+    }
+
+    fn bump(&mut self) -> Result<(usize, Token), ExpressionError> {
+        let token = self.tokens.get(self.next).cloned().ok_or(ExpressionError::UnexpectedEnd)?;
+        self.next += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), ExpressionError> {
+        match self.bump()? {
+            (_, token) if token == expected => Ok(()),
+            (position, token) => {
+                Err(ExpressionError::UnexpectedToken { found: token.to_string(), position })
+            }
+        }
+    }
+
+    fn parse_sum(&mut self) -> Result<Expression, ExpressionError> {
+        let mut expression = self.parse_product()?;
+        while let Some((_, Token::Op(op @ ('+' | '-')))) = self.peek() {
+            let op = if *op == '+' { BinaryOp::Add } else { BinaryOp::Sub };
+            self.next += 1;
+            let right = self.parse_product()?;
+            expression = Expression::Binary(op, Box::new(expression), Box::new(right));
+        }
+        Ok(expression)
+    }
+
+    fn parse_product(&mut self) -> Result<Expression, ExpressionError> {
+        let mut expression = self.parse_unary()?;
+        while let Some((_, Token::Op(op @ ('*' | '/')))) = self.peek() {
+            let op = if *op == '*' { BinaryOp::Mul } else { BinaryOp::Div };
+            self.next += 1;
+            let right = self.parse_unary()?;
+            expression = Expression::Binary(op, Box::new(expression), Box::new(right));
+        }
+        Ok(expression)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expression, ExpressionError> {
+        if let Some((_, Token::Op('-'))) = self.peek() {
+            self.next += 1;
+            let expression = self.parse_unary()?;
+            return Ok(Expression::Neg(Box::new(expression)));
+        }
+        self.parse_power()
+    }
+
+    fn parse_power(&mut self) -> Result<Expression, ExpressionError> {
+        let base = self.parse_primary()?;
+        if let Some((_, Token::Op('^'))) = self.peek() {
+            self.next += 1;
+            // the exponentiation is right associative
+            let exponent = self.parse_unary()?;
+            return Ok(Expression::Binary(BinaryOp::Pow, Box::new(base), Box::new(exponent)));
+        }
+        Ok(base)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expression, ExpressionError> {
+        match self.bump()? {
+            (_, Token::Number(number)) => Ok(Expression::Number(number)),
+            (_, Token::LeftParen) => {
+                let expression = self.parse_sum()?;
+                self.expect(Token::RightParen)?;
+                Ok(expression)
+            }
+            (_, Token::Ident(name)) => {
+                if !matches!(self.peek(), Some((_, Token::LeftParen))) {
+                    return Ok(Expression::Field(name));
+                }
+                self.next += 1;
+                let function =
+                    Function::from_name(&name).ok_or(ExpressionError::UnknownFunction { name })?;
+
+                let mut arguments = Vec::new();
+                if matches!(self.peek(), Some((_, Token::RightParen))) {
+                    self.next += 1;
+                } else {
+                    loop {
+                        arguments.push(self.parse_sum()?);
+                        match self.bump()? {
+                            (_, Token::Comma) => continue,
+                            (_, Token::RightParen) => break,
+                            (position, token) => {
+                                return Err(ExpressionError::UnexpectedToken {
+                                    found: token.to_string(),
+                                    position,
+                                })
+                            }
+                        }
+                    }
+                }
+                function.check_arity(arguments.len())?;
+                Ok(Expression::Call(function, arguments))
+            }
+            (position, token) => {
+                Err(ExpressionError::UnexpectedToken { found: token.to_string(), position })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(expression: &str, fields: &[(&str, f64)]) -> Option<f64> {
+        let expression: Expression = expression.parse().unwrap();
+        let field_value =
+            |name: &str| fields.iter().find(|(field, _)| *field == name).map(|(_, value)| *value);
+        expression.evaluate(&field_value, 1_000_000.0)
+    }
+
+    #[test]
+    fn evaluate_expressions() {
+        assert_eq!(evaluate("1 + 2 * 3", &[]), Some(7.0));
+        assert_eq!(evaluate("(1 + 2) * 3", &[]), Some(9.0));
+        assert_eq!(evaluate("-2 ^ 2", &[]), Some(-4.0));
+        assert_eq!(evaluate("2 ^ 3 ^ 2", &[]), Some(512.0));
+        assert_eq!(evaluate("max(1, rating, 3)", &[("rating", 4.5)]), Some(4.5));
+        assert_eq!(evaluate("log1p(sales) * 2", &[("sales", 0.0)]), Some(0.0));
+        assert_eq!(
+            evaluate("exp(-(now() - published_at) / 1000)", &[("published_at", 1e6)]),
+            Some(1.0)
+        );
+        assert_eq!(evaluate("stats.views / 10", &[("stats.views", 50.0)]), Some(5.0));
+        // missing fields and infinite results have no score
+        assert_eq!(evaluate("sales + 1", &[]), None);
+        assert_eq!(evaluate("1 / sales", &[("sales", 0.0)]), None);
+        assert_eq!(evaluate("ln(sales)", &[("sales", -1.0)]), None);
+    }
+
+    #[test]
+    fn expression_fields() {
+        let expression: Expression =
+            "log1p(sales) * exp(-age / 30) + rating - sales".parse().unwrap();
+        assert_eq!(expression.fields().into_iter().collect::<Vec<_>>(), ["age", "rating", "sales"]);
+    }
+
+    #[test]
+    fn invalid_expressions() {
+        let error = |s: &str| s.parse::<Expression>().unwrap_err().to_string();
+        insta::assert_snapshot!(error(""), @"the expression is empty");
+        insta::assert_snapshot!(error("1 +"), @"unexpected end of the expression");
+        insta::assert_snapshot!(error("sales )"), @"unexpected `)` at position 6");
+        insta::assert_snapshot!(error("sales % 2"), @"unexpected `%` at position 6");
+        insta::assert_snapshot!(error("log(sales)"), @"unknown function `log`, expected one of log1p, ln, log10, exp, sqrt, abs, floor, ceil, min, max, pow, now");
+        insta::assert_snapshot!(error("pow(sales)"), @"the `pow` function takes 2 argument(s) but 1 were given");
+    }
+}
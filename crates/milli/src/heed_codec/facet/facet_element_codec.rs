@@ -0,0 +1,170 @@
+use std::borrow::Cow;
+use std::str;
+
+use heed::{BoxedError, BytesDecode, BytesEncode};
+
+use crate::facet::value_encoding::f64_into_bytes;
+use crate::heed_codec::SliceTooShortError;
+use crate::{try_split_array_at, FieldId};
+
+/// The key in the [`facet_id_element_docids`][`crate::Index::facet_id_element_docids`] database.
+///
+/// The values of the fields found in the `element`-th object of a faceted array, for the nested
+/// filters to only match the values of a single object, and the number of elements of the
+/// faceted arrays, for the `SIZE` filters. The sizes of the arrays of the documents themselves
+/// are stored without any element, like the strings of the documents as they were written,
+/// for the case-sensitive equality filters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FacetElementKey<'a> {
+    pub field_id: FieldId,
+    pub element: Option<u32>,
+    pub value: FacetElementValue<'a>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FacetElementValue<'a> {
+    /// The field exists in the object.
+    Exists,
+    /// The field is set as null in the object.
+    Null,
+    /// The field is considered empty in the object.
+    Empty,
+    Number(f64),
+    /// A normalized string, see [`crate::normalize_facet`].
+    String(&'a str),
+    /// A string as it was written in the document.
+    OriginalString(&'a str),
+    /// The number of elements of the array stored in the field.
+    Size(u32),
+}
+
+impl FacetElementValue<'_> {
+    /// The byte written right after the field id, ordering the keys by kind of value.
+    pub fn kind(&self, element: Option<u32>) -> u8 {
+        match (self, element) {
+            (FacetElementValue::Size(_), None) => 0,
+            (FacetElementValue::Exists, _) => 1,
+            (FacetElementValue::Null, _) => 2,
+            (FacetElementValue::Empty, _) => 3,
+            (FacetElementValue::Number(_), _) => 4,
+            (FacetElementValue::String(_), _) => 5,
+            (FacetElementValue::OriginalString(_), Some(_)) => 6,
+            (FacetElementValue::Size(_), Some(_)) => 7,
+            (FacetElementValue::OriginalString(_), None) => 8,
+        }
+    }
+}
+
+impl FacetElementKey<'_> {
+    /// Returns the bytes shared by all the keys of the given kind of value of a field,
+    /// in a given element, the values of a kind are ordered in the database.
+    pub fn prefix(field_id: FieldId, kind: u8, element: Option<u32>) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 + 1 + 4);
+        bytes.extend_from_slice(&field_id.to_be_bytes());
+        bytes.push(kind);
+        if let Some(element) = element {
+            bytes.extend_from_slice(&element.to_be_bytes());
+        }
+        bytes
+    }
+}
+
+pub struct FacetElementKeyCodec;
+
+impl<'a> BytesEncode<'a> for FacetElementKeyCodec {
+    type EItem = FacetElementKey<'a>;
+
+    fn bytes_encode(key: &'a Self::EItem) -> Result<Cow<'a, [u8]>, BoxedError> {
+        let FacetElementKey { field_id, element, value } = *key;
+        let kind = value.kind(element);
+        let mut bytes = FacetElementKey::prefix(field_id, kind, element);
+        match value {
+            FacetElementValue::Exists | FacetElementValue::Null | FacetElementValue::Empty => (),
+            FacetElementValue::Number(number) => {
+                let number = f64_into_bytes(number).ok_or("the number must be finite")?;
+                bytes.extend_from_slice(&number);
+            }
+            FacetElementValue::String(string) | FacetElementValue::OriginalString(string) => {
+                bytes.extend_from_slice(string.as_bytes())
+            }
+            FacetElementValue::Size(size) => bytes.extend_from_slice(&size.to_be_bytes()),
+        }
+        Ok(Cow::Owned(bytes))
+    }
+}
+
+impl<'a> BytesDecode<'a> for FacetElementKeyCodec {
+    type DItem = FacetElementKey<'a>;
+
+    fn bytes_decode(bytes: &'a [u8]) -> Result<Self::DItem, BoxedError> {
+        let (field_id_bytes, bytes) = try_split_array_at(bytes).ok_or(SliceTooShortError)?;
+        let field_id = u16::from_be_bytes(field_id_bytes);
+        let ([kind], bytes) = try_split_array_at(bytes).ok_or(SliceTooShortError)?;
+
+        match kind {
+            0 => {
+                let (size_bytes, _) = try_split_array_at(bytes).ok_or(SliceTooShortError)?;
+                let value = FacetElementValue::Size(u32::from_be_bytes(size_bytes));
+                return Ok(FacetElementKey { field_id, element: None, value });
+            }
+            8 => {
+                let value = FacetElementValue::OriginalString(str::from_utf8(bytes)?);
+                return Ok(FacetElementKey { field_id, element: None, value });
+            }
+            _ => (),
+        }
+
+        let (element_bytes, bytes) = try_split_array_at(bytes).ok_or(SliceTooShortError)?;
+        let element = Some(u32::from_be_bytes(element_bytes));
+        let value = match kind {
+            1 => FacetElementValue::Exists,
+            2 => FacetElementValue::Null,
+            3 => FacetElementValue::Empty,
+            4 => {
+                let (number_bytes, _) = try_split_array_at(bytes).ok_or(SliceTooShortError)?;
+                FacetElementValue::Number(f64_from_bytes(number_bytes))
+            }
+            5 => FacetElementValue::String(str::from_utf8(bytes)?),
+            6 => FacetElementValue::OriginalString(str::from_utf8(bytes)?),
+            7 => {
+                let (size_bytes, _) = try_split_array_at(bytes).ok_or(SliceTooShortError)?;
+                FacetElementValue::Size(u32::from_be_bytes(size_bytes))
+            }
+            _ => return Err("invalid kind of facet element value".into()),
+        };
+
+        Ok(FacetElementKey { field_id, element, value })
+    }
+}
+
+/// Reverts the ordered encoding of [`f64_into_bytes`].
+fn f64_from_bytes(mut bytes: [u8; 8]) -> f64 {
+    if bytes[0] & 0x80 != 0 {
+        bytes[0] ^= 0x80;
+    } else {
+        bytes.iter_mut().for_each(|b| *b ^= 0xff);
+    }
+    f64::from_be_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_decode() {
+        let key = |element, value| FacetElementKey { field_id: 3, element, value };
+        let keys = [
+            key(None, FacetElementValue::Size(12)),
+            key(Some(2), FacetElementValue::Exists),
+            key(Some(2), FacetElementValue::Number(-1.5)),
+            key(Some(2), FacetElementValue::String("red")),
+            key(Some(0), FacetElementValue::Size(0)),
+            key(None, FacetElementValue::OriginalString("Red")),
+        ];
+        for key in keys {
+            let bytes = FacetElementKeyCodec::bytes_encode(&key).unwrap();
+            assert_eq!(FacetElementKeyCodec::bytes_decode(&bytes).unwrap(), key);
+        }
+    }
+}
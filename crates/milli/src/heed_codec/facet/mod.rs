@@ -1,3 +1,4 @@
+mod facet_element_codec;
 mod field_doc_id_facet_codec;
 mod ordered_f64_codec;
 
@@ -9,6 +10,7 @@ use heed::types::DecodeIgnore;
 use heed::{BoxedError, BytesDecode, BytesEncode};
 use roaring::RoaringBitmap;
 
+pub use self::facet_element_codec::{FacetElementKey, FacetElementKeyCodec, FacetElementValue};
 pub use self::field_doc_id_facet_codec::FieldDocIdFacetCodec;
 pub use self::ordered_f64_codec::OrderedF64Codec;
 use super::StrRefCodec;
@@ -10,23 +10,28 @@ use roaring::RoaringBitmap;
 use rstar::RTree;
 use serde::{Deserialize, Serialize};
 
+use crate::document_schema::DocumentSchema;
 use crate::documents::PrimaryKey;
 use crate::error::{InternalError, UserError};
+use crate::fieldids_weights_map::DEFAULT_ATTRIBUTE_WEIGHT;
 use crate::fields_ids_map::FieldsIdsMap;
+use crate::function_score::Expression;
 use crate::heed_codec::facet::{
-    FacetGroupKeyCodec, FacetGroupValueCodec, FieldDocIdFacetF64Codec, FieldDocIdFacetStringCodec,
-    FieldIdCodec, OrderedF64Codec,
+    FacetElementKeyCodec, FacetGroupKeyCodec, FacetGroupValueCodec, FieldDocIdFacetF64Codec,
+    FieldDocIdFacetStringCodec, FieldIdCodec, OrderedF64Codec,
 };
-use crate::heed_codec::{BEU16StrCodec, FstSetCodec, StrBEU16Codec, StrRefCodec};
+use crate::heed_codec::{BEU16StrCodec, BEU32StrCodec, FstSetCodec, StrBEU16Codec, StrRefCodec};
+use crate::ingest_pipeline::IngestProcessor;
 use crate::order_by_map::OrderByMap;
+use crate::phonetic::PhoneticAlgorithm;
 use crate::proximity::ProximityPrecision;
 use crate::vector::parsed_vectors::RESERVED_VECTORS_FIELD_NAME;
 use crate::vector::{ArroyWrapper, Embedding, EmbeddingConfig};
 use crate::{
     default_criteria, CboRoaringBitmapCodec, Criterion, DocumentId, ExternalDocumentsIds,
     FacetDistribution, FieldDistribution, FieldId, FieldIdMapMissingEntry, FieldIdWordCountCodec,
-    FieldidsWeightsMap, GeoPoint, LocalizedAttributesRule, ObkvCodec, Result, RoaringBitmapCodec,
-    RoaringBitmapLenCodec, Search, U8StrStrCodec, Weight, BEU16, BEU32, BEU64,
+    FieldidsWeightsMap, GeoPoint, LocalizedAttributesRule, Object, ObkvCodec, Result,
+    RoaringBitmapCodec, RoaringBitmapLenCodec, Search, U8StrStrCodec, Weight, BEU16, BEU32, BEU64,
 };
 
 pub const DEFAULT_MIN_WORD_LEN_ONE_TYPO: u8 = 5;
@@ -48,6 +53,7 @@ pub mod main_key {
     pub const PRIMARY_KEY_KEY: &str = "primary-key";
     pub const SEARCHABLE_FIELDS_KEY: &str = "searchable-fields";
     pub const USER_DEFINED_SEARCHABLE_FIELDS_KEY: &str = "user-defined-searchable-fields";
+    pub const SEARCHABLE_ATTRIBUTES_WEIGHTS_KEY: &str = "searchable-attributes-weights";
     pub const STOP_WORDS_KEY: &str = "stop-words";
     pub const NON_SEPARATOR_TOKENS_KEY: &str = "non-separator-tokens";
     pub const SEPARATOR_TOKENS_KEY: &str = "separator-tokens";
@@ -63,6 +69,8 @@ pub mod main_key {
     pub const TWO_TYPOS_WORD_LEN: &str = "two-typos-word-len";
     pub const EXACT_WORDS: &str = "exact-words";
     pub const EXACT_ATTRIBUTES: &str = "exact-attributes";
+    pub const PHONETIC_ATTRIBUTES: &str = "phonetic-attributes";
+    pub const PHONETIC_ALGORITHM: &str = "phonetic-algorithm";
     pub const MAX_VALUES_PER_FACET: &str = "max-values-per-facet";
     pub const SORT_FACET_VALUES_BY: &str = "sort-facet-values-by";
     pub const PAGINATION_MAX_TOTAL_HITS: &str = "pagination-max-total-hits";
@@ -70,6 +78,14 @@ pub mod main_key {
     pub const EMBEDDING_CONFIGS: &str = "embedding_configs";
     pub const SEARCH_CUTOFF: &str = "search_cutoff";
     pub const LOCALIZED_ATTRIBUTES_RULES: &str = "localized_attributes_rules";
+    pub const SETTINGS_HISTORY: &str = "settings-history";
+    pub const CHANGELOG: &str = "changelog";
+    pub const VERSION_ATTRIBUTE: &str = "version-attribute";
+    pub const AUTO_GENERATE_IDS: &str = "auto-generate-ids";
+    pub const DOCUMENT_SCHEMA: &str = "document-schema";
+    pub const INGEST_PIPELINE: &str = "ingest-pipeline";
+    pub const COMPUTED_FIELDS: &str = "computed-fields";
+    pub const DATE_FIELDS: &str = "date-fields";
 }
 
 pub mod db_name {
@@ -85,6 +101,7 @@ pub mod db_name {
     pub const WORD_FIELD_ID_DOCIDS: &str = "word-field-id-docids";
     pub const WORD_PREFIX_POSITION_DOCIDS: &str = "word-prefix-position-docids";
     pub const WORD_PREFIX_FIELD_ID_DOCIDS: &str = "word-prefix-field-id-docids";
+    pub const PHONETIC_CODE_WORDS: &str = "phonetic-code-words";
     pub const FIELD_ID_WORD_COUNT_DOCIDS: &str = "field-id-word-count-docids";
     pub const FACET_ID_F64_DOCIDS: &str = "facet-id-f64-docids";
     pub const FACET_ID_EXISTS_DOCIDS: &str = "facet-id-exists-docids";
@@ -93,11 +110,13 @@ pub mod db_name {
     pub const FACET_ID_STRING_DOCIDS: &str = "facet-id-string-docids";
     pub const FACET_ID_NORMALIZED_STRING_STRINGS: &str = "facet-id-normalized-string-strings";
     pub const FACET_ID_STRING_FST: &str = "facet-id-string-fst";
+    pub const FACET_ID_ELEMENT_DOCIDS: &str = "facet-id-element-docids";
     pub const FIELD_ID_DOCID_FACET_F64S: &str = "field-id-docid-facet-f64s";
     pub const FIELD_ID_DOCID_FACET_STRINGS: &str = "field-id-docid-facet-strings";
     pub const VECTOR_EMBEDDER_CATEGORY_ID: &str = "vector-embedder-category-id";
     pub const VECTOR_ARROY: &str = "vector-arroy";
     pub const DOCUMENTS: &str = "documents";
+    pub const DOCUMENT_CHANGES: &str = "document-changes";
 }
 
 #[derive(Clone)]
@@ -137,6 +156,9 @@ pub struct Index {
     pub word_prefix_position_docids: Database<StrBEU16Codec, CboRoaringBitmapCodec>,
     /// Maps the word prefix and a field id with all the docids where the prefix appears inside the field
     pub word_prefix_fid_docids: Database<StrBEU16Codec, CboRoaringBitmapCodec>,
+    /// Maps the phonetic codes with the words of the index having these codes, only filled
+    /// when there are phonetic attributes.
+    pub phonetic_code_words: Database<Str, SerdeJson<BTreeSet<String>>>,
 
     /// Maps the facet field id and the docids for which this field exists
     pub facet_id_exists_docids: Database<FieldIdCodec, CboRoaringBitmapCodec>,
@@ -153,6 +175,9 @@ pub struct Index {
     pub facet_id_normalized_string_strings: Database<BEU16StrCodec, SerdeJson<BTreeSet<String>>>,
     /// Maps the facet field id of the string facets with an FST containing all the facets values.
     pub facet_id_string_fst: Database<BEU16, FstSetCodec>,
+    /// Maps the facet field id, the element of the faceted arrays and the values of this
+    /// element with the docids that corresponds to them.
+    pub facet_id_element_docids: Database<FacetElementKeyCodec, CboRoaringBitmapCodec>,
 
     /// Maps the document id, the facet field id and the numbers.
     pub field_id_docid_facet_f64s: Database<FieldDocIdFacetF64Codec, Unit>,
@@ -166,6 +191,10 @@ pub struct Index {
 
     /// Maps the document id to the document as an obkv store.
     pub(crate) documents: Database<BEU32, ObkvCodec>,
+
+    /// Maps the task uid and the external document id to the kind of change
+    /// the task made to the document, only filled when the changelog is enabled.
+    pub document_changes: Database<BEU32StrCodec, SerdeJson<DocumentChangeKind>>,
 }
 
 impl Index {
@@ -177,7 +206,7 @@ impl Index {
     ) -> Result<Index> {
         use db_name::*;
 
-        options.max_dbs(25);
+        options.max_dbs(28);
 
         let env = unsafe { options.open(path) }?;
         let mut wtxn = env.write_txn()?;
@@ -199,12 +228,15 @@ impl Index {
             env.create_database(&mut wtxn, Some(WORD_PREFIX_POSITION_DOCIDS))?;
         let word_prefix_fid_docids =
             env.create_database(&mut wtxn, Some(WORD_PREFIX_FIELD_ID_DOCIDS))?;
+        let phonetic_code_words = env.create_database(&mut wtxn, Some(PHONETIC_CODE_WORDS))?;
         let facet_id_f64_docids = env.create_database(&mut wtxn, Some(FACET_ID_F64_DOCIDS))?;
         let facet_id_string_docids =
             env.create_database(&mut wtxn, Some(FACET_ID_STRING_DOCIDS))?;
         let facet_id_normalized_string_strings =
             env.create_database(&mut wtxn, Some(FACET_ID_NORMALIZED_STRING_STRINGS))?;
         let facet_id_string_fst = env.create_database(&mut wtxn, Some(FACET_ID_STRING_FST))?;
+        let facet_id_element_docids =
+            env.create_database(&mut wtxn, Some(FACET_ID_ELEMENT_DOCIDS))?;
         let facet_id_exists_docids =
             env.create_database(&mut wtxn, Some(FACET_ID_EXISTS_DOCIDS))?;
         let facet_id_is_null_docids =
@@ -221,6 +253,7 @@ impl Index {
         let vector_arroy = env.create_database(&mut wtxn, Some(VECTOR_ARROY))?;
 
         let documents = env.create_database(&mut wtxn, Some(DOCUMENTS))?;
+        let document_changes = env.create_database(&mut wtxn, Some(DOCUMENT_CHANGES))?;
         wtxn.commit()?;
 
         Index::set_creation_dates(&env, main, created_at, updated_at)?;
@@ -238,11 +271,13 @@ impl Index {
             word_fid_docids,
             word_prefix_position_docids,
             word_prefix_fid_docids,
+            phonetic_code_words,
             field_id_word_count_docids,
             facet_id_f64_docids,
             facet_id_string_docids,
             facet_id_normalized_string_strings,
             facet_id_string_fst,
+            facet_id_element_docids,
             facet_id_exists_docids,
             facet_id_is_null_docids,
             facet_id_is_empty_docids,
@@ -251,6 +286,7 @@ impl Index {
             vector_arroy,
             embedder_category_id,
             documents,
+            document_changes,
         })
     }
 
@@ -444,6 +480,7 @@ impl Index {
             .unwrap_or_else(|| {
                 Ok(FieldidsWeightsMap::from_field_id_map_without_searchable(
                     &self.fields_ids_map(rtxn)?,
+                    &self.searchable_attributes_weights(rtxn)?,
                 ))
             })
     }
@@ -649,7 +686,9 @@ impl Index {
         // We can write the user defined searchable fields as-is.
         self.put_user_defined_searchable_fields(wtxn, user_fields)?;
 
+        let attributes_weights = self.searchable_attributes_weights(wtxn)?;
         let mut weights = FieldidsWeightsMap::default();
+        let mut explicit_weights = Vec::new();
 
         // Now we generate the real searchable fields:
         // 1. Take the user defined searchable fields as-is to keep the priority defined by the attributes criterion.
@@ -669,10 +708,21 @@ impl Index {
                     let weight: u16 =
                         weight.try_into().map_err(|_| UserError::AttributeLimitReached)?;
                     weights.insert(id, weight);
+
+                    let explicit_weight = attributes_weights
+                        .get(*user_field)
+                        .copied()
+                        .unwrap_or(DEFAULT_ATTRIBUTE_WEIGHT);
+                    explicit_weights.push((id, explicit_weight));
                 }
             }
         }
 
+        // The explicit weights replace the order of the searchable attributes when defined.
+        if !attributes_weights.is_empty() {
+            weights = FieldidsWeightsMap::from_attributes_weights(explicit_weights);
+        }
+
         self.put_searchable_fields(wtxn, &real_fields)?;
         self.put_fieldids_weights_map(wtxn, &weights)?;
         Ok(())
@@ -759,6 +809,41 @@ impl Index {
             .get(rtxn, main_key::USER_DEFINED_SEARCHABLE_FIELDS_KEY)
     }
 
+    /// Writes the explicit weights of the searchable attributes, the most important attributes
+    /// having the highest weights.
+    pub(crate) fn put_searchable_attributes_weights(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        weights: &BTreeMap<String, Weight>,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<_>>().put(
+            wtxn,
+            main_key::SEARCHABLE_ATTRIBUTES_WEIGHTS_KEY,
+            weights,
+        )
+    }
+
+    /// Deletes the explicit weights of the searchable attributes, the order of the searchable
+    /// attributes defines their importance again.
+    pub(crate) fn delete_searchable_attributes_weights(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+    ) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::SEARCHABLE_ATTRIBUTES_WEIGHTS_KEY)
+    }
+
+    /// Returns the explicit weights of the searchable attributes.
+    pub fn searchable_attributes_weights(
+        &self,
+        rtxn: &RoTxn<'_>,
+    ) -> heed::Result<BTreeMap<String, Weight>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<_>>()
+            .get(rtxn, main_key::SEARCHABLE_ATTRIBUTES_WEIGHTS_KEY)?
+            .unwrap_or_default())
+    }
+
     /// Identical to `user_defined_searchable_fields`, but returns ids instead.
     pub fn user_defined_searchable_fields_ids(
         &self,
@@ -902,15 +987,20 @@ impl Index {
 
     /// Returns the user defined faceted fields names.
     ///
-    /// The user faceted fields are the union of all the filterable, sortable, distinct, and Asc/Desc fields.
+    /// The user faceted fields are the union of all the filterable, sortable, distinct, Asc/Desc,
+    /// and function ranking rules fields.
     pub fn user_defined_faceted_fields(&self, rtxn: &RoTxn<'_>) -> Result<HashSet<String>> {
         let filterable_fields = self.filterable_fields(rtxn)?;
         let sortable_fields = self.sortable_fields(rtxn)?;
         let distinct_field = self.distinct_field(rtxn)?;
         let asc_desc_fields =
-            self.criteria(rtxn)?.into_iter().filter_map(|criterion| match criterion {
-                Criterion::Asc(field) | Criterion::Desc(field) => Some(field),
-                _otherwise => None,
+            self.criteria(rtxn)?.into_iter().flat_map(|criterion| match criterion {
+                Criterion::Asc(field) | Criterion::Desc(field) => vec![field],
+                Criterion::Function(expression) => match expression.parse::<Expression>() {
+                    Ok(expression) => expression.fields().into_iter().map(String::from).collect(),
+                    Err(_) => Vec::new(),
+                },
+                _otherwise => Vec::new(),
             });
 
         let mut faceted_fields = filterable_fields;
@@ -994,6 +1084,31 @@ impl Index {
         self.main.remap_key_type::<Str>().delete(wtxn, main_key::DISTINCT_FIELD_KEY)
     }
 
+    /* version attribute */
+
+    pub(crate) fn put_version_attribute(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        version_attribute: &str,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, Str>().put(
+            wtxn,
+            main_key::VERSION_ATTRIBUTE,
+            version_attribute,
+        )
+    }
+
+    /// The attribute holding the version of the documents, if any.
+    ///
+    /// When set, an incoming document only replaces the stored one if its version is greater.
+    pub fn version_attribute<'a>(&self, rtxn: &'a RoTxn<'_>) -> heed::Result<Option<&'a str>> {
+        self.main.remap_types::<Str, Str>().get(rtxn, main_key::VERSION_ATTRIBUTE)
+    }
+
+    pub(crate) fn delete_version_attribute(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::VERSION_ATTRIBUTE)
+    }
+
     /* criteria */
 
     pub(crate) fn put_criteria(
@@ -1484,6 +1599,63 @@ impl Index {
         self.main.remap_key_type::<Str>().delete(txn, main_key::EXACT_ATTRIBUTES)
     }
 
+    /// Returns the phonetic attributes: attributes matched by the phonetic codes of the words.
+    pub fn phonetic_attributes<'t>(&self, txn: &'t RoTxn<'t>) -> Result<Vec<&'t str>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeBincode<Vec<&str>>>()
+            .get(txn, main_key::PHONETIC_ATTRIBUTES)?
+            .unwrap_or_default())
+    }
+
+    /// Returns the list of phonetic attributes field ids.
+    pub fn phonetic_attributes_ids(&self, txn: &RoTxn<'_>) -> Result<HashSet<FieldId>> {
+        let attrs = self.phonetic_attributes(txn)?;
+        let fid_map = self.fields_ids_map(txn)?;
+        Ok(attrs.iter().filter_map(|attr| fid_map.id(attr)).collect())
+    }
+
+    /// Writes the phonetic attributes to the database.
+    pub(crate) fn put_phonetic_attributes(
+        &self,
+        txn: &mut RwTxn<'_>,
+        attrs: &[&str],
+    ) -> Result<()> {
+        self.main.remap_types::<Str, SerdeBincode<&[&str]>>().put(
+            txn,
+            main_key::PHONETIC_ATTRIBUTES,
+            &attrs,
+        )?;
+        Ok(())
+    }
+
+    /// Clears the phonetic attributes from the store.
+    pub(crate) fn delete_phonetic_attributes(&self, txn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(txn, main_key::PHONETIC_ATTRIBUTES)
+    }
+
+    pub fn phonetic_algorithm(&self, txn: &RoTxn<'_>) -> heed::Result<Option<PhoneticAlgorithm>> {
+        self.main
+            .remap_types::<Str, SerdeBincode<PhoneticAlgorithm>>()
+            .get(txn, main_key::PHONETIC_ALGORITHM)
+    }
+
+    pub(crate) fn put_phonetic_algorithm(
+        &self,
+        txn: &mut RwTxn<'_>,
+        val: PhoneticAlgorithm,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeBincode<PhoneticAlgorithm>>().put(
+            txn,
+            main_key::PHONETIC_ALGORITHM,
+            &val,
+        )
+    }
+
+    pub(crate) fn delete_phonetic_algorithm(&self, txn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(txn, main_key::PHONETIC_ALGORITHM)
+    }
+
     pub fn max_values_per_facet(&self, txn: &RoTxn<'_>) -> heed::Result<Option<u64>> {
         self.main.remap_types::<Str, BEU64>().get(txn, main_key::MAX_VALUES_PER_FACET)
     }
@@ -1647,6 +1819,204 @@ impl Index {
         Ok(res)
     }
 
+    pub fn put_settings_history(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        history: &[SettingsVersion],
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<&[SettingsVersion]>>().put(
+            wtxn,
+            main_key::SETTINGS_HISTORY,
+            &history,
+        )
+    }
+
+    /// Returns the recorded versions of the settings of this index, oldest first.
+    pub fn settings_history(&self, rtxn: &RoTxn<'_>) -> Result<Vec<SettingsVersion>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<Vec<SettingsVersion>>>()
+            .get(rtxn, main_key::SETTINGS_HISTORY)?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn put_changelog(&self, wtxn: &mut RwTxn<'_>, enabled: bool) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<bool>>().put(wtxn, main_key::CHANGELOG, &enabled)
+    }
+
+    /// Whether the document changes made by the tasks must be recorded, disabled by default.
+    pub fn changelog(&self, rtxn: &RoTxn<'_>) -> Result<bool> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<bool>>()
+            .get(rtxn, main_key::CHANGELOG)?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn delete_changelog(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::CHANGELOG)
+    }
+
+    pub(crate) fn put_auto_generate_ids(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        enabled: bool,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<bool>>().put(
+            wtxn,
+            main_key::AUTO_GENERATE_IDS,
+            &enabled,
+        )
+    }
+
+    /// Whether an id must be generated for the documents without one, disabled by default.
+    pub fn auto_generate_ids(&self, rtxn: &RoTxn<'_>) -> Result<bool> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<bool>>()
+            .get(rtxn, main_key::AUTO_GENERATE_IDS)?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn delete_auto_generate_ids(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::AUTO_GENERATE_IDS)
+    }
+
+    pub(crate) fn put_document_schema(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        schema: &DocumentSchema,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<DocumentSchema>>().put(
+            wtxn,
+            main_key::DOCUMENT_SCHEMA,
+            schema,
+        )
+    }
+
+    /// The schema the documents must conform to, if any.
+    pub fn document_schema(&self, rtxn: &RoTxn<'_>) -> heed::Result<Option<DocumentSchema>> {
+        self.main
+            .remap_types::<Str, SerdeJson<DocumentSchema>>()
+            .get(rtxn, main_key::DOCUMENT_SCHEMA)
+    }
+
+    pub(crate) fn delete_document_schema(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::DOCUMENT_SCHEMA)
+    }
+
+    pub(crate) fn put_ingest_pipeline(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        pipeline: &[IngestProcessor],
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<&[IngestProcessor]>>().put(
+            wtxn,
+            main_key::INGEST_PIPELINE,
+            &pipeline,
+        )
+    }
+
+    /// The processors applied on the documents before they are indexed, empty by default.
+    pub fn ingest_pipeline(&self, rtxn: &RoTxn<'_>) -> Result<Vec<IngestProcessor>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<Vec<IngestProcessor>>>()
+            .get(rtxn, main_key::INGEST_PIPELINE)?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn delete_ingest_pipeline(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::INGEST_PIPELINE)
+    }
+
+    pub(crate) fn put_computed_fields(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        fields: &BTreeMap<String, String>,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<&BTreeMap<String, String>>>().put(
+            wtxn,
+            main_key::COMPUTED_FIELDS,
+            &fields,
+        )
+    }
+
+    /// The Rhai expressions of the fields computed on the documents, by field name.
+    pub fn computed_fields(&self, rtxn: &RoTxn<'_>) -> Result<BTreeMap<String, String>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<BTreeMap<String, String>>>()
+            .get(rtxn, main_key::COMPUTED_FIELDS)?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn delete_computed_fields(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::COMPUTED_FIELDS)
+    }
+
+    /* date fields */
+
+    pub(crate) fn put_date_fields(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        fields: &BTreeSet<String>,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<_>>().put(wtxn, main_key::DATE_FIELDS, fields)
+    }
+
+    /// The fields holding dates, their RFC 3339 and `YYYY-MM-DD` values are also faceted
+    /// as Unix timestamps in seconds to be filtered and sorted chronologically.
+    pub fn date_fields(&self, rtxn: &RoTxn<'_>) -> heed::Result<BTreeSet<String>> {
+        Ok(self
+            .main
+            .remap_types::<Str, SerdeJson<_>>()
+            .get(rtxn, main_key::DATE_FIELDS)?
+            .unwrap_or_default())
+    }
+
+    pub(crate) fn delete_date_fields(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::DATE_FIELDS)
+    }
+
+    pub fn put_document_change(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        task_uid: u32,
+        external_id: &str,
+        kind: DocumentChangeKind,
+    ) -> heed::Result<()> {
+        self.document_changes.put(wtxn, &(task_uid, external_id), &kind)
+    }
+
+    /// Returns the document changes made by the tasks with an uid strictly greater than `since`,
+    /// ordered by task uid.
+    pub fn document_changes<'t>(
+        &self,
+        rtxn: &'t RoTxn<'t>,
+        since: Option<u32>,
+    ) -> Result<impl Iterator<Item = Result<DocumentChangeEntry>> + 't> {
+        let iter = match since {
+            Some(u32::MAX) => None,
+            Some(since) => Some(self.document_changes.range(rtxn, &((since + 1, "")..))?),
+            None => Some(self.document_changes.range(rtxn, &(..))?),
+        };
+
+        Ok(iter.into_iter().flatten().map(|entry| {
+            let ((task_uid, document_id), kind) = entry?;
+            Ok(DocumentChangeEntry { task_uid, document_id: document_id.to_string(), kind })
+        }))
+    }
+
+    /// Removes the document changes made by the tasks with an uid strictly lower than `task_uid`.
+    pub fn delete_document_changes_before(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        task_uid: u32,
+    ) -> heed::Result<usize> {
+        self.document_changes.delete_range(wtxn, &(..(task_uid, "")))
+    }
+
     pub fn prefix_settings(&self, _rtxn: &RoTxn<'_>) -> Result<PrefixSettings> {
         Ok(PrefixSettings {
             compute_prefixes: true,
@@ -1663,6 +2033,43 @@ pub struct IndexEmbeddingConfig {
     pub user_provided: RoaringBitmap,
 }
 
+/// The kind of modification a task made to a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DocumentChangeKind {
+    #[serde(rename = "add")]
+    Addition,
+    Update,
+    #[serde(rename = "delete")]
+    Deletion,
+}
+
+/// An entry of the changelog of the index.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentChangeEntry {
+    pub task_uid: u32,
+    pub document_id: String,
+    #[serde(rename = "operation")]
+    pub kind: DocumentChangeKind,
+}
+
+/// The effective settings of the index right after a settings update was applied.
+///
+/// The settings are stored in their serialized form since their
+/// representation is owned by the HTTP layer.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsVersion {
+    pub version: u32,
+    /// The uid of the task that produced this version, `None` for the
+    /// settings the index had before its first recorded update.
+    pub task_uid: Option<u32>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub recorded_at: time::OffsetDateTime,
+    pub settings: Object,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct PrefixSettings {
     pub prefix_count_threshold: u64,
@@ -0,0 +1,181 @@
+//! Declarative transformations applied on the documents of an index before they are indexed.
+//!
+//! The processors of a pipeline are applied in order on the top-level fields of every document
+//! added or updated. A processor ignores the documents in which its field is missing or holds
+//! a value it can't transform, e.g. a date that can't be parsed is kept as is.
+
+use deserr::Deserr;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+
+use crate::parse_date;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Deserr)]
+#[serde(tag = "type", rename_all = "camelCase", deny_unknown_fields)]
+#[deserr(tag = "type", rename_all = camelCase, deny_unknown_fields)]
+pub enum IngestProcessor {
+    /// Moves the value of a field to another field.
+    Rename { field: String, to: String },
+    /// Removes a field.
+    Remove { field: String },
+    /// Splits a string into an array of the trimmed and non-empty parts.
+    Split { field: String, separator: String },
+    /// Replaces an RFC 3339 date-time or a `YYYY-MM-DD` date by its Unix timestamp in seconds.
+    ParseDate { field: String },
+    /// Sets a field when it is missing or `null`.
+    SetDefault { field: String, value: Value },
+    /// Lowercases a string or the strings of an array.
+    Lowercase { field: String },
+    /// Builds the `_geo` field from a latitude and a longitude field.
+    Geo { lat: String, lng: String },
+}
+
+/// Applies the processors of the pipeline in order on the document.
+///
+/// A `partial` document only contains the fields to update, the missing fields
+/// are kept as they are stored and must not be given a default value.
+pub fn apply_ingest_pipeline(
+    pipeline: &[IngestProcessor],
+    document: &mut Map<String, Value>,
+    partial: bool,
+) {
+    for processor in pipeline {
+        processor.apply(document, partial);
+    }
+}
+
+impl IngestProcessor {
+    fn apply(&self, document: &mut Map<String, Value>, partial: bool) {
+        match self {
+            IngestProcessor::Rename { field, to } => {
+                if let Some(value) = document.shift_remove(field) {
+                    document.insert(to.clone(), value);
+                }
+            }
+            IngestProcessor::Remove { field } => {
+                document.shift_remove(field);
+            }
+            IngestProcessor::Split { field, separator } => {
+                if let Some(Value::String(string)) = document.get(field) {
+                    let parts = string
+                        .split(separator.as_str())
+                        .map(str::trim)
+                        .filter(|part| !part.is_empty())
+                        .map(|part| Value::String(part.to_string()))
+                        .collect();
+                    document.insert(field.clone(), Value::Array(parts));
+                }
+            }
+            IngestProcessor::ParseDate { field } => {
+                if let Some(Value::String(string)) = document.get(field) {
+                    if let Some(timestamp) = parse_date(string) {
+                        document.insert(field.clone(), Value::Number(Number::from(timestamp)));
+                    }
+                }
+            }
+            IngestProcessor::SetDefault { field, value } => {
+                if document.get(field).map_or(!partial, Value::is_null) {
+                    document.insert(field.clone(), value.clone());
+                }
+            }
+            IngestProcessor::Lowercase { field } => match document.get_mut(field) {
+                Some(Value::String(string)) => *string = string.to_lowercase(),
+                Some(Value::Array(values)) => {
+                    for value in values {
+                        if let Value::String(string) = value {
+                            *string = string.to_lowercase();
+                        }
+                    }
+                }
+                _ => (),
+            },
+            IngestProcessor::Geo { lat, lng } => {
+                let lat = document.get(lat).filter(|value| !value.is_null());
+                let lng = document.get(lng).filter(|value| !value.is_null());
+                if let (Some(lat), Some(lng)) = (lat, lng) {
+                    let mut geo = Map::new();
+                    geo.insert("lat".to_string(), lat.clone());
+                    geo.insert("lng".to_string(), lng.clone());
+                    document.insert("_geo".to_string(), Value::Object(geo));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn apply(pipeline: Value, document: Value) -> Value {
+        let pipeline: Vec<IngestProcessor> = serde_json::from_value(pipeline).unwrap();
+        let Value::Object(mut document) = document else { panic!() };
+        apply_ingest_pipeline(&pipeline, &mut document, false);
+        Value::Object(document)
+    }
+
+    #[test]
+    fn pipeline() {
+        let pipeline = json!([
+            { "type": "rename", "field": "name", "to": "title" },
+            { "type": "lowercase", "field": "title" },
+            { "type": "split", "field": "tags", "separator": "," },
+            { "type": "parseDate", "field": "released" },
+            { "type": "parseDate", "field": "updated" },
+            { "type": "setDefault", "field": "stock", "value": 0 },
+            { "type": "geo", "lat": "latitude", "lng": "longitude" },
+            { "type": "remove", "field": "latitude" },
+            { "type": "remove", "field": "longitude" },
+        ]);
+        let document = json!({
+            "id": 1,
+            "name": "Dune",
+            "tags": "sf, classic,,",
+            "released": "1965-08-01",
+            "updated": "2024-01-01T12:00:00Z",
+            "stock": null,
+            "latitude": 48.8,
+            "longitude": "2.3",
+        });
+
+        let document = apply(pipeline, document);
+        assert_eq!(
+            document,
+            json!({
+                "id": 1,
+                "title": "dune",
+                "tags": ["sf", "classic"],
+                "released": -139449600,
+                "updated": 1704110400,
+                "stock": 0,
+                "_geo": { "lat": 48.8, "lng": "2.3" },
+            })
+        );
+    }
+
+    #[test]
+    fn ignore_invalid_values() {
+        let pipeline = json!([
+            { "type": "parseDate", "field": "date" },
+            { "type": "split", "field": "tags", "separator": "," },
+            { "type": "geo", "lat": "lat", "lng": "lng" },
+        ]);
+        let document = json!({ "date": "yesterday", "tags": 12, "lat": 1 });
+        let document = apply(pipeline, document);
+        assert_eq!(document, json!({ "date": "yesterday", "tags": 12, "lat": 1 }));
+    }
+
+    #[test]
+    fn partial_documents_keep_their_missing_fields() {
+        let pipeline = json!([
+            { "type": "setDefault", "field": "stock", "value": 0 },
+            { "type": "setDefault", "field": "color", "value": "black" },
+        ]);
+        let pipeline: Vec<IngestProcessor> = serde_json::from_value(pipeline).unwrap();
+        let Value::Object(mut document) = json!({ "id": 1, "color": null }) else { panic!() };
+        apply_ingest_pipeline(&pipeline, &mut document, true);
+        assert_eq!(Value::Object(document), json!({ "id": 1, "color": "black" }));
+    }
+}
@@ -10,18 +10,23 @@ pub mod documents;
 
 mod asc_desc;
 mod criterion;
+pub mod document_schema;
 mod error;
 mod external_documents_ids;
 pub mod facet;
 mod fields_ids_map;
+pub mod function_score;
 pub mod heed_codec;
 pub mod index;
+pub mod ingest_pipeline;
 mod localized_attributes_rules;
 pub mod order_by_map;
+pub mod phonetic;
 pub mod prompt;
 pub mod proximity;
 pub mod score_details;
 mod search;
+mod stemming;
 mod thread_pool_no_abort;
 pub mod update;
 pub mod vector;
@@ -37,7 +42,7 @@ use std::fmt;
 use std::hash::BuildHasherDefault;
 
 use charabia::normalizer::{CharNormalizer, CompatibilityDecompositionNormalizer};
-pub use filter_parser::{Condition, FilterCondition, Span, Token};
+pub use filter_parser::{parse_date, Condition, FilterCondition, Span, Token};
 use fxhash::{FxHasher32, FxHasher64};
 pub use grenad::CompressionType;
 pub use search::new::{
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use charabia::Language;
 use serde::{Deserialize, Serialize};
@@ -14,15 +14,24 @@ use crate::FieldId;
 /// The pattern `attribute_name*` matches any attribute name that starts with `attribute_name`.
 /// The pattern `*attribute_name` matches any attribute name that ends with `attribute_name`.
 /// The pattern `*attribute_name*` matches any attribute name that contains `attribute_name`.
+///
+/// When `stemming` is enabled, the words of the matching attributes are also indexed
+/// under their stem in the locales that have a Snowball stemmer.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LocalizedAttributesRule {
     pub attribute_patterns: Vec<String>,
     pub locales: Vec<Language>,
+    #[serde(default)]
+    pub stemming: bool,
 }
 
 impl LocalizedAttributesRule {
     pub fn new(attribute_patterns: Vec<String>, locales: Vec<Language>) -> Self {
-        Self { attribute_patterns, locales }
+        Self { attribute_patterns, locales, stemming: false }
+    }
+
+    pub fn with_stemming(self, stemming: bool) -> Self {
+        Self { stemming, ..self }
     }
 
     pub fn match_str(&self, str: &str) -> bool {
@@ -32,6 +41,15 @@ impl LocalizedAttributesRule {
     pub fn locales(&self) -> &[Language] {
         &self.locales
     }
+
+    /// Returns the locales in which the words of the matching attributes are stemmed.
+    pub fn stemmed_locales(&self) -> &[Language] {
+        if self.stemming {
+            &self.locales
+        } else {
+            &[]
+        }
+    }
 }
 
 fn match_pattern(pattern: &str, str: &str) -> bool {
@@ -51,6 +69,7 @@ fn match_pattern(pattern: &str, str: &str) -> bool {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LocalizedFieldIds {
     field_id_to_locales: HashMap<FieldId, Vec<Language>>,
+    stemmed_field_ids: HashSet<FieldId>,
 }
 
 impl LocalizedFieldIds {
@@ -60,6 +79,7 @@ impl LocalizedFieldIds {
         fields_ids: I,
     ) -> Self {
         let mut field_id_to_locales = HashMap::new();
+        let mut stemmed_field_ids = HashSet::new();
 
         if let Some(rules) = rules {
             let fields = fields_ids.filter_map(|field_id| {
@@ -71,6 +91,9 @@ impl LocalizedFieldIds {
                 for rule in rules {
                     if rule.match_str(field_name) {
                         locales.extend(rule.locales.iter());
+                        if rule.stemming {
+                            stemmed_field_ids.insert(field_id);
+                        }
                         // Take the first rule that matches
                         break;
                     }
@@ -84,13 +107,33 @@ impl LocalizedFieldIds {
             }
         }
 
-        Self { field_id_to_locales }
+        Self { field_id_to_locales, stemmed_field_ids }
     }
 
     pub fn locales(&self, fields_id: FieldId) -> Option<&[Language]> {
         self.field_id_to_locales.get(&fields_id).map(Vec::as_slice)
     }
 
+    /// Returns the locales in which the words of the field are stemmed.
+    pub fn stemmed_locales(&self, fields_id: FieldId) -> &[Language] {
+        match self.locales(fields_id) {
+            Some(locales) if self.stemmed_field_ids.contains(&fields_id) => locales,
+            _ => &[],
+        }
+    }
+
+    /// Returns all the locales in which the words of the fields are stemmed.
+    pub fn all_stemmed_locales(&self) -> Vec<Language> {
+        let mut locales: Vec<_> = self
+            .stemmed_field_ids
+            .iter()
+            .flat_map(|fid| self.stemmed_locales(*fid).iter().copied())
+            .collect();
+        locales.sort();
+        locales.dedup();
+        locales
+    }
+
     pub fn all_locales(&self) -> Vec<Language> {
         let mut locales = Vec::new();
         for field_locales in self.field_id_to_locales.values() {
@@ -0,0 +1,165 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::OnceLock;
+
+use fst::Streamer;
+use heed::{RoTxn, RwTxn};
+use rphonetic::{BeiderMorse, BeiderMorseBuilder, ConfigFiles, DoubleMetaphone, Encoder};
+use serde::{Deserialize, Serialize};
+
+use crate::stemming::is_stem_word;
+use crate::{Index, Result};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum PhoneticAlgorithm {
+    #[default]
+    DoubleMetaphone,
+    BeiderMorse,
+}
+
+/// Computes the phonetic codes of the words with the algorithm of the index.
+pub enum PhoneticEncoder {
+    DoubleMetaphone(DoubleMetaphone),
+    BeiderMorse(BeiderMorse<'static>),
+}
+
+impl PhoneticEncoder {
+    pub fn new(algorithm: PhoneticAlgorithm) -> Self {
+        match algorithm {
+            PhoneticAlgorithm::DoubleMetaphone => {
+                PhoneticEncoder::DoubleMetaphone(DoubleMetaphone::default())
+            }
+            PhoneticAlgorithm::BeiderMorse => {
+                static CONFIG_FILES: OnceLock<ConfigFiles> = OnceLock::new();
+                let config_files = CONFIG_FILES.get_or_init(ConfigFiles::default);
+                PhoneticEncoder::BeiderMorse(BeiderMorseBuilder::new(config_files).build())
+            }
+        }
+    }
+
+    /// Returns the deduplicated phonetic codes of the word.
+    pub fn encode(&self, word: &str) -> BTreeSet<String> {
+        let mut codes = BTreeSet::new();
+        match self {
+            PhoneticEncoder::DoubleMetaphone(encoder) => {
+                codes.insert(encoder.encode(word));
+                codes.insert(encoder.encode_alternate(word));
+            }
+            PhoneticEncoder::BeiderMorse(encoder) => {
+                // Beider-Morse returns all the possible pronunciations as `(a|b)-(c|d)`
+                let encoded = encoder.encode(word);
+                codes.extend(encoded.split(['|', '-', '(', ')']).map(String::from));
+            }
+        }
+        codes.retain(|code| !code.is_empty());
+        codes
+    }
+}
+
+/// Returns a copy of the words FST of the index, taken before an indexing to update the
+/// phonetic codes with the words added and removed by this indexing, see
+/// [`update_phonetic_code_words`]. Returns `None` when there are no phonetic attributes.
+pub fn phonetic_words_snapshot(
+    index: &Index,
+    rtxn: &RoTxn<'_>,
+) -> Result<Option<fst::Set<Vec<u8>>>> {
+    if index.phonetic_attributes(rtxn)?.is_empty() {
+        return Ok(None);
+    }
+    let words_fst = index.words_fst(rtxn)?;
+    Ok(Some(fst::Set::new(words_fst.as_fst().as_bytes().to_vec())?))
+}
+
+/// Rebuilds the phonetic codes of the words of the index from its words FST,
+/// only keeping them when there are phonetic attributes.
+pub fn build_phonetic_code_words(index: &Index, wtxn: &mut RwTxn<'_>) -> Result<()> {
+    index.phonetic_code_words.clear(wtxn)?;
+    if index.phonetic_attributes(wtxn)?.is_empty() {
+        return Ok(());
+    }
+
+    let encoder = PhoneticEncoder::new(index.phonetic_algorithm(wtxn)?.unwrap_or_default());
+    let mut code_words = BTreeMap::<String, BTreeSet<String>>::new();
+    let words_fst = index.words_fst(wtxn)?;
+    let mut stream = words_fst.stream();
+    while let Some(word) = stream.next() {
+        let word = std::str::from_utf8(word)?;
+        if is_stem_word(word) {
+            continue;
+        }
+        for code in encoder.encode(word) {
+            code_words.entry(code).or_default().insert(word.to_string());
+        }
+    }
+    drop(stream);
+    drop(words_fst);
+
+    for (code, words) in code_words {
+        index.phonetic_code_words.put(wtxn, &code, &words)?;
+    }
+
+    Ok(())
+}
+
+/// Updates the phonetic codes with the words added and removed from the words FST of the
+/// index since the given snapshot was taken, see [`phonetic_words_snapshot`].
+pub fn update_phonetic_code_words(
+    index: &Index,
+    wtxn: &mut RwTxn<'_>,
+    previous_words_fst: fst::Set<Vec<u8>>,
+) -> Result<()> {
+    let words_fst = index.words_fst(wtxn)?;
+    if words_fst.as_fst().as_bytes() == previous_words_fst.as_fst().as_bytes() {
+        return Ok(());
+    }
+
+    let encoder = PhoneticEncoder::new(index.phonetic_algorithm(wtxn)?.unwrap_or_default());
+    // the words removed and added for each phonetic code
+    let mut code_words = BTreeMap::<String, (BTreeSet<String>, BTreeSet<String>)>::new();
+    let mut stream = words_fst.op().add(&previous_words_fst).symmetric_difference();
+    while let Some((word, indexed_values)) = stream.next() {
+        let word = std::str::from_utf8(word)?;
+        if is_stem_word(word) {
+            continue;
+        }
+        // the first FST of the operation is the current words FST
+        let added = indexed_values.iter().any(|value| value.index == 0);
+        for code in encoder.encode(word) {
+            let (del_words, add_words) = code_words.entry(code).or_default();
+            if added {
+                add_words.insert(word.to_string());
+            } else {
+                del_words.insert(word.to_string());
+            }
+        }
+    }
+    drop(stream);
+    drop(words_fst);
+
+    for (code, (del_words, add_words)) in code_words {
+        let mut words = index.phonetic_code_words.get(wtxn, &code)?.unwrap_or_default();
+        words.retain(|word| !del_words.contains(word));
+        words.extend(add_words);
+        if words.is_empty() {
+            index.phonetic_code_words.delete(wtxn, &code)?;
+        } else {
+            index.phonetic_code_words.put(wtxn, &code, &words)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double_metaphone_codes() {
+        let encoder = PhoneticEncoder::new(PhoneticAlgorithm::DoubleMetaphone);
+        let schmidt = encoder.encode("schmidt");
+        let smith = encoder.encode("smith");
+        assert!(schmidt.intersection(&smith).next().is_some());
+        assert!(encoder.encode("jones").is_disjoint(&smith));
+    }
+}
@@ -17,6 +17,7 @@ pub enum ScoreDetails {
     Sort(Sort),
     Vector(Vector),
     GeoSort(GeoSort),
+    FunctionScore(FunctionScore),
 
     /// Returned when we don't have the time to finish applying all the subsequent ranking-rules
     Skipped,
@@ -50,6 +51,7 @@ impl ScoreDetails {
             ScoreDetails::Position(details) => Some(*details),
             ScoreDetails::ExactAttribute(details) => Some(details.rank()),
             ScoreDetails::ExactWords(details) => Some(details.rank()),
+            ScoreDetails::FunctionScore(details) => Some(details.rank),
             ScoreDetails::Sort(_) => None,
             ScoreDetails::GeoSort(_) => None,
             ScoreDetails::Vector(_) => None,
@@ -96,6 +98,7 @@ impl ScoreDetails {
             ScoreDetails::Position(p) => RankOrValue::Rank(*p),
             ScoreDetails::ExactAttribute(e) => RankOrValue::Rank(e.rank()),
             ScoreDetails::ExactWords(e) => RankOrValue::Rank(e.rank()),
+            ScoreDetails::FunctionScore(f) => RankOrValue::Rank(f.rank),
             ScoreDetails::Sort(sort) => RankOrValue::Sort(sort),
             ScoreDetails::GeoSort(geosort) => RankOrValue::GeoSort(geosort),
             ScoreDetails::Vector(vector) => {
@@ -248,6 +251,15 @@ impl ScoreDetails {
                     details_map.insert(sort, sort_details);
                     order += 1;
                 }
+                ScoreDetails::FunctionScore(details) => {
+                    let function_details = serde_json::json!({
+                        "order": order,
+                        "score": details.rank.local_score(),
+                    });
+                    details_map
+                        .insert(format!("function({})", details.expression), function_details);
+                    order += 1;
+                }
                 ScoreDetails::Vector(s) => {
                     let similarity = s.similarity.as_ref();
 
@@ -415,6 +427,15 @@ impl ExactAttribute {
     }
 }
 
+/// The bucket of the score computed by a function ranking rule.
+///
+/// The documents without a score are in the last rank.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionScore {
+    pub expression: String,
+    pub rank: Rank,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Sort {
     pub field_name: String,
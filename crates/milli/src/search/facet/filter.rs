@@ -1,23 +1,30 @@
 use std::collections::HashSet;
 use std::fmt::{Debug, Display};
 use std::ops::Bound::{self, Excluded, Included};
+use std::ops::RangeBounds;
 
 use either::Either;
-pub use filter_parser::{Condition, Error as FPError, FilterCondition, Token};
-use heed::types::LazyDecode;
+use filter_parser::ErrorKind as FPErrorKind;
+pub use filter_parser::{Condition, Error as FPError, FilterCondition, JsonFilterError, Token};
+use fst::Streamer;
+use heed::types::{Bytes, LazyDecode};
 use memchr::memmem::Finder;
+use regex::{Regex, RegexBuilder};
 use roaring::{MultiOps, RoaringBitmap};
 use serde_json::Value;
 
 use super::facet_range_search;
 use crate::error::{Error, UserError};
+use crate::facet::value_encoding::f64_into_bytes;
 use crate::heed_codec::facet::{
-    FacetGroupKey, FacetGroupKeyCodec, FacetGroupValue, FacetGroupValueCodec, OrderedF64Codec,
+    FacetElementKey, FacetElementKeyCodec, FacetElementValue, FacetGroupKey, FacetGroupKeyCodec,
+    FacetGroupValue, FacetGroupValueCodec, OrderedF64Codec,
 };
+use crate::heed_codec::StrRefCodec;
 use crate::index::db_name::FACET_ID_STRING_DOCIDS;
 use crate::{
-    distance_between_two_points, lat_lng_to_xyz, FieldId, Index, InternalError, Result,
-    SerializationError,
+    distance_between_two_points, lat_lng_to_xyz, FieldId, FieldsIdsMap, Index, InternalError,
+    Result, SerializationError,
 };
 
 /// The maximum number of filters the filter AST can process.
@@ -61,6 +68,8 @@ impl Display for BadGeoError {
 enum FilterError<'a> {
     AttributeNotFilterable { attribute: &'a str, filterable_fields: HashSet<String> },
     ParseGeoError(BadGeoError),
+    MixedRangeBounds,
+    UnsupportedInNestedFilter { filter: &'static str },
     TooDeep,
 }
 impl<'a> std::error::Error for FilterError<'a> {}
@@ -98,6 +107,12 @@ impl<'a> Display for FilterError<'a> {
                 MAX_FILTER_DEPTH
             ),
             Self::ParseGeoError(error) => write!(f, "{}", error),
+            Self::MixedRangeBounds => {
+                write!(f, "The bounds of a `TO` range must either be both numbers or both strings.")
+            }
+            Self::UnsupportedInNestedFilter { filter } => {
+                write!(f, "The {filter} cannot be used inside a nested filter.")
+            }
         }
     }
 }
@@ -108,6 +123,12 @@ impl<'a> From<FPError<'a>> for Error {
     }
 }
 
+impl From<JsonFilterError> for Error {
+    fn from(error: JsonFilterError) -> Self {
+        Self::UserError(UserError::InvalidFilter(error.to_string()))
+    }
+}
+
 impl<'a> From<Filter<'a>> for FilterCondition<'a> {
     fn from(f: Filter<'a>) -> Self {
         f.condition
@@ -122,13 +143,25 @@ impl<'a> Filter<'a> {
                 Ok(condition)
             }
             Value::Array(arr) => Self::parse_filter_array(arr),
+            Value::Object(_) => Self::from_json_object(facets).map(Some),
             v => Err(Error::UserError(UserError::InvalidFilterExpression(
-                &["String", "Array"],
+                &["String", "Array", "Object"],
                 v.clone(),
             ))),
         }
     }
 
+    /// Builds a filter from a structured JSON filter, see [`FilterCondition::from_json`].
+    pub fn from_json_object(filter: &'a Value) -> Result<Self> {
+        let condition = FilterCondition::from_json(filter)?;
+
+        if let Some(token) = condition.token_at_depth(MAX_FILTER_DEPTH) {
+            return Err(token.as_external_error(FilterError::TooDeep).into());
+        }
+
+        Ok(Self { condition })
+    }
+
     fn parse_filter_array(arr: &'a [Value]) -> Result<Option<Self>> {
         let mut ands = Vec::new();
         for value in arr {
@@ -247,20 +280,39 @@ impl<'a> Filter<'a> {
         // as the facets values are all in the same database and prefixed by the
         // field id and the level.
 
+        if let Some((left, right)) =
+            Self::string_range_bounds(rtxn, strings_db, field_id, operator)?
+        {
+            let mut output = RoaringBitmap::new();
+            Self::explore_facet_string_levels(
+                rtxn,
+                strings_db,
+                field_id,
+                left,
+                right,
+                universe,
+                &mut output,
+            )?;
+            return Ok(output);
+        }
+
         let (left, right) = match operator {
             Condition::GreaterThan(val) => {
-                (Excluded(val.parse_finite_float()?), Included(f64::MAX))
+                (Excluded(val.parse_finite_float_or_datetime()?), Included(f64::MAX))
             }
             Condition::GreaterThanOrEqual(val) => {
-                (Included(val.parse_finite_float()?), Included(f64::MAX))
+                (Included(val.parse_finite_float_or_datetime()?), Included(f64::MAX))
             }
-            Condition::LowerThan(val) => (Included(f64::MIN), Excluded(val.parse_finite_float()?)),
-            Condition::LowerThanOrEqual(val) => {
-                (Included(f64::MIN), Included(val.parse_finite_float()?))
+            Condition::LowerThan(val) => {
+                (Included(f64::MIN), Excluded(val.parse_finite_float_or_datetime()?))
             }
-            Condition::Between { from, to } => {
-                (Included(from.parse_finite_float()?), Included(to.parse_finite_float()?))
+            Condition::LowerThanOrEqual(val) => {
+                (Included(f64::MIN), Included(val.parse_finite_float_or_datetime()?))
             }
+            Condition::Between { from, to } => (
+                Included(from.parse_finite_float_or_datetime()?),
+                Included(to.parse_finite_float_or_datetime()?),
+            ),
             Condition::Null => {
                 let is_null = index.null_faceted_documents_ids(rtxn, field_id)?;
                 return Ok(is_null);
@@ -285,7 +337,7 @@ impl<'a> Filter<'a> {
                     )?
                     .map(|v| v.bitmap)
                     .unwrap_or_default();
-                let number = val.parse_finite_float().ok();
+                let number = val.parse_finite_float_or_datetime().ok();
                 let number_docids = match number {
                     Some(n) => numbers_db
                         .get(rtxn, &FacetGroupKey { field_id, level: 0, left_bound: n })?
@@ -354,6 +406,56 @@ impl<'a> Filter<'a> {
 
                 return Ok(docids);
             }
+            Condition::EndsWith { keyword: _, word } => {
+                let value = crate::normalize_facet(word.value());
+                let base = FacetGroupKey { field_id, level: 0, left_bound: "" };
+                let docids = strings_db
+                    .prefix_iter(rtxn, &base)?
+                    .remap_data_type::<LazyDecode<FacetGroupValueCodec>>()
+                    .filter_map(|result| -> Option<Result<RoaringBitmap>> {
+                        match result {
+                            Ok((FacetGroupKey { left_bound, .. }, lazy_group_value)) => {
+                                if left_bound.ends_with(value.as_str()) {
+                                    Some(lazy_group_value.decode().map(|gv| gv.bitmap).map_err(
+                                        |_| {
+                                            InternalError::from(SerializationError::Decoding {
+                                                db_name: Some(FACET_ID_STRING_DOCIDS),
+                                            })
+                                            .into()
+                                        },
+                                    ))
+                                } else {
+                                    None
+                                }
+                            }
+                            Err(_e) => {
+                                Some(Err(InternalError::from(SerializationError::Decoding {
+                                    db_name: Some(FACET_ID_STRING_DOCIDS),
+                                })
+                                .into()))
+                            }
+                        }
+                    })
+                    .union()?;
+
+                return Ok(docids);
+            }
+            Condition::Matches { keyword: _, regex } => {
+                let pattern = Self::facet_regex(regex)?;
+                return Self::matching_facet_strings_docids(rtxn, index, field_id, |normalized| {
+                    pattern.is_match(normalized)
+                });
+            }
+            Condition::CaseSensitiveEqual { keyword: _, word } => {
+                // the original strings are stored without any element of an array
+                let value = FacetElementValue::OriginalString(word.value());
+                let key = FacetElementKey { field_id, element: None, value };
+                let mut docids = index.facet_id_element_docids.get(rtxn, &key)?.unwrap_or_default();
+                if let Some(universe) = universe {
+                    docids &= universe;
+                }
+                return Ok(docids);
+            }
         };
 
         let mut output = RoaringBitmap::new();
@@ -369,6 +471,51 @@ impl<'a> Filter<'a> {
         Ok(output)
     }
 
+    /// Compiles the regex of a `MATCHES` filter. The string facet values are normalized,
+    /// the regex is thus case-insensitive like the other string operators.
+    fn facet_regex(regex: &Token<'a>) -> Result<Regex> {
+        let regex = RegexBuilder::new(regex.value())
+            .case_insensitive(true)
+            .build()
+            .map_err(|e| regex.as_external_error(e))?;
+        Ok(regex)
+    }
+
+    /// Returns the documents ids for which one of the normalized string facet values of the
+    /// field matches the predicate. The values are listed with the facet string FST of the
+    /// field rather than by scanning the levels of the facet strings database.
+    fn matching_facet_strings_docids(
+        rtxn: &heed::RoTxn<'_>,
+        index: &Index,
+        field_id: FieldId,
+        predicate: impl Fn(&str) -> bool,
+    ) -> Result<RoaringBitmap> {
+        // the fields without any string value don't have an FST
+        let Some(fst) = index.facet_id_string_fst.get(rtxn, &field_id)? else {
+            return Ok(RoaringBitmap::new());
+        };
+
+        let mut docids = RoaringBitmap::new();
+        let mut stream = fst.stream();
+        while let Some(bytes) = stream.next() {
+            let Ok(value) = std::str::from_utf8(bytes) else { continue };
+            let key = (field_id, value);
+            let Some(normalized) = index.facet_id_normalized_string_strings.get(rtxn, &key)? else {
+                continue;
+            };
+            for left_bound in normalized.iter().filter(|normalized| predicate(normalized)) {
+                let key = FacetGroupKey { field_id, level: 0, left_bound: left_bound.as_str() };
+                if let Some(FacetGroupValue { bitmap, .. }) =
+                    index.facet_id_string_docids.get(rtxn, &key)?
+                {
+                    docids |= bitmap;
+                }
+            }
+        }
+
+        Ok(docids)
+    }
+
     /// Aggregates the documents ids that are part of the specified range automatically
     /// going deeper through the levels.
     fn explore_facet_number_levels(
@@ -395,6 +542,432 @@ impl<'a> Filter<'a> {
         Ok(())
     }
 
+    /// Returns the normalized bounds of a range condition when one of its values is neither a
+    /// number nor a date, meaning that the range must be evaluated lexicographically on the
+    /// string facet values instead of the number ones.
+    fn string_range_bounds(
+        rtxn: &heed::RoTxn<'_>,
+        strings_db: heed::Database<FacetGroupKeyCodec<StrRefCodec>, FacetGroupValueCodec>,
+        field_id: FieldId,
+        operator: &Condition<'a>,
+    ) -> Result<Option<(Bound<String>, Bound<String>)>> {
+        let is_string = |token: &Token<'a>| match token.parse_finite_float_or_datetime() {
+            Ok(_) => Ok(false),
+            Err(error) if matches!(error.kind(), FPErrorKind::External(_)) => Ok(true),
+            Err(error) => Err(error),
+        };
+        let normalize = |token: &Token<'a>| crate::normalize_facet(token.value());
+
+        let bounds = match operator {
+            Condition::GreaterThan(val) if is_string(val)? => {
+                (Excluded(normalize(val)), Bound::Unbounded)
+            }
+            Condition::GreaterThanOrEqual(val) if is_string(val)? => {
+                (Included(normalize(val)), Bound::Unbounded)
+            }
+            Condition::LowerThan(val) if is_string(val)? => {
+                (Bound::Unbounded, Excluded(normalize(val)))
+            }
+            Condition::LowerThanOrEqual(val) if is_string(val)? => {
+                (Bound::Unbounded, Included(normalize(val)))
+            }
+            Condition::Between { from, to } if is_string(from)? || is_string(to)? => {
+                (Included(normalize(from)), Included(normalize(to)))
+            }
+            _ => return Ok(None),
+        };
+
+        // the fields without any string value keep reporting the invalid numbers
+        let base = FacetGroupKey { field_id, level: 0, left_bound: "" };
+        if strings_db.prefix_iter(rtxn, &base)?.next().is_none() {
+            return Ok(None);
+        }
+
+        if let Condition::Between { from, to } = operator {
+            if is_string(from)? != is_string(to)? {
+                return Err(from.as_external_error(FilterError::MixedRangeBounds).into());
+            }
+        }
+
+        Ok(Some(bounds))
+    }
+
+    /// Aggregates the documents ids whose string facet values are part of the specified
+    /// range, automatically going deeper through the levels.
+    fn explore_facet_string_levels(
+        rtxn: &heed::RoTxn<'_>,
+        db: heed::Database<FacetGroupKeyCodec<StrRefCodec>, FacetGroupValueCodec>,
+        field_id: FieldId,
+        left: Bound<String>,
+        right: Bound<String>,
+        universe: Option<&RoaringBitmap>,
+        output: &mut RoaringBitmap,
+    ) -> Result<()> {
+        match (&left, &right) {
+            // lower TO upper when lower > upper must return no result
+            (Included(l), Included(r)) if l > r => return Ok(()),
+            (Included(l), Excluded(r)) if l >= r => return Ok(()),
+            (Excluded(l), Excluded(r)) if l >= r => return Ok(()),
+            (Excluded(l), Included(r)) if l >= r => return Ok(()),
+            (_, _) => (),
+        }
+        let left = left.as_ref().map(String::as_str);
+        let right = right.as_ref().map(String::as_str);
+        facet_range_search::find_docids_of_facet_within_bounds::<StrRefCodec>(
+            rtxn, db, field_id, &left, &right, universe, output,
+        )?;
+
+        Ok(())
+    }
+
+    /// Evaluates the filter of a nested filter on a single element of the array stored in
+    /// `array_field`, the fields of the filter are the fields of the objects of the array.
+    ///
+    /// Returns the documents ids, among the given ones having this element, for which the
+    /// values of this element match the filter.
+    fn evaluate_element(
+        filter: &FilterCondition<'a>,
+        rtxn: &heed::RoTxn<'_>,
+        index: &Index,
+        field_ids_map: &FieldsIdsMap,
+        array_field: &str,
+        element: Option<u32>,
+        docids: &RoaringBitmap,
+    ) -> Result<RoaringBitmap> {
+        let field_id = |fid: &Token| field_ids_map.id(&format!("{array_field}.{}", fid.value()));
+
+        match filter {
+            FilterCondition::Not(filter) => {
+                let selected = Self::evaluate_element(
+                    filter,
+                    rtxn,
+                    index,
+                    field_ids_map,
+                    array_field,
+                    element,
+                    docids,
+                )?;
+                Ok(docids - selected)
+            }
+            FilterCondition::In { fid, els } => match field_id(fid) {
+                Some(field_id) => els
+                    .iter()
+                    .map(|el| Condition::Equal(el.clone()))
+                    .map(|op| {
+                        Self::evaluate_element_operator(rtxn, index, field_id, element, docids, &op)
+                    })
+                    .union(),
+                None => Ok(RoaringBitmap::new()),
+            },
+            FilterCondition::Condition { fid, op } => match field_id(fid) {
+                Some(field_id) => {
+                    Self::evaluate_element_operator(rtxn, index, field_id, element, docids, op)
+                }
+                None if matches!(op, Condition::NotEqual(_)) => Ok(docids.clone()),
+                None => Ok(RoaringBitmap::new()),
+            },
+            FilterCondition::Or(filters) => filters
+                .iter()
+                .map(|filter| {
+                    Self::evaluate_element(
+                        filter,
+                        rtxn,
+                        index,
+                        field_ids_map,
+                        array_field,
+                        element,
+                        docids,
+                    )
+                })
+                .union(),
+            FilterCondition::And(filters) => {
+                let mut output = docids.clone();
+                for filter in filters {
+                    if output.is_empty() {
+                        break;
+                    }
+                    output = Self::evaluate_element(
+                        filter,
+                        rtxn,
+                        index,
+                        field_ids_map,
+                        array_field,
+                        element,
+                        &output,
+                    )?;
+                }
+                Ok(output)
+            }
+            FilterCondition::Size { fid, op } => {
+                Self::evaluate_size(rtxn, index, field_id(fid), element, docids, op)
+            }
+            FilterCondition::Nested { fid, .. } => {
+                Err(fid.as_external_error(FilterError::UnsupportedInNestedFilter {
+                    filter: "nested filters",
+                }))?
+            }
+            FilterCondition::GeoLowerThan { point, .. } => {
+                Err(point[0].as_external_error(FilterError::UnsupportedInNestedFilter {
+                    filter: "`_geoRadius`",
+                }))?
+            }
+            FilterCondition::GeoBoundingBox { top_right_point, .. } => Err(top_right_point[0]
+                .as_external_error(FilterError::UnsupportedInNestedFilter {
+                    filter: "`_geoBoundingBox`",
+                }))?,
+        }
+    }
+
+    /// Returns the documents ids, among the given ones, for which the values of the field in
+    /// the given element of the array satisfy the condition.
+    fn evaluate_element_operator(
+        rtxn: &heed::RoTxn<'_>,
+        index: &Index,
+        field_id: FieldId,
+        element: Option<u32>,
+        docids: &RoaringBitmap,
+        operator: &Condition<'a>,
+    ) -> Result<RoaringBitmap> {
+        let db = index.facet_id_element_docids;
+        let get = |value| -> Result<RoaringBitmap> {
+            let key = FacetElementKey { field_id, element, value };
+            Ok(db.get(rtxn, &key)?.unwrap_or_default() & docids)
+        };
+        let prefix = |value: FacetElementValue| {
+            FacetElementKey::prefix(field_id, value.kind(element), element)
+        };
+        // the strings of the keys are valid UTF-8 and never contain this byte
+        let prefix_end = |prefix: &[u8]| [prefix, &[u8::MAX][..]].concat();
+
+        if let Some((left, right)) =
+            Self::string_range_bounds(rtxn, index.facet_id_string_docids, field_id, operator)?
+        {
+            let prefix = prefix(FacetElementValue::String(""));
+            let bound = |bound: Bound<String>, unbounded| match bound {
+                Bound::Unbounded => unbounded,
+                bound => bound.map(|s| [&prefix[..], s.as_bytes()].concat()),
+            };
+            let left = bound(left, Included(prefix.clone()));
+            let right = bound(right, Included(prefix_end(&prefix)));
+            return Self::element_range_docids(rtxn, index, left, right, docids);
+        }
+
+        let (left, right) = match operator {
+            Condition::GreaterThan(val) => {
+                (Excluded(val.parse_finite_float_or_datetime()?), Included(f64::MAX))
+            }
+            Condition::GreaterThanOrEqual(val) => {
+                (Included(val.parse_finite_float_or_datetime()?), Included(f64::MAX))
+            }
+            Condition::LowerThan(val) => {
+                (Included(f64::MIN), Excluded(val.parse_finite_float_or_datetime()?))
+            }
+            Condition::LowerThanOrEqual(val) => {
+                (Included(f64::MIN), Included(val.parse_finite_float_or_datetime()?))
+            }
+            Condition::Between { from, to } => (
+                Included(from.parse_finite_float_or_datetime()?),
+                Included(to.parse_finite_float_or_datetime()?),
+            ),
+            Condition::Null => return get(FacetElementValue::Null),
+            Condition::Empty => return get(FacetElementValue::Empty),
+            Condition::Exists => return get(FacetElementValue::Exists),
+            Condition::Equal(val) => {
+                let normalized = crate::normalize_facet(val.value());
+                let string_docids = get(FacetElementValue::String(&normalized))?;
+                let number_docids = match val.parse_finite_float_or_datetime() {
+                    Ok(number) => get(FacetElementValue::Number(number))?,
+                    Err(_) => RoaringBitmap::new(),
+                };
+                return Ok(string_docids | number_docids);
+            }
+            Condition::NotEqual(val) => {
+                let operator = Condition::Equal(val.clone());
+                let selected = Self::evaluate_element_operator(
+                    rtxn, index, field_id, element, docids, &operator,
+                )?;
+                return Ok(docids - selected);
+            }
+            Condition::Contains { keyword: _, word } => {
+                let value = crate::normalize_facet(word.value());
+                let finder = Finder::new(&value);
+                return Self::element_strings_docids(
+                    rtxn,
+                    index,
+                    &prefix(FacetElementValue::String("")),
+                    docids,
+                    |normalized| finder.find(normalized.as_bytes()).is_some(),
+                );
+            }
+            Condition::StartsWith { keyword: _, word } => {
+                let value = crate::normalize_facet(word.value());
+                let prefix = [prefix(FacetElementValue::String("")), value.into_bytes()].concat();
+                return Self::element_strings_docids(rtxn, index, &prefix, docids, |_| true);
+            }
+            Condition::EndsWith { keyword: _, word } => {
+                let value = crate::normalize_facet(word.value());
+                return Self::element_strings_docids(
+                    rtxn,
+                    index,
+                    &prefix(FacetElementValue::String("")),
+                    docids,
+                    |normalized| normalized.ends_with(value.as_str()),
+                );
+            }
+            Condition::Matches { keyword: _, regex } => {
+                let pattern = Self::facet_regex(regex)?;
+                return Self::element_strings_docids(
+                    rtxn,
+                    index,
+                    &prefix(FacetElementValue::String("")),
+                    docids,
+                    |normalized| pattern.is_match(normalized),
+                );
+            }
+            Condition::CaseSensitiveEqual { keyword: _, word } => {
+                return get(FacetElementValue::OriginalString(word.value()));
+            }
+        };
+
+        let prefix = prefix(FacetElementValue::Number(0.0));
+        let bound = |bound: Bound<f64>| {
+            // the bounds are finite numbers
+            bound.map(|n| [&prefix[..], &f64_into_bytes(n).unwrap()[..]].concat())
+        };
+        Self::element_range_docids(rtxn, index, bound(left), bound(right), docids)
+    }
+
+    /// Returns the documents ids, among the given ones, of the keys of the element
+    /// database within the bounds.
+    fn element_range_docids(
+        rtxn: &heed::RoTxn<'_>,
+        index: &Index,
+        left: Bound<Vec<u8>>,
+        right: Bound<Vec<u8>>,
+        docids: &RoaringBitmap,
+    ) -> Result<RoaringBitmap> {
+        match (&left, &right) {
+            // lower TO upper when lower > upper must return no result
+            (Included(l), Included(r)) if l > r => return Ok(RoaringBitmap::new()),
+            (Included(l), Excluded(r)) if l >= r => return Ok(RoaringBitmap::new()),
+            (Excluded(l), Excluded(r)) if l >= r => return Ok(RoaringBitmap::new()),
+            (Excluded(l), Included(r)) if l >= r => return Ok(RoaringBitmap::new()),
+            (_, _) => (),
+        }
+
+        let range = (left.as_ref().map(Vec::as_slice), right.as_ref().map(Vec::as_slice));
+        let mut output = RoaringBitmap::new();
+        for result in index.facet_id_element_docids.remap_key_type::<Bytes>().range(rtxn, &range)? {
+            let (_, bitmap) = result?;
+            output |= bitmap;
+        }
+        Ok(output & docids)
+    }
+
+    /// Returns the documents ids, among the given ones, of the string keys of the element
+    /// database starting with the prefix whose string matches the predicate.
+    fn element_strings_docids(
+        rtxn: &heed::RoTxn<'_>,
+        index: &Index,
+        prefix: &[u8],
+        docids: &RoaringBitmap,
+        predicate: impl Fn(&str) -> bool,
+    ) -> Result<RoaringBitmap> {
+        let mut output = RoaringBitmap::new();
+        for result in index
+            .facet_id_element_docids
+            .remap_key_type::<Bytes>()
+            .prefix_iter(rtxn, prefix)?
+            .remap_key_type::<FacetElementKeyCodec>()
+        {
+            let (FacetElementKey { value, .. }, bitmap) = result?;
+            match value {
+                FacetElementValue::String(string) | FacetElementValue::OriginalString(string)
+                    if predicate(string) =>
+                {
+                    output |= bitmap
+                }
+                _ => (),
+            }
+        }
+        Ok(output & docids)
+    }
+
+    /// Returns the documents ids, among the given ones, for which the number of elements of
+    /// the array stored in the field, in the given element of its parent array if any,
+    /// satisfies the condition.
+    ///
+    /// The documents missing the field are considered as having an empty array, while the
+    /// documents storing a single value in the field, e.g. a string, never match.
+    fn evaluate_size(
+        rtxn: &heed::RoTxn<'_>,
+        index: &Index,
+        field_id: Option<FieldId>,
+        element: Option<u32>,
+        docids: &RoaringBitmap,
+        operator: &Condition<'a>,
+    ) -> Result<RoaringBitmap> {
+        if let Condition::NotEqual(val) = operator {
+            let operator = Condition::Equal(val.clone());
+            let selected = Self::evaluate_size(rtxn, index, field_id, element, docids, &operator)?;
+            return Ok(docids - selected);
+        }
+
+        // the sizes can only be compared to numbers
+        let bounds = match operator {
+            Condition::GreaterThan(val) => (Excluded(val.parse_finite_float()?), Bound::Unbounded),
+            Condition::GreaterThanOrEqual(val) => {
+                (Included(val.parse_finite_float()?), Bound::Unbounded)
+            }
+            Condition::LowerThan(val) => (Bound::Unbounded, Excluded(val.parse_finite_float()?)),
+            Condition::LowerThanOrEqual(val) => {
+                (Bound::Unbounded, Included(val.parse_finite_float()?))
+            }
+            Condition::Equal(val) => {
+                let size = val.parse_finite_float()?;
+                (Included(size), Included(size))
+            }
+            Condition::Between { from, to } => {
+                (Included(from.parse_finite_float()?), Included(to.parse_finite_float()?))
+            }
+            _ => return Ok(RoaringBitmap::new()),
+        };
+
+        let Some(field_id) = field_id else {
+            return Ok(if bounds.contains(&0.0) { docids.clone() } else { RoaringBitmap::new() });
+        };
+
+        let mut output = RoaringBitmap::new();
+        if bounds.contains(&0.0) {
+            let exists = match element {
+                Some(_) => {
+                    let key =
+                        FacetElementKey { field_id, element, value: FacetElementValue::Exists };
+                    index.facet_id_element_docids.get(rtxn, &key)?.unwrap_or_default()
+                }
+                None => index.exists_faceted_documents_ids(rtxn, field_id)?,
+            };
+            output |= docids - exists;
+        }
+
+        let kind = FacetElementValue::Size(0).kind(element);
+        let prefix = FacetElementKey::prefix(field_id, kind, element);
+        for result in index
+            .facet_id_element_docids
+            .remap_key_type::<Bytes>()
+            .prefix_iter(rtxn, &prefix)?
+            .remap_key_type::<FacetElementKeyCodec>()
+        {
+            let (FacetElementKey { value, .. }, bitmap) = result?;
+            if let FacetElementValue::Size(size) = value {
+                if bounds.contains(&(size as f64)) {
+                    output |= bitmap;
+                }
+            }
+        }
+        Ok(output & docids)
+    }
+
     fn inner_evaluate(
         &self,
         rtxn: &heed::RoTxn<'_>,
@@ -491,6 +1064,60 @@ impl<'a> Filter<'a> {
                     Ok(RoaringBitmap::new())
                 }
             }
+            FilterCondition::Nested { fid, filter } => {
+                if crate::is_faceted(fid.value(), filterable_fields) {
+                    let field_ids_map = index.fields_ids_map(rtxn)?;
+                    let Some(field_id) = field_ids_map.id(fid.value()) else {
+                        return Ok(RoaringBitmap::new());
+                    };
+
+                    // the objects of the array are faceted one by one along with their index
+                    let mut output = RoaringBitmap::new();
+                    let exists = FacetElementValue::Exists.kind(None);
+                    let prefix = FacetElementKey::prefix(field_id, exists, None);
+                    for result in index
+                        .facet_id_element_docids
+                        .remap_key_type::<Bytes>()
+                        .prefix_iter(rtxn, &prefix)?
+                        .remap_key_type::<FacetElementKeyCodec>()
+                    {
+                        let (FacetElementKey { element, .. }, mut docids) = result?;
+                        if let Some(universe) = universe {
+                            docids &= universe;
+                        }
+                        if !docids.is_empty() {
+                            output |= Self::evaluate_element(
+                                filter,
+                                rtxn,
+                                index,
+                                &field_ids_map,
+                                fid.value(),
+                                element,
+                                &docids,
+                            )?;
+                        }
+                    }
+                    Ok(output)
+                } else {
+                    Err(fid.as_external_error(FilterError::AttributeNotFilterable {
+                        attribute: fid.value(),
+                        filterable_fields: filterable_fields.clone(),
+                    }))?
+                }
+            }
+            FilterCondition::Size { fid, op } => {
+                if crate::is_faceted(fid.value(), filterable_fields) {
+                    let field_ids_map = index.fields_ids_map(rtxn)?;
+                    let field_id = field_ids_map.id(fid.value());
+                    let documents_ids = index.documents_ids(rtxn)?;
+                    Self::evaluate_size(rtxn, index, field_id, None, &documents_ids, op)
+                } else {
+                    Err(fid.as_external_error(FilterError::AttributeNotFilterable {
+                        attribute: fid.value(),
+                        filterable_fields: filterable_fields.clone(),
+                    }))?
+                }
+            }
             FilterCondition::GeoLowerThan { point, radius } => {
                 if filterable_fields.contains("_geo") {
                     let base_point: [f64; 2] =
@@ -679,6 +1306,7 @@ mod tests {
     use either::Either;
     use maplit::hashset;
     use roaring::RoaringBitmap;
+    use serde_json::json;
 
     use crate::index::tests::TempIndex;
     use crate::Filter;
@@ -1208,4 +1836,253 @@ mod tests {
         let result = filter.evaluate(&rtxn, &index).unwrap();
         assert_eq!(result, RoaringBitmap::from_iter((0..100).filter(|x| x % 10 != 0)));
     }
+
+    #[test]
+    fn filter_string_range() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_primary_key("id".to_owned());
+                settings.set_filterable_fields(hashset! { S("id"), S("sku") });
+            })
+            .unwrap();
+
+        let letters = ['A', 'B', 'C', 'D'];
+        let mut docs = vec![];
+        for i in 0..100 {
+            let sku = format!("{}{:03}", letters[i / 25], i);
+            docs.push(serde_json::json!({ "id": i, "sku": sku }));
+        }
+
+        index.add_documents(documents!(docs)).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let test = |filter_str: &str, expected: std::ops::Range<u32>| {
+            let filter = Filter::from_str(filter_str).unwrap().unwrap();
+            let result = filter.evaluate(&rtxn, &index).unwrap();
+            assert_eq!(result, RoaringBitmap::from_iter(expected), "{filter_str}");
+        };
+
+        test("sku >= A010 AND sku < B", 10..25);
+        test("sku > A010", 11..100);
+        test("sku <= 'b030'", 0..31);
+        test("sku < C", 0..50);
+        test("sku B TO C060", 25..61);
+        test("sku C TO B", 0..0);
+        test("NOT sku >= D", 0..75);
+        // the numbers are still compared as numbers
+        test("sku > 10", 0..0);
+
+        let filter = Filter::from_str("sku 10 TO C").unwrap().unwrap();
+        let error = filter.evaluate(&rtxn, &index).unwrap_err();
+        assert!(error.to_string().starts_with(
+            "The bounds of a `TO` range must either be both numbers or both strings."
+        ));
+
+        // the fields without string values only accept numbers
+        let filter = Filter::from_str("id > abc").unwrap().unwrap();
+        let error = filter.evaluate(&rtxn, &index).unwrap_err();
+        assert!(error.to_string().contains("invalid float literal"), "{error}");
+    }
+
+    #[test]
+    fn filter_string_operators() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_primary_key("id".to_owned());
+                settings.set_filterable_fields(hashset! { S("code") });
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "code": "AB-100" },
+                { "id": 1, "code": "ab-100" },
+                { "id": 2, "code": "AB-200-x" },
+                { "id": 3, "code": "CD-300" },
+                { "id": 4, "code": ["EF-400", "ef-400"] },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let test = |filter_str: &str, expected: &[u32]| {
+            let filter = Filter::from_str(filter_str).unwrap().unwrap();
+            let result = filter.evaluate(&rtxn, &index).unwrap();
+            assert_eq!(result, expected.iter().copied().collect::<RoaringBitmap>(), "{filter_str}");
+        };
+
+        test("code ENDS WITH 00", &[0, 1, 3, 4]);
+        test("code NOT ENDS WITH '-X'", &[0, 1, 3, 4]);
+        test("code == AB-100", &[0]);
+        test("code == ab-100", &[1]);
+        test("code == Ab-100", &[]);
+        test("code = Ab-100", &[0, 1]);
+        test("code == EF-400", &[4]);
+        test("code == ef-400", &[4]);
+        test("code MATCHES /^AB-[0-9]+$/", &[0, 1]);
+        test("code MATCHES /^ab-/", &[0, 1, 2]);
+        test("code NOT MATCHES /^[A-Z]{2}-[0-9]+$/", &[2]);
+        test("code MATCHES /x$/ AND code STARTS WITH ab", &[2]);
+
+        let filter = Filter::from_str("code MATCHES /[a-/").unwrap().unwrap();
+        let error = filter.evaluate(&rtxn, &index).unwrap_err();
+        assert!(error.to_string().contains("unclosed character class"), "{error}");
+    }
+
+    #[test]
+    fn filter_nested_objects() {
+        let index = TempIndex::new();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "variants": [{ "color": "red", "size": "L" }, { "color": "blue", "size": "M" }] },
+                { "id": 1, "variants": [{ "color": "red", "size": "M" }] },
+                { "id": 2, "variants": [{ "color": "green", "size": 42 }, { "color": "red" }] },
+                { "id": 3, "variants": "red" },
+            ]))
+            .unwrap();
+
+        // the field becomes filterable after the documents have been indexed
+        index
+            .update_settings(|settings| {
+                settings.set_filterable_fields(hashset! { S("variants") });
+            })
+            .unwrap();
+
+        let test = |filter_str: &str, expected: &[u32]| {
+            let rtxn = index.read_txn().unwrap();
+            let filter = Filter::from_str(filter_str).unwrap().unwrap();
+            let result = filter.evaluate(&rtxn, &index).unwrap();
+            assert_eq!(result, RoaringBitmap::from_iter(expected.iter().copied()), "{filter_str}");
+        };
+
+        test("variants.color = red AND variants.size = M", &[0, 1]);
+        test("variants { color = red AND size = M }", &[1]);
+        test("variants { color = red OR size > 40 }", &[0, 1, 2]);
+        test("variants { color = red AND NOT size EXISTS }", &[2]);
+        test("variants { NOT color = red }", &[0, 2]);
+        test("NOT variants { color = red AND size = L }", &[1, 2, 3]);
+
+        index
+            .add_documents(documents!([
+                { "id": 4, "variants": [{ "color": "blue" }, { "color": "red", "size": "M" }] },
+            ]))
+            .unwrap();
+        test("variants { color = red AND size = M }", &[1, 4]);
+
+        index
+            .add_documents(documents!([{ "id": 1, "variants": [{ "color": "red", "size": "S" }] }]))
+            .unwrap();
+        test("variants { color = red AND size = M }", &[4]);
+
+        let rtxn = index.read_txn().unwrap();
+        let filter = Filter::from_str("id { color = red }").unwrap().unwrap();
+        let error = filter.evaluate(&rtxn, &index).unwrap_err();
+        assert!(error.to_string().starts_with(
+            "Attribute `id` is not filterable. Available filterable attributes are: `variants`."
+        ));
+    }
+
+    #[test]
+    fn filter_array_size() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_primary_key("id".to_owned());
+                settings.set_filterable_fields(hashset! { S("images"), S("variants") });
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "images": ["a.png", "b.png", "c.png"] },
+                { "id": 1, "images": ["a.png"], "variants": [{ "tags": ["x", "y"] }, { "tags": [] }] },
+                { "id": 2, "images": [] },
+                { "id": 3, "images": "a.png" },
+                { "id": 4, "images": ["a.png", "a.png", "b.png", "c.png"], "variants": [{ "tags": ["z"] }] },
+                { "id": 5, "variants": [{ "color": "red" }] },
+            ]))
+            .unwrap();
+
+        let test = |filter_str: &str, expected: &[u32]| {
+            let rtxn = index.read_txn().unwrap();
+            let filter = Filter::from_str(filter_str).unwrap().unwrap();
+            let result = filter.evaluate(&rtxn, &index).unwrap();
+            assert_eq!(result, RoaringBitmap::from_iter(expected.iter().copied()), "{filter_str}");
+        };
+
+        test("SIZE(images) >= 3", &[0, 4]);
+        // the documents missing the field are considered as having an empty array
+        test("SIZE(images) = 0", &[2, 5]);
+        test("SIZE(images) < 3", &[1, 2, 5]);
+        test("SIZE(images) != 1", &[0, 2, 3, 4, 5]);
+        test("SIZE(variants) = 2", &[1]);
+        test("SIZE(variants) = 0", &[0, 2, 3, 5]);
+        test("variants { SIZE(tags) = 0 }", &[1, 5]);
+        test("variants { SIZE(tags) >= 1 }", &[1, 4]);
+
+        index
+            .add_documents(documents!([{ "id": 2, "images": ["a.png", "b.png", "c.png"] }]))
+            .unwrap();
+        test("SIZE(images) >= 3", &[0, 2, 4]);
+        test("SIZE(images) = 0", &[5]);
+
+        let rtxn = index.read_txn().unwrap();
+        let filter = Filter::from_str("SIZE(images) > many").unwrap().unwrap();
+        assert!(filter.evaluate(&rtxn, &index).is_err());
+        let filter = Filter::from_str("SIZE(id) > 1").unwrap().unwrap();
+        let error = filter.evaluate(&rtxn, &index).unwrap_err();
+        assert!(error.to_string().starts_with("Attribute `id` is not filterable."), "{error}");
+    }
+
+    #[test]
+    fn filter_json_object() {
+        let index = TempIndex::new();
+
+        index
+            .update_settings(|settings| {
+                settings.set_primary_key("id".to_owned());
+                settings.set_filterable_fields(hashset! { S("title"), S("price"), S("tags") });
+            })
+            .unwrap();
+
+        index
+            .add_documents(documents!([
+                { "id": 0, "title": "l'été \"chaud\"", "price": 10, "tags": ["a", "b"] },
+                { "id": 1, "title": "hiver AND printemps", "price": 25, "tags": ["b"] },
+                { "id": 2, "title": "automne", "price": 40 },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let test = |filter: serde_json::Value, expected: &[u32]| {
+            let result = Filter::from_json(&filter).unwrap().unwrap().evaluate(&rtxn, &index);
+            assert_eq!(
+                result.unwrap(),
+                RoaringBitmap::from_iter(expected.iter().copied()),
+                "{filter}"
+            );
+        };
+
+        test(json!({ "field": "title", "op": "=", "value": "l'été \"chaud\"" }), &[0]);
+        test(json!({ "field": "title", "op": "=", "value": "hiver AND printemps" }), &[1]);
+        test(json!({ "field": "price", "op": "TO", "value": [10, 30] }), &[0, 1]);
+        test(json!({ "field": "tags", "op": "NOT IN", "value": ["a"] }), &[1, 2]);
+        test(
+            json!({ "or": [
+                { "size": "tags", "op": ">=", "value": 2 },
+                { "not": { "field": "tags", "op": "EXISTS" } },
+            ] }),
+            &[0, 2],
+        );
+
+        let error = Filter::from_json(&json!({ "field": "price", "op": "~", "value": 1 }))
+            .map(|_| ())
+            .unwrap_err();
+        assert!(error.to_string().ends_with("at `$.op`"), "{error}");
+    }
 }
@@ -1,5 +1,7 @@
 /// This mod is necessary until https://github.com/BurntSushi/fst/pull/137 gets merged.
 /// All credits for this code go to BurntSushi.
+use std::cell::Cell;
+
 use fst::Automaton;
 
 pub struct StartsWith<A>(pub A);
@@ -185,3 +187,84 @@ impl<A: Automaton> Automaton for Complement<A> {
         ComplementState(self.0.accept(&state.0, byte))
     }
 }
+
+/// An automaton that matches the keys following a pattern in which a `*`
+/// matches any sequence of bytes, including an empty one.
+///
+/// The automaton stops matching after `max_transitions` bytes have been accepted,
+/// as a pattern starting with a `*` would otherwise go through the whole FST.
+#[derive(Clone, Debug)]
+pub struct Wildcard<'a> {
+    pattern: &'a [u8],
+    remaining_transitions: Cell<usize>,
+}
+
+impl<'a> Wildcard<'a> {
+    pub fn new(pattern: &'a str, max_transitions: usize) -> Self {
+        Wildcard { pattern: pattern.as_bytes(), remaining_transitions: Cell::new(max_transitions) }
+    }
+
+    /// Inserts the position in the state along with the positions following
+    /// the stars, as they can match an empty sequence.
+    fn insert_position(&self, state: &mut Vec<usize>, mut position: usize) {
+        loop {
+            if !state.contains(&position) {
+                state.push(position);
+            }
+            if self.pattern.get(position) != Some(&b'*') {
+                break;
+            }
+            position += 1;
+        }
+    }
+}
+
+impl Automaton for Wildcard<'_> {
+    /// The positions in the pattern the key can be at.
+    type State = Vec<usize>;
+
+    fn start(&self) -> Vec<usize> {
+        let mut state = Vec::new();
+        self.insert_position(&mut state, 0);
+        state
+    }
+    fn is_match(&self, state: &Vec<usize>) -> bool {
+        state.contains(&self.pattern.len())
+    }
+    fn can_match(&self, state: &Vec<usize>) -> bool {
+        !state.is_empty() && self.remaining_transitions.get() > 0
+    }
+    fn accept(&self, state: &Vec<usize>, byte: u8) -> Vec<usize> {
+        let remaining = self.remaining_transitions.get();
+        self.remaining_transitions.set(remaining.saturating_sub(1));
+
+        let mut next = Vec::new();
+        for &position in state {
+            match self.pattern.get(position) {
+                Some(b'*') => self.insert_position(&mut next, position),
+                Some(&b) if b == byte => self.insert_position(&mut next, position + 1),
+                _ => (),
+            }
+        }
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fst::{IntoStreamer, Set};
+
+    use super::*;
+
+    #[test]
+    fn wildcard_max_transitions() {
+        let set = Set::from_iter(["iphone", "phone", "smartphone", "tablet"]).unwrap();
+        let search = |max_transitions| {
+            set.search(Wildcard::new("*phone", max_transitions)).into_stream().into_strs().unwrap()
+        };
+
+        assert_eq!(search(usize::MAX), ["iphone", "phone", "smartphone"]);
+        // the automaton stops matching once all the transitions are followed
+        assert!(search(3).is_empty());
+    }
+}
@@ -175,6 +175,8 @@ impl<'a> Search<'a> {
             time_budget: self.time_budget.clone(),
             ranking_score_threshold: self.ranking_score_threshold,
             locales: self.locales.clone(),
+            field_scoped_query: self.field_scoped_query,
+            ranking_rules: self.ranking_rules.clone(),
         };
 
         let semantic = search.semantic.take();
@@ -12,8 +12,8 @@ use self::new::{execute_vector_search, PartialSearchResult};
 use crate::score_details::{ScoreDetails, ScoringStrategy};
 use crate::vector::Embedder;
 use crate::{
-    execute_search, filtered_universe, AscDesc, DefaultSearchLogger, DocumentId, Error, Index,
-    Result, SearchContext, TimeBudget, UserError,
+    execute_search, filtered_universe, AscDesc, Criterion, DefaultSearchLogger, DocumentId, Error,
+    Index, Result, SearchContext, TimeBudget, UserError,
 };
 
 // Building these factories is not free.
@@ -55,6 +55,8 @@ pub struct Search<'a> {
     time_budget: TimeBudget,
     ranking_score_threshold: Option<f64>,
     locales: Option<Vec<Language>>,
+    field_scoped_query: bool,
+    ranking_rules: Option<Vec<Criterion>>,
 }
 
 impl<'a> Search<'a> {
@@ -78,6 +80,8 @@ impl<'a> Search<'a> {
             locales: None,
             time_budget: TimeBudget::max(),
             ranking_score_threshold: None,
+            field_scoped_query: false,
+            ranking_rules: None,
         }
     }
 
@@ -170,6 +174,19 @@ impl<'a> Search<'a> {
         self
     }
 
+    /// Binds the terms and phrases prefixed by the name of a searchable attribute,
+    /// as in `author:tolkien`, to this attribute.
+    pub fn field_scoped_query(&mut self, field_scoped_query: bool) -> &mut Search<'a> {
+        self.field_scoped_query = field_scoped_query;
+        self
+    }
+
+    /// Ranks the documents with the given ranking rules instead of the ones of the settings.
+    pub fn ranking_rules(&mut self, ranking_rules: Vec<Criterion>) -> &mut Search<'a> {
+        self.ranking_rules = Some(ranking_rules);
+        self
+    }
+
     pub fn execute_for_candidates(&self, has_vector_search: bool) -> Result<RoaringBitmap> {
         if has_vector_search {
             let ctx = SearchContext::new(self.index, self.rtxn)?;
@@ -185,6 +202,8 @@ impl<'a> Search<'a> {
         if let Some(searchable_attributes) = self.searchable_attributes {
             ctx.attributes_to_search_on(searchable_attributes)?;
         }
+        ctx.field_scoped_query = self.field_scoped_query;
+        ctx.ranking_rules = self.ranking_rules.clone();
 
         if let Some(distinct) = &self.distinct {
             let filterable_fields = ctx.index.filterable_fields(ctx.txn)?;
@@ -285,6 +304,8 @@ impl fmt::Debug for Search<'_> {
             time_budget,
             ranking_score_threshold,
             locales,
+            field_scoped_query,
+            ranking_rules,
         } = self;
         f.debug_struct("Search")
             .field("query", query)
@@ -306,6 +327,8 @@ impl fmt::Debug for Search<'_> {
             .field("time_budget", time_budget)
             .field("ranking_score_threshold", ranking_score_threshold)
             .field("locales", locales)
+            .field("field_scoped_query", field_scoped_query)
+            .field("ranking_rules", ranking_rules)
             .finish()
     }
 }
@@ -14,7 +14,7 @@ use crate::heed_codec::{BytesDecodeOwned, StrBEU16Codec};
 use crate::proximity::ProximityPrecision;
 use crate::update::MergeCboRoaringBitmaps;
 use crate::{
-    CboRoaringBitmapCodec, CboRoaringBitmapLenCodec, Result, SearchContext, U8StrStrCodec,
+    CboRoaringBitmapCodec, CboRoaringBitmapLenCodec, FieldId, Result, SearchContext, U8StrStrCodec,
 };
 
 /// A cache storing pointers to values in the LMDB databases.
@@ -34,8 +34,10 @@ pub struct DatabaseCache<'ctx> {
     pub exact_word_docids: FxHashMap<Interned<String>, Option<Cow<'ctx, [u8]>>>,
     pub word_prefix_docids: FxHashMap<Interned<String>, Option<Cow<'ctx, [u8]>>>,
     pub exact_word_prefix_docids: FxHashMap<Interned<String>, Option<Cow<'ctx, [u8]>>>,
+    pub word_phonetic_docids: FxHashMap<Interned<String>, Option<Cow<'ctx, [u8]>>>,
 
     pub words_fst: Option<fst::Set<Cow<'ctx, [u8]>>>,
+    pub phonetic_fids: Option<Vec<FieldId>>,
     pub word_position_docids: FxHashMap<(Interned<String>, u16), Option<Cow<'ctx, [u8]>>>,
     pub word_prefix_position_docids: FxHashMap<(Interned<String>, u16), Option<Cow<'ctx, [u8]>>>,
     pub word_positions: FxHashMap<Interned<String>, Vec<u16>>,
@@ -180,6 +182,17 @@ impl<'ctx> SearchContext<'ctx> {
         }
     }
 
+    /// Returns the field ids of the phonetic attributes.
+    pub fn get_phonetic_fids(&mut self) -> Result<Vec<FieldId>> {
+        if let Some(fids) = self.db_cache.phonetic_fids.clone() {
+            Ok(fids)
+        } else {
+            let fids: Vec<_> = self.index.phonetic_attributes_ids(self.txn)?.into_iter().collect();
+            self.db_cache.phonetic_fids = Some(fids.clone());
+            Ok(fids)
+        }
+    }
+
     pub fn word_docids(
         &mut self,
         universe: Option<&RoaringBitmap>,
@@ -201,9 +214,39 @@ impl<'ctx> SearchContext<'ctx> {
                 })
             }
             Word::Derived(word) => self.get_db_word_docids(universe, word),
+            Word::Phonetic(word) => self.get_db_word_phonetic_docids(universe, word),
         }
     }
 
+    /// Retrieve or insert the docids of the word within the phonetic attributes.
+    fn get_db_word_phonetic_docids(
+        &mut self,
+        universe: Option<&RoaringBitmap>,
+        word: Interned<String>,
+    ) -> Result<Option<RoaringBitmap>> {
+        let phonetic_fids = self.get_phonetic_fids()?;
+        let interned = self.word_interner.get(word).as_str();
+        let keys: Vec<_> = phonetic_fids
+            .into_iter()
+            .filter(|fid| {
+                self.restricted_fids
+                    .as_ref()
+                    .map_or(true, |fids| fids.tolerant.iter().any(|(id, _)| id == fid))
+            })
+            .map(|fid| (interned, fid))
+            .collect();
+
+        DatabaseCache::get_value_from_keys(
+            self.txn,
+            word,
+            &keys[..],
+            &mut self.db_cache.word_phonetic_docids,
+            self.index.word_fid_docids.remap_data_type::<Bytes>(),
+            universe,
+            MergeCboRoaringBitmaps,
+        )
+    }
+
     /// Retrieve or insert the given value in the `word_docids` database.
     fn get_db_word_docids(
         &mut self,
@@ -289,7 +332,9 @@ impl<'ctx> SearchContext<'ctx> {
                     }
                 })
             }
-            Word::Derived(prefix) => self.get_db_word_prefix_docids(universe, prefix),
+            Word::Derived(prefix) | Word::Phonetic(prefix) => {
+                self.get_db_word_prefix_docids(universe, prefix)
+            }
         }
     }
 
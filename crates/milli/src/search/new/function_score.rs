@@ -0,0 +1,143 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use heed::BytesDecode;
+use roaring::RoaringBitmap;
+
+use super::logger::SearchLogger;
+use super::{RankingRule, RankingRuleOutput, RankingRuleQueryTrait, SearchContext};
+use crate::function_score::Expression;
+use crate::heed_codec::facet::{FacetGroupKeyCodec, OrderedF64Codec};
+use crate::heed_codec::BytesRefCodec;
+use crate::score_details::{self, Rank, ScoreDetails};
+use crate::search::facet::ascending_facet_sort;
+use crate::{CriterionError, DocumentId, Result};
+
+/// The number of buckets the scores of the candidates are spread into, the documents
+/// of the same bucket are then ranked by the following ranking rules.
+const FUNCTION_SCORE_BUCKETS: u32 = 100;
+
+/// Ranks the documents by the decreasing score computed by an expression over
+/// their numeric facet values.
+///
+/// The scores are normalized between the smallest and the highest scores of the candidates,
+/// and bucketed so that the documents with close scores are tied.
+/// The documents for which the expression can't be computed, e.g. because they miss
+/// one of the fields, are returned last.
+pub struct FunctionScore<Query> {
+    expression_text: String,
+    expression: Expression,
+    original_query: Option<Query>,
+    buckets: VecDeque<(Rank, RoaringBitmap)>,
+}
+
+impl<Query> FunctionScore<Query> {
+    pub fn new(expression_text: String) -> Result<Self> {
+        let expression = expression_text.parse().map_err(|error| {
+            CriterionError::InvalidFunction { expression: expression_text.clone(), error }
+        })?;
+        Ok(Self { expression_text, expression, original_query: None, buckets: VecDeque::new() })
+    }
+}
+
+impl<'ctx, Query: RankingRuleQueryTrait> RankingRule<'ctx, Query> for FunctionScore<Query> {
+    fn id(&self) -> String {
+        format!("function({})", self.expression_text)
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, target = "search::function_score")]
+    fn start_iteration(
+        &mut self,
+        ctx: &mut SearchContext<'ctx>,
+        _logger: &mut dyn SearchLogger<Query>,
+        parent_candidates: &RoaringBitmap,
+        parent_query: &Query,
+    ) -> Result<()> {
+        let fields_ids_map = ctx.index.fields_ids_map(ctx.txn)?;
+        let number_db =
+            ctx.index.facet_id_f64_docids.remap_key_type::<FacetGroupKeyCodec<BytesRefCodec>>();
+
+        // retrieve the smallest value of the fields of the expression for every candidate
+        let mut fields_values: HashMap<&str, HashMap<DocumentId, f64>> = HashMap::new();
+        for field in self.expression.fields() {
+            let values = fields_values.entry(field).or_default();
+            let Some(field_id) = fields_ids_map.id(field) else { continue };
+            let iter =
+                ascending_facet_sort(ctx.txn, number_db, field_id, parent_candidates.clone())?;
+            for result in iter {
+                let (docids, bytes) = result?;
+                let value = OrderedF64Codec::bytes_decode(bytes).map_err(heed::Error::Decoding)?;
+                for docid in docids {
+                    values.entry(docid).or_insert(value);
+                }
+            }
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0.0, |d| d.as_secs_f64());
+        let mut scores = Vec::new();
+        for docid in parent_candidates {
+            let field_value =
+                |name: &str| fields_values.get(name).and_then(|values| values.get(&docid)).copied();
+            if let Some(score) = self.expression.evaluate(&field_value, now) {
+                scores.push((docid, score));
+            }
+        }
+
+        let min = scores.iter().map(|(_, score)| *score).fold(f64::INFINITY, f64::min);
+        let max = scores.iter().map(|(_, score)| *score).fold(f64::NEG_INFINITY, f64::max);
+        let mut buckets: BTreeMap<u32, RoaringBitmap> = BTreeMap::new();
+        for (docid, score) in scores {
+            let bucket = if max > min {
+                let normalized = (score - min) / (max - min);
+                (normalized * (FUNCTION_SCORE_BUCKETS - 1) as f64).round() as u32
+            } else {
+                FUNCTION_SCORE_BUCKETS - 1
+            };
+            buckets.entry(bucket).or_default().insert(docid);
+        }
+
+        // the rank 1 is kept for the documents without a score
+        self.buckets = buckets
+            .into_iter()
+            .rev()
+            .map(|(bucket, docids)| {
+                (Rank { rank: bucket + 2, max_rank: FUNCTION_SCORE_BUCKETS + 1 }, docids)
+            })
+            .collect();
+        self.original_query = Some(parent_query.clone());
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, target = "search::function_score")]
+    fn next_bucket(
+        &mut self,
+        _ctx: &mut SearchContext<'ctx>,
+        _logger: &mut dyn SearchLogger<Query>,
+        universe: &RoaringBitmap,
+    ) -> Result<Option<RankingRuleOutput<Query>>> {
+        let query = self.original_query.as_ref().unwrap().clone();
+        let (rank, candidates) = match self.buckets.pop_front() {
+            Some((rank, docids)) => (rank, docids & universe),
+            None => (Rank { rank: 1, max_rank: FUNCTION_SCORE_BUCKETS + 1 }, universe.clone()),
+        };
+
+        Ok(Some(RankingRuleOutput {
+            query,
+            candidates,
+            score: ScoreDetails::FunctionScore(score_details::FunctionScore {
+                expression: self.expression_text.clone(),
+                rank,
+            }),
+        }))
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, target = "search::function_score")]
+    fn end_iteration(
+        &mut self,
+        _ctx: &mut SearchContext<'ctx>,
+        _logger: &mut dyn SearchLogger<Query>,
+    ) {
+        self.original_query = None;
+        self.buckets.clear();
+    }
+}
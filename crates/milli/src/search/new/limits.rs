@@ -7,6 +7,12 @@ pub const MAX_PREFIX_COUNT: usize = 1_000;
 pub const MAX_ONE_TYPO_COUNT: usize = 150;
 /// Maximum number of words that can be derived from a single word with a distance of two to that word.
 pub const MAX_TWO_TYPOS_COUNT: usize = 50;
+/// Maximum number of words that can be derived from a single word by sharing its phonetic code.
+pub const MAX_PHONETIC_COUNT: usize = 50;
+/// Maximum number of words that can be derived from a single wildcard term.
+pub const MAX_WILDCARD_COUNT: usize = 500;
+/// Maximum number of transitions of the words FST followed to derive the words of a single wildcard term.
+pub const MAX_WILDCARD_TRANSITIONS: usize = 100_000;
 
 /// Maximum amount of synonym phrases that can be derived from a single word.
 pub const MAX_SYNONYM_PHRASE_COUNT: usize = 50;
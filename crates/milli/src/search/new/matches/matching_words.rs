@@ -43,6 +43,21 @@ impl MatchingWords {
             let (matching_words, matching_phrases) = term.all_computed_derivations();
 
             for matching_phrase in matching_phrases {
+                // the words of a sloppy phrase are not side-by-side in the documents,
+                // so they are highlighted independently.
+                let phrase = ctx.phrase_interner.get(matching_phrase);
+                if phrase.slop > 0 {
+                    for &word in phrase.words.iter().flatten() {
+                        words.push(LocatedMatchingWords {
+                            value: vec![word],
+                            positions: located_term.positions.clone(),
+                            is_prefix: false,
+                            original_char_count: ctx.word_interner.get(word).chars().count(),
+                        });
+                    }
+                    continue;
+                }
+
                 phrases.push(LocatedMatchingPhrase {
                     value: matching_phrase,
                     positions: located_term.positions.clone(),
@@ -262,9 +277,10 @@ pub(crate) mod tests {
         let mut ctx = SearchContext::new(&temp_index, &rtxn).unwrap();
         let mut builder = TokenizerBuilder::default();
         let tokenizer = builder.build();
-        let tokens = tokenizer.tokenize("split this world");
+        let query = "split this world";
+        let tokens = tokenizer.tokenize(query);
         let ExtractedTokens { query_terms, .. } =
-            located_query_terms_from_tokens(&mut ctx, tokens, None).unwrap();
+            located_query_terms_from_tokens(&mut ctx, tokens, query, None).unwrap();
         let matching_words = MatchingWords::new(ctx, query_terms);
 
         assert_eq!(
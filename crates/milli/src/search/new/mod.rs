@@ -15,6 +15,7 @@ mod resolve_query_graph;
 mod small_bitmap;
 
 mod exact_attribute;
+mod function_score;
 mod sort;
 mod vector_sort;
 
@@ -44,6 +45,7 @@ use roaring::RoaringBitmap;
 use sort::Sort;
 
 use self::distinct::facet_string_values;
+use self::function_score::FunctionScore;
 use self::geo_sort::GeoSort;
 pub use self::geo_sort::Strategy as GeoSortStrategy;
 use self::graph_based_ranking_rule::Words;
@@ -68,6 +70,10 @@ pub struct SearchContext<'ctx> {
     pub term_interner: Interner<QueryTerm>,
     pub phrase_docids: PhraseDocIdsCache,
     pub restricted_fids: Option<RestrictedFids>,
+    /// Whether the `attribute:term` syntax binds the query terms to the searchable attributes.
+    pub field_scoped_query: bool,
+    /// The ranking rules replacing the ones of the settings for this search.
+    pub ranking_rules: Option<Vec<crate::Criterion>>,
 }
 
 impl<'ctx> SearchContext<'ctx> {
@@ -94,9 +100,20 @@ impl<'ctx> SearchContext<'ctx> {
             term_interner: <_>::default(),
             phrase_docids: <_>::default(),
             restricted_fids: None,
+            field_scoped_query: false,
+            ranking_rules: None,
         })
     }
 
+    /// Returns the ranking rules of this search, the ones given at search time if any
+    /// or else the ones of the settings.
+    pub fn ranking_rules(&self) -> Result<Vec<crate::Criterion>> {
+        match &self.ranking_rules {
+            Some(ranking_rules) => Ok(ranking_rules.clone()),
+            None => Ok(self.index.criteria(self.txn)?),
+        }
+    }
+
     pub fn attributes_to_search_on(
         &mut self,
         attributes_to_search_on: &'ctx [String],
@@ -153,12 +170,44 @@ impl<'ctx> SearchContext<'ctx> {
 
         Ok(())
     }
+
+    /// Returns the localized attributes rules applied to the fields the search is performed on.
+    pub fn searched_localized_fields(&self) -> Result<LocalizedFieldIds> {
+        let localized_attributes_rules = self.index.localized_attributes_rules(self.txn)?;
+        let fields_ids_map = self.index.fields_ids_map(self.txn)?;
+
+        let localized_fields = match &self.restricted_fids {
+            // if AttributeToSearchOn is set, use the restricted list of ids
+            Some(restricted_fids) => {
+                let iter = restricted_fids
+                    .exact
+                    .iter()
+                    .chain(restricted_fids.tolerant.iter())
+                    .map(|(fid, _)| *fid);
+
+                LocalizedFieldIds::new(&localized_attributes_rules, &fields_ids_map, iter)
+            }
+            // Otherwise use the full list of ids coming from the index searchable fields
+            None => {
+                let searchable_fields = self.index.searchable_fields_ids(self.txn)?;
+                LocalizedFieldIds::new(
+                    &localized_attributes_rules,
+                    &fields_ids_map,
+                    searchable_fields.into_iter(),
+                )
+            }
+        };
+
+        Ok(localized_fields)
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, PartialOrd, Ord, Eq)]
 pub enum Word {
     Original(Interned<String>),
     Derived(Interned<String>),
+    /// A word sounding like the original word, only matched in the phonetic attributes
+    Phonetic(Interned<String>),
 }
 
 impl Word {
@@ -166,6 +215,7 @@ impl Word {
         match self {
             Word::Original(word) => *word,
             Word::Derived(word) => *word,
+            Word::Phonetic(word) => *word,
         }
     }
 }
@@ -270,7 +320,7 @@ fn get_ranking_rules_for_placeholder_search<'ctx>(
     let mut sorted_fields = HashSet::new();
     let mut geo_sorted = false;
     let mut ranking_rules: Vec<BoxRankingRule<'ctx, PlaceholderQuery>> = vec![];
-    let settings_ranking_rules = ctx.index.criteria(ctx.txn)?;
+    let settings_ranking_rules = ctx.ranking_rules()?;
     for rr in settings_ranking_rules {
         match rr {
             // These rules need a query to have an effect; ignore them in placeholder search
@@ -307,6 +357,9 @@ fn get_ranking_rules_for_placeholder_search<'ctx>(
                 sorted_fields.insert(field_name.clone());
                 ranking_rules.push(Box::new(Sort::new(ctx.index, ctx.txn, field_name, false)?));
             }
+            crate::Criterion::Function(expression) => {
+                ranking_rules.push(Box::new(FunctionScore::new(expression)?));
+            }
         }
     }
     Ok(ranking_rules)
@@ -332,7 +385,7 @@ fn get_ranking_rules_for_vector<'ctx>(
     let mut vector = false;
     let mut ranking_rules: Vec<BoxRankingRule<'ctx, PlaceholderQuery>> = vec![];
 
-    let settings_ranking_rules = ctx.index.criteria(ctx.txn)?;
+    let settings_ranking_rules = ctx.ranking_rules()?;
     for rr in settings_ranking_rules {
         match rr {
             crate::Criterion::Words
@@ -383,6 +436,9 @@ fn get_ranking_rules_for_vector<'ctx>(
                 sorted_fields.insert(field_name.clone());
                 ranking_rules.push(Box::new(Sort::new(ctx.index, ctx.txn, field_name, false)?));
             }
+            crate::Criterion::Function(expression) => {
+                ranking_rules.push(Box::new(FunctionScore::new(expression)?));
+            }
         }
     }
 
@@ -412,7 +468,7 @@ fn get_ranking_rules_for_query_graph_search<'ctx>(
     }
 
     let mut ranking_rules: Vec<BoxRankingRule<'ctx, QueryGraph>> = vec![];
-    let settings_ranking_rules = ctx.index.criteria(ctx.txn)?;
+    let settings_ranking_rules = ctx.ranking_rules()?;
     for rr in settings_ranking_rules {
         // Add Words before any of: typo, proximity, attribute
         match rr {
@@ -493,6 +549,9 @@ fn get_ranking_rules_for_query_graph_search<'ctx>(
                 sorted_fields.insert(field_name.clone());
                 ranking_rules.push(Box::new(Sort::new(ctx.index, ctx.txn, field_name, false)?));
             }
+            crate::Criterion::Function(expression) => {
+                ranking_rules.push(Box::new(FunctionScore::new(expression)?));
+            }
         }
     }
     Ok(ranking_rules)
@@ -686,30 +745,7 @@ pub fn execute_search(
             }
             None => {
                 // If no locales are specified, we use the locales specified in the localized attributes rules
-                let localized_attributes_rules = ctx.index.localized_attributes_rules(ctx.txn)?;
-                let fields_ids_map = ctx.index.fields_ids_map(ctx.txn)?;
-                let searchable_fields = ctx.index.searchable_fields_ids(ctx.txn)?;
-
-                let localized_fields = match &ctx.restricted_fids {
-                    // if AttributeToSearchOn is set, use the restricted list of ids
-                    Some(restricted_fids) => {
-                        let iter = restricted_fids
-                            .exact
-                            .iter()
-                            .chain(restricted_fids.tolerant.iter())
-                            .map(|(fid, _)| *fid);
-
-                        LocalizedFieldIds::new(&localized_attributes_rules, &fields_ids_map, iter)
-                    }
-                    // Otherwise use the full list of ids coming from the index searchable fields
-                    None => LocalizedFieldIds::new(
-                        &localized_attributes_rules,
-                        &fields_ids_map,
-                        searchable_fields.into_iter(),
-                    ),
-                };
-
-                db_locales = localized_fields.all_locales();
+                db_locales = ctx.searched_localized_fields()?.all_locales();
                 if !db_locales.is_empty() {
                     tokbuilder.allow_list(&db_locales);
                 }
@@ -725,7 +761,7 @@ pub fn execute_search(
         drop(entered);
 
         let ExtractedTokens { query_terms, negative_words, negative_phrases } =
-            located_query_terms_from_tokens(ctx, tokens, words_limit)?;
+            located_query_terms_from_tokens(ctx, tokens, query, words_limit)?;
         used_negative_operator = !negative_words.is_empty() || !negative_phrases.is_empty();
 
         let ignored_documents = resolve_negative_words(ctx, Some(&universe), &negative_words)?;
@@ -833,7 +869,7 @@ fn check_sort_criteria(
 
     // We check that the sort ranking rule exists and throw an
     // error if we try to use it and that it doesn't.
-    let sort_ranking_rule_missing = !ctx.index.criteria(ctx.txn)?.contains(&crate::Criterion::Sort);
+    let sort_ranking_rule_missing = !ctx.ranking_rules()?.contains(&crate::Criterion::Sort);
     if sort_ranking_rule_missing {
         return Err(UserError::SortRankingRuleMissing.into());
     }
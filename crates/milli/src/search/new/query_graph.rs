@@ -109,23 +109,27 @@ impl QueryGraph {
         let (mut prev2, mut prev1, mut prev0): (Vec<u16>, Vec<u16>, Vec<u16>) =
             (vec![], vec![], vec![root_node]);
 
-        let original_terms_len = terms.len();
-        for term_idx in 0..original_terms_len {
+        // The alternatives of an `OR` operator share the positions of the term they are an
+        // alternative to, they get the same term id and are parallel nodes of the graph.
+        let term_groups: Vec<_> = terms.chunk_by(|a, b| a.positions == b.positions).collect();
+        for (term_idx, group) in term_groups.iter().enumerate() {
             let mut new_nodes = vec![];
 
-            let new_node_idx = add_node(
-                &mut nodes_data,
-                QueryNodeData::Term(LocatedQueryTermSubset {
-                    term_subset: QueryTermSubset::full(terms[term_idx].value),
-                    positions: terms[term_idx].positions.clone(),
-                    term_ids: term_idx as u8..=term_idx as u8,
-                }),
-            );
-            new_nodes.push(new_node_idx);
+            for term in group.iter() {
+                let new_node_idx = add_node(
+                    &mut nodes_data,
+                    QueryNodeData::Term(LocatedQueryTermSubset {
+                        term_subset: QueryTermSubset::full(term.value),
+                        positions: term.positions.clone(),
+                        term_ids: term_idx as u8..=term_idx as u8,
+                    }),
+                );
+                new_nodes.push(new_node_idx);
+            }
 
             if !prev1.is_empty() {
                 if let Some(ngram) =
-                    query_term::make_ngram(ctx, &terms[term_idx - 1..=term_idx], &nbr_typos)?
+                    ngram_of_groups(ctx, &term_groups[term_idx - 1..=term_idx], &nbr_typos)?
                 {
                     new_located_query_terms.push(ngram.clone());
                     let ngram_idx = add_node(
@@ -141,7 +145,7 @@ impl QueryGraph {
             }
             if !prev2.is_empty() {
                 if let Some(ngram) =
-                    query_term::make_ngram(ctx, &terms[term_idx - 2..=term_idx], &nbr_typos)?
+                    ngram_of_groups(ctx, &term_groups[term_idx - 2..=term_idx], &nbr_typos)?
                 {
                     new_located_query_terms.push(ngram.clone());
                     let ngram_idx = add_node(
@@ -420,6 +424,22 @@ fn add_node(nodes_data: &mut Vec<QueryNodeData>, node_data: QueryNodeData) -> u1
     new_node_idx
 }
 
+/// Make an ngram of the terms of the groups, unless one of them has alternatives.
+fn ngram_of_groups(
+    ctx: &mut SearchContext<'_>,
+    groups: &[&[LocatedQueryTerm]],
+    number_of_typos_allowed: &impl Fn(&str) -> u8,
+) -> Result<Option<LocatedQueryTerm>> {
+    let mut terms = Vec::with_capacity(groups.len());
+    for group in groups {
+        match group {
+            [term] => terms.push(term.clone()),
+            _ => return Ok(None),
+        }
+    }
+    query_term::make_ngram(ctx, &terms, number_of_typos_allowed)
+}
+
 impl QueryGraph {
     /*
     Build a query graph from a list of paths
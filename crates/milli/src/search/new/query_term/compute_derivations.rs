@@ -5,13 +5,16 @@ use std::ops::ControlFlow;
 use fst::automaton::Str;
 use fst::{Automaton, IntoStreamer, Streamer};
 use heed::types::DecodeIgnore;
+use rust_stemmers::Stemmer;
 
 use super::{OneTypoTerm, Phrase, QueryTerm, ZeroTypoTerm};
-use crate::search::fst_utils::{Complement, Intersection, StartsWith, Union};
+use crate::phonetic::PhoneticEncoder;
+use crate::search::fst_utils::{Complement, Intersection, StartsWith, Union, Wildcard};
 use crate::search::new::interner::{DedupInterner, Interned};
 use crate::search::new::query_term::{Lazy, TwoTypoTerm};
 use crate::search::new::{limits, SearchContext};
 use crate::search::{build_dfa, get_first};
+use crate::stemming::{is_stem_word, stem_word};
 use crate::{Result, MAX_WORD_LENGTH};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -131,6 +134,10 @@ fn find_zero_one_two_typo_derivations(
 
     while let Some((derived_word, state)) = stream.next() {
         let derived_word = std::str::from_utf8(derived_word)?;
+        // the stems are only derived from the word they stem
+        if is_stem_word(derived_word) {
+            continue;
+        }
         let derived_word_interned = word_interner.insert(derived_word.to_owned());
         // in the case the typo is on the first letter, we know the number of typo
         // is two
@@ -190,6 +197,8 @@ pub fn partially_initialized_term_from_word(
                 zero_typo: <_>::default(),
                 one_typo: Lazy::Init(<_>::default()),
                 two_typo: Lazy::Init(<_>::default()),
+                phonetic: BTreeSet::new(),
+                field_id: None,
             }
         });
     }
@@ -248,11 +257,18 @@ pub fn partially_initialized_term_from_word(
             }
             synonym_word_count += words.len();
             let words = words.into_iter().map(|w| Some(ctx.word_interner.insert(w))).collect();
-            Some(ctx.phrase_interner.insert(Phrase { words }))
+            Some(ctx.phrase_interner.insert(Phrase { words, slop: 0 }))
         })
         .collect();
-    let zero_typo =
-        ZeroTypoTerm { phrase: None, exact: zero_typo, prefix_of, synonyms, use_prefix_db };
+    let zero_typo = ZeroTypoTerm {
+        phrase: None,
+        exact: zero_typo,
+        prefix_of,
+        wildcard_of: BTreeSet::new(),
+        synonyms,
+        use_prefix_db,
+        stem: None,
+    };
 
     Ok(QueryTerm {
         original: word_interned,
@@ -262,12 +278,113 @@ pub fn partially_initialized_term_from_word(
         zero_typo,
         one_typo: Lazy::Uninit,
         two_typo: Lazy::Uninit,
+        phonetic: BTreeSet::new(),
+        field_id: None,
     })
 }
 
+/// Returns the term of a wildcard pattern, in which a `*` matches any sequence of characters.
+///
+/// The words matching the pattern are derivations without typos of the term.
+pub fn wildcard_term(ctx: &mut SearchContext<'_>, pattern: &str) -> Result<QueryTerm> {
+    let pattern_interned = ctx.word_interner.insert(pattern.to_owned());
+
+    let fst = ctx.get_words_fst()?;
+    let mut wildcard_of = BTreeSet::new();
+    let automaton = Wildcard::new(pattern, limits::MAX_WILDCARD_TRANSITIONS);
+    let mut stream = fst.search(automaton).into_stream();
+    while let Some(derived_word) = stream.next() {
+        let derived_word = std::str::from_utf8(derived_word)?;
+        if is_stem_word(derived_word) {
+            continue;
+        }
+        if wildcard_of.len() >= limits::MAX_WILDCARD_COUNT {
+            break;
+        }
+        wildcard_of.insert(ctx.word_interner.insert(derived_word.to_owned()));
+    }
+
+    Ok(QueryTerm {
+        original: pattern_interned,
+        ngram_words: None,
+        max_levenshtein_distance: 0,
+        is_prefix: false,
+        zero_typo: ZeroTypoTerm {
+            phrase: None,
+            exact: None,
+            prefix_of: BTreeSet::new(),
+            wildcard_of,
+            synonyms: BTreeSet::new(),
+            use_prefix_db: None,
+            stem: None,
+        },
+        // a pattern has neither typos nor split words
+        one_typo: Lazy::Init(<_>::default()),
+        two_typo: Lazy::Init(<_>::default()),
+        phonetic: BTreeSet::new(),
+        field_id: None,
+    })
+}
+
+/// Returns the stem of the word if it is indexed in one of the stemmed attributes.
+pub fn stem_derivation(
+    ctx: &mut SearchContext<'_>,
+    word: &str,
+    stemmer: &Stemmer,
+) -> Result<Option<Interned<String>>> {
+    let stem = stem_word(stemmer, word);
+    if stem.len() <= MAX_WORD_LENGTH && ctx.get_words_fst()?.contains(&stem) {
+        Ok(Some(ctx.word_interner.insert(stem)))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Returns the words of the phonetic attributes sharing a phonetic code with the word.
+pub fn phonetic_derivations(
+    ctx: &mut SearchContext<'_>,
+    word: &str,
+) -> Result<BTreeSet<Interned<String>>> {
+    let mut phonetic = BTreeSet::new();
+    let phonetic_fids = ctx.get_phonetic_fids()?;
+    if phonetic_fids.is_empty() || word.len() > MAX_WORD_LENGTH {
+        return Ok(phonetic);
+    }
+
+    let encoder = PhoneticEncoder::new(ctx.index.phonetic_algorithm(ctx.txn)?.unwrap_or_default());
+    let word_fid_docids = ctx.index.word_fid_docids.remap_data_type::<DecodeIgnore>();
+    'codes: for code in encoder.encode(word) {
+        let Some(derived_words) = ctx.index.phonetic_code_words.get(ctx.txn, &code)? else {
+            continue;
+        };
+        for derived_word in derived_words {
+            if derived_word == word {
+                continue;
+            }
+            // the phonetic codes are computed for all the words of the index
+            let mut in_phonetic_attribute = false;
+            for fid in &phonetic_fids {
+                if word_fid_docids.get(ctx.txn, &(derived_word.as_str(), *fid))?.is_some() {
+                    in_phonetic_attribute = true;
+                    break;
+                }
+            }
+            if !in_phonetic_attribute {
+                continue;
+            }
+            if phonetic.len() >= limits::MAX_PHONETIC_COUNT {
+                break 'codes;
+            }
+            phonetic.insert(ctx.word_interner.insert(derived_word));
+        }
+    }
+
+    Ok(phonetic)
+}
+
 fn find_split_words(ctx: &mut SearchContext<'_>, word: &str) -> Result<Option<Interned<Phrase>>> {
     if let Some((l, r)) = split_best_frequency(ctx, word)? {
-        Ok(Some(ctx.phrase_interner.insert(Phrase { words: vec![Some(l), Some(r)] })))
+        Ok(Some(ctx.phrase_interner.insert(Phrase { words: vec![Some(l), Some(r)], slop: 0 })))
     } else {
         Ok(None)
     }
@@ -325,7 +442,7 @@ impl Interned<QueryTerm> {
         let split_words = if let Some((ngram_words, split_words)) =
             self_mut.ngram_words.as_ref().zip(split_words.as_ref())
         {
-            let Phrase { words } = ctx.phrase_interner.get(*split_words);
+            let Phrase { words, slop: _ } = ctx.phrase_interner.get(*split_words);
             if ngram_words.iter().ne(words.iter().flatten()) {
                 Some(*split_words)
             } else {
@@ -16,7 +16,7 @@ pub use phrase::Phrase;
 
 use super::interner::{DedupInterner, Interned};
 use super::{limits, SearchContext, Word};
-use crate::Result;
+use crate::{FieldId, Result};
 
 /// A set of word derivations attached to a location in the search query.
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -32,6 +32,7 @@ pub struct QueryTermSubset {
     zero_typo_subset: NTypoTermSubset,
     one_typo_subset: NTypoTermSubset,
     two_typo_subset: NTypoTermSubset,
+    phonetic_subset: NTypoTermSubset,
     /// `true` if the term cannot be deleted through the term matching strategy
     ///
     /// Note that there are other reasons for which a term cannot be deleted, such as
@@ -51,6 +52,10 @@ pub struct QueryTerm {
     one_typo: Lazy<OneTypoTerm>,
     // May not be computed yet
     two_typo: Lazy<TwoTypoTerm>,
+    /// Words of the phonetic attributes sharing a phonetic code with the original word
+    phonetic: BTreeSet<Interned<String>>,
+    /// The attribute the term is bound to with the field-scoped query syntax
+    field_id: Option<FieldId>,
 }
 
 // SubTerms will be in a dedup interner
@@ -62,10 +67,14 @@ struct ZeroTypoTerm {
     exact: Option<Interned<String>>,
     /// All the words that contain the original word as prefix
     prefix_of: BTreeSet<Interned<String>>,
+    /// All the words matching the original wildcard pattern
+    wildcard_of: BTreeSet<Interned<String>>,
     /// All the synonyms of the original word or phrase
     synonyms: BTreeSet<Interned<Phrase>>,
     /// A prefix in the prefix databases matching the original word
     use_prefix_db: Option<Interned<String>>,
+    /// The stem of the original word, indexed alongside the words of the stemmed attributes
+    stem: Option<Interned<String>>,
 }
 #[derive(Default, Clone, PartialEq, Eq, Hash)]
 struct OneTypoTerm {
@@ -148,6 +157,7 @@ impl QueryTermSubset {
             zero_typo_subset: NTypoTermSubset::Nothing,
             one_typo_subset: NTypoTermSubset::Nothing,
             two_typo_subset: NTypoTermSubset::Nothing,
+            phonetic_subset: NTypoTermSubset::Nothing,
             mandatory: false,
         }
     }
@@ -157,6 +167,7 @@ impl QueryTermSubset {
             zero_typo_subset: NTypoTermSubset::All,
             one_typo_subset: NTypoTermSubset::All,
             two_typo_subset: NTypoTermSubset::All,
+            phonetic_subset: NTypoTermSubset::All,
             mandatory: false,
         }
     }
@@ -166,12 +177,14 @@ impl QueryTermSubset {
         self.zero_typo_subset.union(&other.zero_typo_subset);
         self.one_typo_subset.union(&other.one_typo_subset);
         self.two_typo_subset.union(&other.two_typo_subset);
+        self.phonetic_subset.union(&other.phonetic_subset);
     }
     pub fn intersect(&mut self, other: &Self) {
         assert!(self.original == other.original);
         self.zero_typo_subset.intersect(&other.zero_typo_subset);
         self.one_typo_subset.intersect(&other.one_typo_subset);
         self.two_typo_subset.intersect(&other.two_typo_subset);
+        self.phonetic_subset.intersect(&other.phonetic_subset);
     }
 
     pub fn use_prefix_db(&self, ctx: &SearchContext<'_>) -> Option<Word> {
@@ -212,8 +225,10 @@ impl QueryTermSubset {
                     phrase: _,
                     exact: zero_typo,
                     prefix_of,
+                    wildcard_of,
                     synonyms: _,
                     use_prefix_db: _,
+                    stem,
                 } = &original.zero_typo;
                 result.extend(zero_typo.iter().copied().map(|w| {
                     if original.ngram_words.is_some() {
@@ -229,14 +244,18 @@ impl QueryTermSubset {
                         Word::Original(w)
                     }
                 }));
+                result.extend(wildcard_of.iter().copied().map(Word::Original));
+                result.extend(stem.iter().copied().map(Word::Derived));
             }
             NTypoTermSubset::Subset { words, phrases: _ } => {
                 let ZeroTypoTerm {
                     phrase: _,
                     exact: zero_typo,
                     prefix_of,
+                    wildcard_of,
                     synonyms: _,
                     use_prefix_db: _,
+                    stem,
                 } = &original.zero_typo;
                 if let Some(zero_typo) = zero_typo {
                     if words.contains(zero_typo) {
@@ -254,6 +273,12 @@ impl QueryTermSubset {
                         Word::Original(w)
                     }
                 }));
+                result.extend(wildcard_of.intersection(words).copied().map(Word::Original));
+                if let Some(stem) = stem {
+                    if words.contains(stem) {
+                        result.insert(Word::Derived(*stem));
+                    }
+                }
             }
             NTypoTermSubset::Nothing => {}
         }
@@ -288,6 +313,16 @@ impl QueryTermSubset {
             NTypoTermSubset::Nothing => {}
         };
 
+        match &self.phonetic_subset {
+            NTypoTermSubset::All => {
+                result.extend(original.phonetic.iter().copied().map(Word::Phonetic));
+            }
+            NTypoTermSubset::Subset { words, phrases: _ } => {
+                result.extend(original.phonetic.intersection(words).copied().map(Word::Phonetic));
+            }
+            NTypoTermSubset::Nothing => {}
+        };
+
         Ok(result)
     }
     pub fn all_phrases(&self, ctx: &mut SearchContext<'_>) -> Result<BTreeSet<Interned<Phrase>>> {
@@ -298,8 +333,15 @@ impl QueryTermSubset {
         }
         let original = ctx.term_interner.get_mut(self.original);
 
-        let ZeroTypoTerm { phrase, exact: _, prefix_of: _, synonyms, use_prefix_db: _ } =
-            &original.zero_typo;
+        let ZeroTypoTerm {
+            phrase,
+            exact: _,
+            prefix_of: _,
+            wildcard_of: _,
+            synonyms,
+            use_prefix_db: _,
+            stem: _,
+        } = &original.zero_typo;
         result.extend(phrase.iter().copied());
         result.extend(synonyms.iter().copied());
 
@@ -368,6 +410,17 @@ impl QueryTermSubset {
             _ => panic!(),
         }
     }
+    /// Returns `true` if the subset contains words sounding like the original word.
+    pub fn has_phonetic_derivations(&self, ctx: &SearchContext<'_>) -> bool {
+        let t = ctx.term_interner.get(self.original);
+        match &self.phonetic_subset {
+            NTypoTermSubset::All => !t.phonetic.is_empty(),
+            NTypoTermSubset::Subset { words, phrases: _ } => {
+                t.phonetic.intersection(words).next().is_some()
+            }
+            NTypoTermSubset::Nothing => false,
+        }
+    }
     pub fn keep_only_exact_term(&mut self, ctx: &SearchContext<'_>) {
         if let Some(term) = self.exact_term(ctx) {
             match term {
@@ -378,6 +431,7 @@ impl QueryTermSubset {
                     };
                     self.clear_one_typo_subset();
                     self.clear_two_typo_subset();
+                    self.clear_phonetic_subset();
                 }
                 ExactTerm::Word(w) => {
                     self.zero_typo_subset = NTypoTermSubset::Subset {
@@ -386,6 +440,7 @@ impl QueryTermSubset {
                     };
                     self.clear_one_typo_subset();
                     self.clear_two_typo_subset();
+                    self.clear_phonetic_subset();
                 }
             }
         }
@@ -399,6 +454,13 @@ impl QueryTermSubset {
     pub fn clear_two_typo_subset(&mut self) {
         self.two_typo_subset = NTypoTermSubset::Nothing;
     }
+    pub fn clear_phonetic_subset(&mut self) {
+        self.phonetic_subset = NTypoTermSubset::Nothing;
+    }
+    /// Returns the attribute the term is bound to, if any.
+    pub fn field_id(&self, ctx: &SearchContext<'_>) -> Option<FieldId> {
+        ctx.term_interner.get(self.original).field_id
+    }
     pub fn description(&self, ctx: &SearchContext<'_>) -> String {
         let t = ctx.term_interner.get(self.original);
         ctx.word_interner.get(t.original).to_owned()
@@ -407,12 +469,22 @@ impl QueryTermSubset {
 
 impl ZeroTypoTerm {
     fn is_empty(&self) -> bool {
-        let ZeroTypoTerm { phrase, exact: zero_typo, prefix_of, synonyms, use_prefix_db } = self;
+        let ZeroTypoTerm {
+            phrase,
+            exact: zero_typo,
+            prefix_of,
+            wildcard_of,
+            synonyms,
+            use_prefix_db,
+            stem,
+        } = self;
         phrase.is_none()
             && zero_typo.is_none()
             && prefix_of.is_empty()
+            && wildcard_of.is_empty()
             && synonyms.is_empty()
             && use_prefix_db.is_none()
+            && stem.is_none()
     }
 }
 impl OneTypoTerm {
@@ -437,7 +509,10 @@ impl QueryTerm {
             return false;
         };
 
-        self.zero_typo.is_empty() && one_typo.is_empty() && two_typo.is_empty()
+        self.zero_typo.is_empty()
+            && one_typo.is_empty()
+            && two_typo.is_empty()
+            && self.phonetic.is_empty()
     }
     fn allows_split_words(&self) -> bool {
         self.zero_typo.phrase.is_none()
@@ -489,10 +564,19 @@ impl QueryTerm {
         let mut words = BTreeSet::new();
         let mut phrases = BTreeSet::new();
 
-        let ZeroTypoTerm { phrase, exact: zero_typo, prefix_of, synonyms, use_prefix_db: _ } =
-            &self.zero_typo;
+        let ZeroTypoTerm {
+            phrase,
+            exact: zero_typo,
+            prefix_of,
+            wildcard_of,
+            synonyms,
+            use_prefix_db: _,
+            stem,
+        } = &self.zero_typo;
         words.extend(zero_typo.iter().copied());
         words.extend(prefix_of.iter().copied());
+        words.extend(wildcard_of.iter().copied());
+        words.extend(stem.iter().copied());
         phrases.extend(phrase.iter().copied());
         phrases.extend(synonyms.iter().copied());
 
@@ -505,6 +589,8 @@ impl QueryTerm {
             words.extend(two_typos.iter().copied());
         };
 
+        words.extend(self.phonetic.iter().copied());
+
         (words.into_iter().collect(), phrases.into_iter().collect())
     }
 }
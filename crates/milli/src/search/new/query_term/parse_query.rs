@@ -1,13 +1,17 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 
 use charabia::normalizer::NormalizedTokenIter;
-use charabia::{SeparatorKind, TokenKind};
+use charabia::{SeparatorKind, Token, TokenKind};
+use itertools::{peek_nth, PeekNth};
 
-use super::compute_derivations::partially_initialized_term_from_word;
+use super::compute_derivations::{
+    partially_initialized_term_from_word, phonetic_derivations, stem_derivation, wildcard_term,
+};
 use super::{LocatedQueryTerm, ZeroTypoTerm};
 use crate::search::new::query_term::{Lazy, Phrase, QueryTerm};
 use crate::search::new::Word;
-use crate::{Result, SearchContext, MAX_WORD_LENGTH};
+use crate::stemming::stemmer_for;
+use crate::{FieldId, Result, SearchContext, MAX_WORD_LENGTH};
 
 #[derive(Clone)]
 /// Extraction of the content of a query.
@@ -25,9 +29,13 @@ pub struct ExtractedTokens {
 pub fn located_query_terms_from_tokens(
     ctx: &mut SearchContext<'_>,
     query: NormalizedTokenIter<'_, '_, '_, '_>,
+    original_query: &str,
     words_limit: Option<usize>,
 ) -> Result<ExtractedTokens> {
     let nbr_typos = number_of_typos_allowed(ctx)?;
+    let stemmed_locales = ctx.searched_localized_fields()?.all_stemmed_locales();
+    let scopable_fields =
+        if ctx.field_scoped_query { scopable_fields(ctx)? } else { HashMap::new() };
 
     let mut query_terms = Vec::new();
 
@@ -37,13 +45,17 @@ pub fn located_query_terms_from_tokens(
     let mut negative_next_token = false;
     let mut negative_words = Vec::new();
     let mut negative_phrases = Vec::new();
+    // The attribute the next term or phrase is bound to by an `attribute:` prefix.
+    let mut scoped_field = None;
+    // Whether the next term is an alternative to the previous one, introduced by an `OR`.
+    let mut alternative_next = false;
 
     let parts_limit = words_limit.unwrap_or(usize::MAX);
 
     // start with the last position as we will wrap around to position 0 at the beginning of the loop below.
     let mut position = u16::MAX;
 
-    let mut peekable = query.take(super::limits::MAX_TOKEN_COUNT).peekable();
+    let mut peekable = peek_nth(query.take(super::limits::MAX_TOKEN_COUNT));
     while let Some(token) = peekable.next() {
         if token.lemma().is_empty() {
             continue;
@@ -64,44 +76,102 @@ pub fn located_query_terms_from_tokens(
                 // 3. if the word is the last token of the query we push it as a prefix word.
                 if let Some(phrase) = &mut phrase {
                     phrase.push_word(ctx, &token, position)
+                } else if is_or_operator(&token, original_query)
+                    && !negative_next_token
+                    && peekable.peek().is_some()
+                    && query_terms
+                        .last()
+                        .map_or(false, |term| *term.positions.end() == position.wrapping_sub(1))
+                {
+                    // the operator is not a term of the query
+                    alternative_next = true;
+                    position = position.wrapping_sub(1);
+                } else if let Some(fid) = field_scope(&token, &mut peekable, &scopable_fields)
+                    .filter(|_| !negative_next_token)
+                {
+                    // the attribute name is not a term of the query
+                    scoped_field = Some(fid);
+                    position = position.wrapping_sub(1);
+                } else if let Some((pattern, skipped_tokens)) =
+                    wildcard_pattern(&token, &mut peekable, original_query)
+                        .filter(|_| !negative_next_token)
+                {
+                    for _ in 0..skipped_tokens {
+                        peekable.next();
+                    }
+                    let mut term = wildcard_term(ctx, &pattern)?;
+                    term.field_id = scoped_field.take();
+                    let located_term = LocatedQueryTerm {
+                        value: ctx.term_interner.push(term),
+                        positions: position..=position,
+                    };
+                    push_query_term(
+                        &mut query_terms,
+                        located_term,
+                        std::mem::take(&mut alternative_next),
+                        &mut position,
+                    );
                 } else if negative_next_token {
                     let word = token.lemma().to_string();
                     let word = Word::Original(ctx.word_interner.insert(word));
                     negative_words.push(word);
                     negative_next_token = false;
+                    alternative_next = false;
                 } else if peekable.peek().is_some() {
                     match token.kind {
                         TokenKind::Word => {
                             let word = token.lemma();
-                            let term = partially_initialized_term_from_word(
+                            let mut term = partially_initialized_term_from_word(
                                 ctx,
                                 word,
                                 nbr_typos(word),
                                 false,
                                 false,
                             )?;
+                            if let Some(stemmer) = stemmer_for(token.language, &stemmed_locales) {
+                                term.zero_typo.stem = stem_derivation(ctx, word, &stemmer)?;
+                            }
+                            term.phonetic = phonetic_derivations(ctx, word)?;
+                            term.field_id = scoped_field.take();
                             let located_term = LocatedQueryTerm {
                                 value: ctx.term_interner.push(term),
                                 positions: position..=position,
                             };
-                            query_terms.push(located_term);
+                            push_query_term(
+                                &mut query_terms,
+                                located_term,
+                                std::mem::take(&mut alternative_next),
+                                &mut position,
+                            );
                         }
                         TokenKind::StopWord | TokenKind::Separator(_) | TokenKind::Unknown => (),
                     }
                 } else {
                     let word = token.lemma();
-                    let term = partially_initialized_term_from_word(
+                    let mut term = partially_initialized_term_from_word(
                         ctx,
                         word,
                         nbr_typos(word),
                         true,
                         false,
                     )?;
+                    if token.kind == TokenKind::Word {
+                        if let Some(stemmer) = stemmer_for(token.language, &stemmed_locales) {
+                            term.zero_typo.stem = stem_derivation(ctx, word, &stemmer)?;
+                        }
+                        term.phonetic = phonetic_derivations(ctx, word)?;
+                    }
+                    term.field_id = scoped_field.take();
                     let located_term = LocatedQueryTerm {
                         value: ctx.term_interner.push(term),
                         positions: position..=position,
                     };
-                    query_terms.push(located_term);
+                    push_query_term(
+                        &mut query_terms,
+                        located_term,
+                        std::mem::take(&mut alternative_next),
+                        &mut position,
+                    );
                 }
             }
             TokenKind::Separator(separator_kind) => {
@@ -116,6 +186,8 @@ pub fn located_query_terms_from_tokens(
                     // If we have a hard separator inside a phrase, we immediately start a new phrase
                     let phrase = if separator_kind == SeparatorKind::Hard {
                         if let Some(phrase) = phrase {
+                            let field_id = phrase.field_id;
+                            let alternative = phrase.alternative;
                             if let Some(located_query_term) = phrase.build(ctx) {
                                 // as we are evaluating a negative operator we put the phrase
                                 // in the negative one *but* we don't reset the negative operator
@@ -123,10 +195,15 @@ pub fn located_query_terms_from_tokens(
                                 if negative_phrase {
                                     negative_phrases.push(located_query_term);
                                 } else {
-                                    query_terms.push(located_query_term);
+                                    push_query_term(
+                                        &mut query_terms,
+                                        located_query_term,
+                                        alternative,
+                                        &mut position,
+                                    );
                                 }
                             }
-                            Some(PhraseBuilder::empty())
+                            Some(PhraseBuilder { field_id, ..PhraseBuilder::empty() })
                         } else {
                             None
                         }
@@ -141,9 +218,13 @@ pub fn located_query_terms_from_tokens(
                     }
 
                     // Consume the closing quote and the phrase
-                    if let Some(phrase) = phrase {
+                    if let Some(mut phrase) = phrase {
                         // Per the check above, quote_count > 0
                         quote_count -= 1;
+                        if quote_count == 0 {
+                            phrase.slop = phrase_slop(&token, &mut peekable).unwrap_or_default();
+                        }
+                        let alternative = phrase.alternative;
                         if let Some(located_query_term) = phrase.build(ctx) {
                             // we were evaluating a negative operator so we
                             // put the phrase in the negative phrases
@@ -151,7 +232,12 @@ pub fn located_query_terms_from_tokens(
                                 negative_phrases.push(located_query_term);
                                 negative_phrase = false;
                             } else {
-                                query_terms.push(located_query_term);
+                                push_query_term(
+                                    &mut query_terms,
+                                    located_query_term,
+                                    alternative,
+                                    &mut position,
+                                );
                             }
                         }
                     }
@@ -159,7 +245,12 @@ pub fn located_query_terms_from_tokens(
                     // Start new phrase if the token ends with an opening quote
                     if quote_count % 2 == 1 {
                         negative_phrase = negative_next_token;
-                        Some(PhraseBuilder::empty())
+                        Some(PhraseBuilder {
+                            field_id: scoped_field.take(),
+                            alternative: std::mem::take(&mut alternative_next)
+                                && !negative_next_token,
+                            ..PhraseBuilder::empty()
+                        })
                     } else {
                         None
                     }
@@ -177,12 +268,13 @@ pub fn located_query_terms_from_tokens(
 
     // If a quote is never closed, we consider all of the end of the query as a phrase.
     if let Some(phrase) = phrase.take() {
+        let alternative = phrase.alternative;
         if let Some(located_query_term) = phrase.build(ctx) {
             // put the phrase in the negative set if we are evaluating a negative operator.
             if negative_phrase {
                 negative_phrases.push(located_query_term);
             } else {
-                query_terms.push(located_query_term);
+                push_query_term(&mut query_terms, located_query_term, alternative, &mut position);
             }
         }
     }
@@ -190,6 +282,139 @@ pub fn located_query_terms_from_tokens(
     Ok(ExtractedTokens { query_terms, negative_words, negative_phrases })
 }
 
+/// Pushes the term in the query terms.
+///
+/// The alternatives introduced by an `OR` operator share the positions of the term
+/// they are an alternative to, which is how the query graph tells them apart.
+fn push_query_term(
+    query_terms: &mut Vec<LocatedQueryTerm>,
+    mut term: LocatedQueryTerm,
+    is_alternative: bool,
+    position: &mut u16,
+) {
+    if let Some(previous) = query_terms.last().filter(|_| is_alternative) {
+        term.positions = previous.positions.clone();
+        *position = *previous.positions.end();
+    }
+    query_terms.push(term);
+}
+
+/// Returns `true` if the token is an explicit `OR` operator, which must be written in uppercase.
+fn is_or_operator(token: &Token<'_>, original_query: &str) -> bool {
+    matches!(token.kind, TokenKind::Word | TokenKind::StopWord)
+        && original_query.get(token.byte_start..token.byte_end) == Some("OR")
+}
+
+/// Returns the slop of the phrase closed by the token, written as `"quick fox"~3`,
+/// and consumes the tokens it is made of.
+fn phrase_slop<'o>(
+    token: &Token<'o>,
+    tokens: &mut PeekNth<impl Iterator<Item = Token<'o>>>,
+) -> Option<u8> {
+    // the tilde is either part of the closing separator, a separator on its own,
+    // or a prefix of the number depending on the separators of the index.
+    let (slop, consumed): (u8, usize) = if token.lemma().ends_with("\"~") {
+        (tokens.peek()?.lemma().parse().ok()?, 1)
+    } else if token.lemma().ends_with('"') {
+        if tokens.peek()?.lemma() == "~" {
+            (tokens.peek_nth(1)?.lemma().parse().ok()?, 2)
+        } else {
+            (tokens.peek()?.lemma().strip_prefix('~')?.parse().ok()?, 1)
+        }
+    } else {
+        return None;
+    };
+    for _ in 0..consumed {
+        tokens.next();
+    }
+    Some(slop)
+}
+
+/// Returns the wildcard pattern starting at the token, as in `*phone` or `iph*ne`,
+/// along with the number of following tokens that are part of it.
+///
+/// The `*` can either be a separator or a character of the words depending on
+/// the separators of the index, hence why the original query is inspected.
+fn wildcard_pattern<'o>(
+    token: &Token<'o>,
+    tokens: &mut PeekNth<impl Iterator<Item = Token<'o>>>,
+    original_query: &str,
+) -> Option<(String, usize)> {
+    let mut pattern = String::new();
+    if original_query.get(..token.byte_start)?.ends_with('*') {
+        pattern.push('*');
+    }
+    pattern.push_str(token.lemma());
+
+    let mut end = token.byte_end;
+    let mut skipped_tokens = 0;
+    loop {
+        let rest = original_query.get(end..)?;
+        let stars = rest.len() - rest.trim_start_matches('*').len();
+        if stars == 0 {
+            break;
+        }
+        pattern.push('*');
+        end += stars;
+
+        // skip the stars and glue the word directly following them
+        let mut next = skipped_tokens;
+        while tokens.peek_nth(next).map_or(false, |t| t.byte_end <= end) {
+            next += 1;
+        }
+        match tokens.peek_nth(next) {
+            Some(t) if t.kind == TokenKind::Word && t.byte_start == end => {
+                pattern.push_str(t.lemma());
+                end = t.byte_end;
+                skipped_tokens = next + 1;
+            }
+            _ => {
+                skipped_tokens = next;
+                break;
+            }
+        }
+    }
+
+    (pattern.contains('*') && pattern.len() <= MAX_WORD_LENGTH).then_some((pattern, skipped_tokens))
+}
+
+/// Returns the searchable attributes that the terms can be bound to, by their lowercased name.
+fn scopable_fields(ctx: &SearchContext<'_>) -> Result<HashMap<String, FieldId>> {
+    let fields_ids_map = ctx.index.fields_ids_map(ctx.txn)?;
+    let fieldids_weights_map = ctx.index.fieldids_weights_map(ctx.txn)?;
+    Ok(fieldids_weights_map
+        .ids()
+        .filter_map(|fid| fields_ids_map.name(fid).map(|name| (name.to_lowercase(), fid)))
+        .collect())
+}
+
+/// Returns the attribute the following term or phrase is bound to if the token is the name
+/// of a searchable attribute directly followed by a colon, as in `author:tolkien` or
+/// `title:"the hobbit"`.
+fn field_scope<'o>(
+    token: &Token<'o>,
+    tokens: &mut PeekNth<impl Iterator<Item = Token<'o>>>,
+    scopable_fields: &HashMap<String, FieldId>,
+) -> Option<FieldId> {
+    if token.kind != TokenKind::Word {
+        return None;
+    }
+    let fid = *scopable_fields.get(token.lemma())?;
+    match tokens.peek()?.lemma() {
+        ":\"" => Some(fid),
+        ":" => match tokens.peek_nth(1)? {
+            next if next.kind == TokenKind::Word => Some(fid),
+            next if matches!(next.kind, TokenKind::Separator(_))
+                && next.lemma().starts_with('"') =>
+            {
+                Some(fid)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 pub fn number_of_typos_allowed<'ctx>(
     ctx: &SearchContext<'ctx>,
 ) -> Result<impl Fn(&str) -> u8 + 'ctx> {
@@ -220,7 +445,11 @@ pub fn make_ngram(
 ) -> Result<Option<LocatedQueryTerm>> {
     assert!(!terms.is_empty());
     for t in terms {
-        if ctx.term_interner.get(t.value).zero_typo.phrase.is_some() {
+        let term = ctx.term_interner.get(t.value);
+        if term.zero_typo.phrase.is_some()
+            || term.field_id.is_some()
+            || ctx.word_interner.get(term.original).contains('*')
+        {
             return Ok(None);
         }
     }
@@ -262,7 +491,7 @@ pub fn make_ngram(
     term.zero_typo.synonyms.extend(
         index_synonyms.get(&words).cloned().unwrap_or_default().into_iter().map(|words| {
             let words = words.into_iter().map(|w| Some(ctx.word_interner.insert(w))).collect();
-            ctx.phrase_interner.insert(Phrase { words })
+            ctx.phrase_interner.insert(Phrase { words, slop: 0 })
         }),
     );
 
@@ -274,6 +503,8 @@ pub fn make_ngram(
         zero_typo: term.zero_typo,
         one_typo: Lazy::Uninit,
         two_typo: Lazy::Uninit,
+        phonetic: BTreeSet::new(),
+        field_id: None,
     };
 
     let term = LocatedQueryTerm { value: ctx.term_interner.push(term), positions: start..=end };
@@ -285,11 +516,21 @@ struct PhraseBuilder {
     words: Vec<Option<crate::search::new::Interned<String>>>,
     start: u16,
     end: u16,
+    slop: u8,
+    field_id: Option<FieldId>,
+    alternative: bool,
 }
 
 impl PhraseBuilder {
     fn empty() -> Self {
-        Self { words: Default::default(), start: u16::MAX, end: u16::MAX }
+        Self {
+            words: Default::default(),
+            start: u16::MAX,
+            end: u16::MAX,
+            slop: 0,
+            field_id: None,
+            alternative: false,
+        }
     }
 
     fn is_empty(&self) -> bool {
@@ -322,7 +563,8 @@ impl PhraseBuilder {
         }
         Some(LocatedQueryTerm {
             value: ctx.term_interner.push({
-                let phrase = ctx.phrase_interner.insert(Phrase { words: self.words });
+                let phrase =
+                    ctx.phrase_interner.insert(Phrase { words: self.words, slop: self.slop });
                 let phrase_desc = phrase.description(ctx);
                 QueryTerm {
                     original: ctx.word_interner.insert(phrase_desc),
@@ -333,11 +575,15 @@ impl PhraseBuilder {
                         phrase: Some(phrase),
                         exact: None,
                         prefix_of: BTreeSet::default(),
+                        wildcard_of: BTreeSet::default(),
                         synonyms: BTreeSet::default(),
                         use_prefix_db: None,
+                        stem: None,
                     },
                     one_typo: Lazy::Uninit,
                     two_typo: Lazy::Uninit,
+                    phonetic: BTreeSet::new(),
+                    field_id: self.field_id,
                 }
             }),
             positions: self.start..=self.end,
@@ -368,13 +614,14 @@ mod tests {
     fn start_with_hard_separator() -> Result<()> {
         let mut builder = TokenizerBuilder::default();
         let tokenizer = builder.build();
-        let tokens = tokenizer.tokenize(".");
+        let query = ".";
+        let tokens = tokenizer.tokenize(query);
         let index = temp_index_with_documents();
         let rtxn = index.read_txn()?;
         let mut ctx = SearchContext::new(&index, &rtxn)?;
         // panics with `attempt to add with overflow` before <https://github.com/meilisearch/meilisearch/issues/3785>
         let ExtractedTokens { query_terms, .. } =
-            located_query_terms_from_tokens(&mut ctx, tokens, None)?;
+            located_query_terms_from_tokens(&mut ctx, tokens, query, None)?;
         assert!(query_terms.is_empty());
 
         Ok(())
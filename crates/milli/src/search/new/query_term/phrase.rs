@@ -8,6 +8,9 @@ use crate::SearchContext;
 #[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct Phrase {
     pub words: Vec<Option<Interned<String>>>,
+    /// The number of words allowed between two consecutive words of the phrase,
+    /// written as `"quick fox"~3` in the search query.
+    pub slop: u8,
 }
 impl Interned<Phrase> {
     pub fn description(self, ctx: &SearchContext<'_>) -> String {
@@ -91,7 +91,10 @@ impl RankingRuleGraphTrait for FidGraph {
         let max_weight: Option<u16> = weights_map.max_weight();
 
         if let Some(max_weight) = max_weight {
-            if !all_fields.contains(&max_weight) {
+            // several fields can share the max weight when the attributes are explicitly weighted
+            let has_max_weight =
+                all_fields.iter().any(|fid| weights_map.weight(*fid) == Some(max_weight));
+            if !has_max_weight {
                 edges.push((
                     max_weight as u32 * term.term_ids.len() as u32, // TODO improve the fid score i.e. fid^10.
                     conditions_interner.insert(FidCondition {
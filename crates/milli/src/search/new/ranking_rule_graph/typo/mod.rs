@@ -52,8 +52,12 @@ impl RankingRuleGraphTrait for TypoGraph {
         // 3-gram -> equivalent to 2 typos
         let base_cost = if term.term_ids.len() == 1 { 0 } else { term.term_ids.len() as u32 };
 
+        // Phonetic derivations cost more than one typo but less than two typos
+        let has_phonetic_derivations = term.term_subset.has_phonetic_derivations(ctx);
+
         for nbr_typos in 0..=term.term_subset.max_typo_cost(ctx) {
             let mut term = term.clone();
+            term.term_subset.clear_phonetic_subset();
             match nbr_typos {
                 0 => {
                     term.term_subset.clear_one_typo_subset();
@@ -70,11 +74,24 @@ impl RankingRuleGraphTrait for TypoGraph {
                 _ => panic!(),
             };
 
+            let cost = if has_phonetic_derivations && nbr_typos == 2 { 3 } else { nbr_typos };
             edges.push((
-                nbr_typos as u32 + base_cost,
+                cost as u32 + base_cost,
                 conditions_interner.insert(TypoCondition { term, nbr_typos }),
             ));
         }
+
+        if has_phonetic_derivations {
+            let mut term = term.clone();
+            term.term_subset.clear_zero_typo_subset();
+            term.term_subset.clear_one_typo_subset();
+            term.term_subset.clear_two_typo_subset();
+
+            edges.push((
+                2 + base_cost,
+                conditions_interner.insert(TypoCondition { term, nbr_typos: 2 }),
+            ));
+        }
         Ok(edges)
     }
 
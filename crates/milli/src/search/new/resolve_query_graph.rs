@@ -10,6 +10,7 @@ use super::query_graph::QueryNodeData;
 use super::query_term::{Phrase, QueryTermSubset};
 use super::small_bitmap::SmallBitmap;
 use super::{QueryGraph, SearchContext, Word};
+use crate::proximity::MAX_DISTANCE;
 use crate::search::new::query_term::LocatedQueryTermSubset;
 use crate::Result;
 
@@ -35,6 +36,15 @@ pub fn compute_query_term_subset_docids(
     universe: Option<&RoaringBitmap>,
     term: &QueryTermSubset,
 ) -> Result<RoaringBitmap> {
+    // a term bound to an attribute only matches within this attribute
+    if let Some(fid) = term.field_id(ctx) {
+        let docids = compute_query_term_subset_docids_within_field_id(ctx, universe, term, fid)?;
+        return match universe {
+            Some(universe) => Ok(docids & universe),
+            None => Ok(docids),
+        };
+    }
+
     let mut docids = RoaringBitmap::new();
     // TODO use the MultiOps trait to do large intersections
     for word in term.all_single_words_except_prefix_db(ctx)? {
@@ -66,6 +76,10 @@ pub fn compute_query_term_subset_docids_within_field_id(
 ) -> Result<RoaringBitmap> {
     let mut docids = RoaringBitmap::new();
     for word in term.all_single_words_except_prefix_db(ctx)? {
+        // the phonetic derivations only match within the phonetic attributes
+        if matches!(word, Word::Phonetic(_)) && !ctx.get_phonetic_fids()?.contains(&fid) {
+            continue;
+        }
         if let Some(word_fid_docids) = ctx.get_db_word_fid_docids(universe, word.interned(), fid)? {
             docids |= word_fid_docids;
         }
@@ -188,7 +202,7 @@ pub fn compute_phrase_docids(
     ctx: &mut SearchContext<'_>,
     phrase: Interned<Phrase>,
 ) -> Result<RoaringBitmap> {
-    let Phrase { words } = ctx.phrase_interner.get(phrase).clone();
+    let Phrase { words, slop } = ctx.phrase_interner.get(phrase).clone();
 
     if words.is_empty() {
         return Ok(RoaringBitmap::new());
@@ -218,7 +232,7 @@ pub fn compute_phrase_docids(
                 .enumerate()
                 .filter_map(|(index, word)| word.as_ref().map(|word| (index, word)))
             {
-                if dist == 0 {
+                if dist == 0 && slop == 0 {
                     match ctx.get_db_word_pair_proximity_docids(None, s1, s2, 1)? {
                         Some(m) => bitmaps.push(m),
                         // If there are no documents for this pair, there will be no
@@ -226,10 +240,16 @@ pub fn compute_phrase_docids(
                         None => return Ok(RoaringBitmap::new()),
                     }
                 } else {
+                    // each word of a sloppy phrase can be followed by `slop` other words
+                    let max_proximity = (dist as u32 + 1) * (slop as u32 + 1);
+                    // farther pairs are not stored, the words only have to appear in the document
+                    if max_proximity >= MAX_DISTANCE {
+                        continue;
+                    }
                     let mut bitmap = RoaringBitmap::new();
-                    for dist in 0..=dist {
+                    for proximity in 1..=max_proximity {
                         if let Some(m) =
-                            ctx.get_db_word_pair_proximity_docids(None, s1, s2, dist as u8 + 1)?
+                            ctx.get_db_word_pair_proximity_docids(None, s1, s2, proximity as u8)?
                         {
                             bitmap |= m;
                         }
@@ -1,4 +1,5 @@
 use crate::index::tests::TempIndex;
+use crate::score_details::ScoreDetails;
 use crate::{db_snap, Criterion, Search, SearchResult, TermsMatchingStrategy};
 
 fn create_index() -> TempIndex {
@@ -157,3 +158,78 @@ fn test_attribute_fid_ngrams() {
     let document_ids_scores: Vec<_> = documents_ids.iter().zip(document_scores).collect();
     insta::assert_snapshot!(format!("{document_ids_scores:#?}"));
 }
+
+#[test]
+fn test_attribute_fid_explicit_weights() {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec![
+                "title".to_owned(),
+                "description".to_owned(),
+                "plot".to_owned(),
+            ]);
+            s.set_searchable_attributes_weights(maplit::btreemap! {
+                "title".to_owned() => 3,
+                "description".to_owned() => 3,
+            });
+            s.set_criteria(vec![Criterion::Attribute]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "title": "", "description": "", "plot": "the fox" },
+            { "id": 1, "title": "", "description": "the fox", "plot": "" },
+            { "id": 2, "title": "the fox", "description": "", "plot": "" },
+        ]))
+        .unwrap();
+
+    // title and description share the highest weight, plot has the default weight of 1
+    db_snap!(index, fieldids_weights_map, @r###"
+    fid weight
+    1   0   |
+    2   0   |
+    3   2   |
+    "###);
+
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("fox");
+    s.scoring_strategy(crate::score_details::ScoringStrategy::Detailed);
+    let SearchResult { documents_ids, document_scores, .. } = s.execute().unwrap();
+
+    let fid_rank = |docid: u32| {
+        let position = documents_ids.iter().position(|id| *id == docid).unwrap();
+        document_scores[position]
+            .iter()
+            .find_map(|score| match score {
+                ScoreDetails::Fid(rank) => Some(rank.rank),
+                _ => None,
+            })
+            .unwrap()
+    };
+
+    // the matches in the title and in the description are ranked the same
+    assert_eq!(documents_ids[2], 0);
+    assert_eq!(fid_rank(1), fid_rank(2));
+    assert!(fid_rank(0) < fid_rank(1));
+
+    // the weights can be reset to the order of the searchable attributes
+    index
+        .update_settings(|s| {
+            s.reset_searchable_attributes_weights();
+        })
+        .unwrap();
+
+    db_snap!(index, fieldids_weights_map, @r###"
+    fid weight
+    1   0   |
+    2   1   |
+    3   2   |
+    "###);
+}
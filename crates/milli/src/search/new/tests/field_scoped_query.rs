@@ -0,0 +1,72 @@
+/*!
+This module tests the following properties about the field-scoped query syntax:
+1. the terms and phrases prefixed by a searchable attribute only match within this attribute
+2. the prefixes naming attributes that are not searchable are searched as plain words
+3. the syntax is ignored when it is not enabled
+*/
+
+use crate::index::tests::TempIndex;
+use crate::{Search, SearchResult};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned(), "author".to_owned()]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "title": "the lord of the rings", "author": "tolkien", "publisher": "allen" },
+            { "id": 1, "title": "tolkien a biography", "author": "carpenter", "publisher": "allen" },
+            { "id": 2, "title": "the publisher of tolkien", "author": "unknown" },
+        ]))
+        .unwrap();
+
+    index
+}
+
+#[test]
+fn test_field_scoped_query() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.field_scoped_query(true);
+    s.query("author:tolkien");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0]");
+
+    let mut s = Search::new(&txn, &index);
+    s.field_scoped_query(true);
+    s.query("title:\"lord of the rings\"");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0]");
+
+    let mut s = Search::new(&txn, &index);
+    s.field_scoped_query(true);
+    s.query("author:\"lord of the rings\"");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[]");
+
+    // `publisher` is not searchable
+    let mut s = Search::new(&txn, &index);
+    s.field_scoped_query(true);
+    s.query("publisher:tolkien");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[2]");
+}
+
+#[test]
+fn test_field_scoped_query_disabled() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("author:tolkien");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[]");
+}
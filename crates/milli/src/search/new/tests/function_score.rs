@@ -0,0 +1,82 @@
+/*!
+This module tests the `function(...)` ranking rule:
+
+1. the documents are ranked by the decreasing score of the expression over their numeric values
+2. the documents with close scores are tied and ranked by the following ranking rules
+3. the documents for which the expression can't be computed are returned last
+4. the score of the rule is part of the ranking score of the documents
+*/
+
+use crate::index::tests::TempIndex;
+use crate::score_details::ScoreDetails;
+use crate::{Criterion, Search, SearchResult};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_criteria(vec![
+                Criterion::Words,
+                Criterion::Function("log1p(sales) * rating".to_owned()),
+            ]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "the book", "sales": 0, "rating": 5 },
+            { "id": 1, "text": "the book", "sales": 99, "rating": 2 },
+            { "id": 2, "text": "the book", "sales": 9, "rating": 4 },
+            { "id": 3, "text": "the book", "sales": 999, "rating": 3 },
+            { "id": 4, "text": "the book", "rating": 5 },
+            { "id": 5, "text": "the other book", "sales": 999, "rating": 5 },
+        ]))
+        .unwrap();
+
+    index
+}
+
+fn function_ranks(document_scores: &[Vec<ScoreDetails>]) -> Vec<u32> {
+    document_scores
+        .iter()
+        .map(|scores| {
+            scores
+                .iter()
+                .find_map(|score| match score {
+                    ScoreDetails::FunctionScore(function) => Some(function.rank.rank),
+                    _ => None,
+                })
+                .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_function_score_placeholder() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.scoring_strategy(crate::score_details::ScoringStrategy::Detailed);
+    let SearchResult { documents_ids, document_scores, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[5, 3, 1, 2, 0, 4]");
+    insta::assert_snapshot!(format!("{:?}", function_ranks(&document_scores)), @"[101, 61, 28, 28, 2, 1]");
+}
+
+#[test]
+fn test_function_score_after_words() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("the other book");
+    s.terms_matching_strategy(crate::TermsMatchingStrategy::Last);
+    s.scoring_strategy(crate::score_details::ScoringStrategy::Detailed);
+    let SearchResult { documents_ids, document_scores, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[5, 3, 1, 2, 0, 4]");
+    // the scores are normalized over the documents of the bucket of the words rule
+    insta::assert_snapshot!(format!("{:?}", function_ranks(&document_scores)), @"[101, 101, 46, 46, 2, 1]");
+}
@@ -3,15 +3,21 @@ pub mod attribute_position;
 pub mod cutoff;
 pub mod distinct;
 pub mod exactness;
+pub mod field_scoped_query;
+pub mod function_score;
 pub mod geo_sort;
 pub mod integration;
 #[cfg(feature = "all-tokenizations")]
 #[cfg(not(feature = "chinese-pinyin"))]
 pub mod language;
 pub mod ngram_split_words;
+pub mod phonetic;
 pub mod proximity;
 pub mod proximity_typo;
+pub mod query_operators;
+pub mod ranking_rules_override;
 pub mod sort;
+pub mod stemming;
 pub mod stop_words;
 pub mod typo;
 pub mod typo_proximity;
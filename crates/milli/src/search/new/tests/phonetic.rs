@@ -0,0 +1,89 @@
+/*!
+This module tests the following properties about phonetic matching:
+1. the words of the phonetic attributes sharing a phonetic code with a query word match it
+2. the phonetic matches are ranked by the typo rule after the one-typo matches
+3. the attributes without phonetic matching only match the words and their typos
+4. the phonetic codes are indexed both when updating the settings and when indexing documents
+5. the phonetic codes are updated when the words are removed from the index
+*/
+
+use std::collections::HashSet;
+
+use crate::index::tests::TempIndex;
+use crate::{Search, SearchResult};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["name".to_owned(), "description".to_owned()]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "name": "smith" },
+            { "id": 1, "name": "schmidt" },
+            { "id": 2, "description": "schmidt" },
+        ]))
+        .unwrap();
+
+    // the words already indexed are associated with their phonetic codes
+    index
+        .update_settings(|s| {
+            s.set_phonetic_attributes(HashSet::from(["name".to_owned()]));
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 3, "name": "smitt" },
+            { "id": 4, "name": "jones" },
+        ]))
+        .unwrap();
+
+    index
+}
+
+#[test]
+fn test_phonetic() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("smith");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 3, 1]");
+
+    let mut s = Search::new(&txn, &index);
+    s.query("schmidt");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[1, 2, 0, 3]");
+}
+
+#[test]
+fn test_phonetic_reset() {
+    let index = create_index();
+    index.update_settings(|s| s.reset_phonetic_attributes()).unwrap();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("smith");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 3]");
+}
+
+#[test]
+fn test_phonetic_deletion() {
+    let index = create_index();
+    index.delete_documents(vec!["3".to_owned()]);
+    let txn = index.read_txn().unwrap();
+
+    // the phonetic codes only keep the words of the index
+    let words: HashSet<_> =
+        index.phonetic_code_words.iter(&txn).unwrap().flat_map(|r| r.unwrap().1).collect();
+    assert!(words.contains("smith"));
+    assert!(!words.contains("smitt"));
+}
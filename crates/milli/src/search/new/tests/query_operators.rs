@@ -0,0 +1,80 @@
+/*!
+This module tests the following properties about the operators of the query language:
+1. the words of a sloppy phrase `"quick fox"~N` must follow each other in the same order
+   with at most `N` other words in between
+2. the terms around an uppercase `OR` are alternatives matching the same place of the query
+3. a lowercase `or` is a plain word of the query
+4. a `*` in a term matches any sequence of characters of the words
+*/
+
+use crate::index::tests::TempIndex;
+use crate::{Search, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "the quick brown fox" },
+            { "id": 1, "text": "the quick fox" },
+            { "id": 2, "text": "the quick and very brown fox" },
+            { "id": 3, "text": "the fox is quick" },
+            { "id": 4, "text": "black laptop" },
+            { "id": 5, "text": "black notebook" },
+            { "id": 6, "text": "black tablet" },
+            { "id": 7, "text": "white laptop" },
+            { "id": 8, "text": "iphone case" },
+            { "id": 9, "text": "smartphone" },
+            { "id": 10, "text": "iphane" },
+        ]))
+        .unwrap();
+
+    index
+}
+
+fn search(index: &TempIndex, query: &str) -> Vec<u32> {
+    let txn = index.read_txn().unwrap();
+    let mut s = Search::new(&txn, index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query(query);
+    let SearchResult { mut documents_ids, .. } = s.execute().unwrap();
+    documents_ids.sort_unstable();
+    documents_ids
+}
+
+#[test]
+fn test_phrase_slop() {
+    let index = create_index();
+
+    insta::assert_snapshot!(format!("{:?}", search(&index, "\"quick fox\"")), @"[1]");
+    insta::assert_snapshot!(format!("{:?}", search(&index, "\"quick fox\"~1")), @"[0, 1]");
+    insta::assert_snapshot!(format!("{:?}", search(&index, "\"fox quick\"~1")), @"[3]");
+}
+
+#[test]
+fn test_or_operator() {
+    let index = create_index();
+
+    insta::assert_snapshot!(format!("{:?}", search(&index, "(laptop OR notebook) black")), @"[4, 5]");
+    insta::assert_snapshot!(format!("{:?}", search(&index, "laptop OR notebook OR tablet")), @"[4, 5, 6, 7]");
+    insta::assert_snapshot!(format!("{:?}", search(&index, "\"quick fox\" OR laptop")), @"[1, 4, 7]");
+    insta::assert_snapshot!(format!("{:?}", search(&index, "laptop or notebook")), @"[]");
+}
+
+#[test]
+fn test_wildcard_terms() {
+    let index = create_index();
+
+    insta::assert_snapshot!(format!("{:?}", search(&index, "*phone")), @"[8, 9]");
+    insta::assert_snapshot!(format!("{:?}", search(&index, "iph*ne")), @"[8, 10]");
+    insta::assert_snapshot!(format!("{:?}", search(&index, "lap*")), @"[4, 7]");
+    insta::assert_snapshot!(format!("{:?}", search(&index, "*phone case")), @"[8]");
+    insta::assert_snapshot!(format!("{:?}", search(&index, "*phone OR notebook")), @"[5, 8, 9]");
+}
@@ -0,0 +1,71 @@
+/*!
+This module tests the ranking rules given at search time:
+1. they replace the ranking rules of the settings for one search
+2. the `sort` ranking rule can be used even when it isn't part of the settings
+3. the ranking rules of the settings are used when none are given
+*/
+
+use big_s::S;
+use maplit::hashset;
+
+use crate::index::tests::TempIndex;
+use crate::{AscDesc, Criterion, Error, Member, Search, SearchResult, UserError};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_sortable_fields(hashset! { S("price") });
+            s.set_criteria(vec![Criterion::Words, Criterion::Desc(S("rank"))]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "text": "the book", "rank": 3, "price": 20 },
+            { "id": 1, "text": "the red book", "rank": 1, "price": 30 },
+            { "id": 2, "text": "book", "rank": 2, "price": 10 },
+        ]))
+        .unwrap();
+
+    index
+}
+
+#[test]
+fn test_ranking_rules_override() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("book red");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[1, 0, 2]");
+
+    let mut s = Search::new(&txn, &index);
+    s.query("book red");
+    s.ranking_rules(vec![Criterion::Desc(S("rank")), Criterion::Words]);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 2, 1]");
+}
+
+#[test]
+fn test_ranking_rules_override_sort() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("book red");
+    s.sort_criteria(vec![AscDesc::Asc(Member::Field(S("price")))]);
+    let error = s.execute().unwrap_err();
+    assert!(matches!(error, Error::UserError(UserError::SortRankingRuleMissing)));
+
+    let mut s = Search::new(&txn, &index);
+    s.query("book red");
+    s.sort_criteria(vec![AscDesc::Asc(Member::Field(S("price")))]);
+    s.ranking_rules(vec![Criterion::Sort, Criterion::Words]);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[2, 0, 1]");
+}
@@ -0,0 +1,72 @@
+/*!
+This module tests the following properties about stemming:
+1. the words of the attributes with stemming enabled match the query words sharing their stem
+2. the stemmed matches are ranked after the exact matches by the exactness rule
+3. the attributes without stemming only match the exact words
+4. the stems are indexed both when indexing documents and when reindexing them after a settings update
+*/
+
+use charabia::Language;
+
+use crate::index::tests::TempIndex;
+use crate::{LocalizedAttributesRule, Search, SearchResult};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned(), "description".to_owned()]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "title": "dog runs fast" },
+            { "id": 1, "title": "dog running fast" },
+        ]))
+        .unwrap();
+
+    // the documents already indexed are reindexed with their stems
+    index
+        .update_settings(|s| {
+            s.set_localized_attributes_rules(vec![
+                LocalizedAttributesRule::new(vec!["title".to_owned()], vec![Language::Eng])
+                    .with_stemming(true),
+                LocalizedAttributesRule::new(vec!["description".to_owned()], vec![Language::Eng]),
+            ]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 2, "title": "dog run fast" },
+            { "id": 3, "title": "dog ran fast" },
+            { "id": 4, "description": "dog runs fast" },
+        ]))
+        .unwrap();
+
+    index
+}
+
+#[test]
+fn test_stemming() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.query("running");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[1, 0, 2]");
+
+    let mut s = Search::new(&txn, &index);
+    s.query("runs");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 1, 2, 4]");
+
+    let mut s = Search::new(&txn, &index);
+    s.query("\"dog running\"");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[1]");
+}
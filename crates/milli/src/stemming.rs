@@ -0,0 +1,77 @@
+use charabia::Language;
+use rust_stemmers::{Algorithm, Stemmer};
+
+/// The character prepended to the stems to store them in the word databases
+/// alongside the words without ever being mistaken for one of them.
+const STEM_PREFIX: char = '\u{1}';
+
+/// Returns the Snowball stemmer of the given language, if there is one.
+pub fn stemmer(language: Language) -> Option<Stemmer> {
+    let algorithm = match language {
+        Language::Ara => Algorithm::Arabic,
+        Language::Dan => Algorithm::Danish,
+        Language::Deu => Algorithm::German,
+        Language::Ell => Algorithm::Greek,
+        Language::Eng => Algorithm::English,
+        Language::Fin => Algorithm::Finnish,
+        Language::Fra => Algorithm::French,
+        Language::Hun => Algorithm::Hungarian,
+        Language::Ita => Algorithm::Italian,
+        Language::Nld => Algorithm::Dutch,
+        Language::Nob => Algorithm::Norwegian,
+        Language::Por => Algorithm::Portuguese,
+        Language::Ron => Algorithm::Romanian,
+        Language::Rus => Algorithm::Russian,
+        Language::Spa => Algorithm::Spanish,
+        Language::Swe => Algorithm::Swedish,
+        Language::Tam => Algorithm::Tamil,
+        Language::Tur => Algorithm::Turkish,
+        _ => return None,
+    };
+
+    Some(Stemmer::create(algorithm))
+}
+
+/// Selects the stemmer to use for a word among the stemmed `locales`.
+///
+/// The detected language of the word is preferred, but when the detection fails
+/// or disagrees with the locales, a single stemmed locale is used as is.
+pub fn stemmer_for(language: Option<Language>, locales: &[Language]) -> Option<Stemmer> {
+    match language {
+        Some(language) if locales.contains(&language) => stemmer(language),
+        _ => match locales {
+            [language] => stemmer(*language),
+            _ => None,
+        },
+    }
+}
+
+/// Returns the word under which the stem of `word` is stored in the word databases.
+pub fn stem_word(stemmer: &Stemmer, word: &str) -> String {
+    let mut stem_word = String::from(STEM_PREFIX);
+    stem_word.push_str(&stemmer.stem(word));
+    stem_word
+}
+
+/// Returns `true` if the word of the word databases is a stem stored by [`stem_word`].
+pub fn is_stem_word(word: &str) -> bool {
+    word.starts_with(STEM_PREFIX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stem_words() {
+        let english = stemmer(Language::Eng).unwrap();
+        assert_eq!(stem_word(&english, "running"), stem_word(&english, "runs"));
+        assert_ne!(stem_word(&english, "running"), "run");
+        assert!(is_stem_word(&stem_word(&english, "running")));
+        assert!(!is_stem_word("running"));
+
+        assert!(stemmer(Language::Jpn).is_none());
+        assert!(stemmer_for(Some(Language::Fra), &[Language::Eng]).is_some());
+        assert!(stemmer_for(None, &[Language::Eng, Language::Fra]).is_none());
+    }
+}
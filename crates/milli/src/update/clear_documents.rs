@@ -36,10 +36,12 @@ impl<'t, 'i> ClearDocuments<'t, 'i> {
             field_id_word_count_docids,
             word_prefix_position_docids,
             word_prefix_fid_docids,
+            phonetic_code_words,
             facet_id_f64_docids,
             facet_id_string_docids,
             facet_id_normalized_string_strings,
             facet_id_string_fst,
+            facet_id_element_docids,
             facet_id_exists_docids,
             facet_id_is_null_docids,
             facet_id_is_empty_docids,
@@ -48,6 +50,7 @@ impl<'t, 'i> ClearDocuments<'t, 'i> {
             vector_arroy,
             embedder_category_id: _,
             documents,
+            document_changes: _,
         } = self.index;
 
         let empty_roaring = RoaringBitmap::default();
@@ -82,9 +85,11 @@ impl<'t, 'i> ClearDocuments<'t, 'i> {
         field_id_word_count_docids.clear(self.wtxn)?;
         word_prefix_position_docids.clear(self.wtxn)?;
         word_prefix_fid_docids.clear(self.wtxn)?;
+        phonetic_code_words.clear(self.wtxn)?;
         facet_id_f64_docids.clear(self.wtxn)?;
         facet_id_normalized_string_strings.clear(self.wtxn)?;
         facet_id_string_fst.clear(self.wtxn)?;
+        facet_id_element_docids.clear(self.wtxn)?;
         facet_id_exists_docids.clear(self.wtxn)?;
         facet_id_is_null_docids.clear(self.wtxn)?;
         facet_id_is_empty_docids.clear(self.wtxn)?;
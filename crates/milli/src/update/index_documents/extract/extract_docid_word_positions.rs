@@ -10,6 +10,7 @@ use serde_json::Value;
 
 use super::helpers::{create_sorter, sorter_into_reader, GrenadParameters, KeepLatestObkv};
 use crate::error::{InternalError, SerializationError};
+use crate::stemming::{stem_word, stemmer_for};
 use crate::update::del_add::{del_add_from_two_obkvs, DelAdd, KvReaderDelAdd};
 use crate::update::settings::{InnerIndexSettings, InnerIndexSettingsDiff};
 use crate::{FieldId, Result, MAX_POSITION_PER_ATTRIBUTE, MAX_WORD_LENGTH};
@@ -217,17 +218,37 @@ fn tokens_from_document<'a>(
                 if let Some(field) = json_to_string(&value, &mut buffers.field_buffer) {
                     // create an iterator of token with their positions.
                     let locales = settings.localized_searchable_fields_ids.locales(field_id);
+                    let stemmed_locales =
+                        settings.localized_searchable_fields_ids.stemmed_locales(field_id);
                     let tokens = process_tokens(tokenizer.tokenize_with_allow_list(field, locales))
                         .take_while(|(p, _)| (*p as u32) < max_positions_per_attributes);
 
                     for (index, token) in tokens {
+                        let stemmer = match token.kind {
+                            TokenKind::Word if !stemmed_locales.is_empty() => {
+                                stemmer_for(token.language, stemmed_locales)
+                            }
+                            _ => None,
+                        };
+
                         // keep a word only if it is not empty and fit in a LMDB key.
                         let token = token.lemma().trim();
                         if !token.is_empty() && token.len() <= MAX_WORD_LENGTH {
                             let position: u16 = index
                                 .try_into()
                                 .map_err(|_| SerializationError::InvalidNumberSerialization)?;
-                            writer.insert(position, token.as_bytes())?;
+
+                            // the stem shares the position of the word, see `position_words`.
+                            buffers.words_buffer.clear();
+                            buffers.words_buffer.push_str(token);
+                            if let Some(stemmer) = stemmer {
+                                let stem = stem_word(&stemmer, token);
+                                if stem.len() <= MAX_WORD_LENGTH {
+                                    buffers.words_buffer.push('\0');
+                                    buffers.words_buffer.push_str(&stem);
+                                }
+                            }
+                            writer.insert(position, buffers.words_buffer.as_bytes())?;
                         }
                     }
 
@@ -243,6 +264,12 @@ fn tokens_from_document<'a>(
     Ok(document_writer.into_inner().map(|v| v.as_slice())?)
 }
 
+/// Returns the words stored at a position of the positions obkv:
+/// the word itself, followed by its stem when the field is stemmed.
+pub fn position_words(words: &[u8]) -> impl Iterator<Item = &[u8]> {
+    words.split(|b| *b == 0)
+}
+
 /// Transform a JSON value into a string that can be indexed.
 fn json_to_string<'a>(value: &'a Value, buffer: &'a mut String) -> Option<&'a str> {
     fn inner(value: &Value, output: &mut String) -> bool {
@@ -316,4 +343,6 @@ struct Buffers {
     obkv_buffer: Vec<u8>,
     // buffer used to store the value data containing an obkv of tokens with their positions.
     obkv_positions_buffer: Vec<u8>,
+    // buffer used to store the words of a position, a word and its stem.
+    words_buffer: String,
 }
@@ -13,13 +13,18 @@ use roaring::RoaringBitmap;
 use serde_json::{from_slice, Value};
 use FilterableValues::{Empty, Null, Values};
 
-use super::helpers::{create_sorter, sorter_into_reader, GrenadParameters, KeepFirst};
+use super::helpers::{
+    create_sorter, sorter_into_reader, GrenadParameters, KeepFirst, MergeDeladdCboRoaringBitmaps,
+};
 use crate::error::InternalError;
 use crate::facet::value_encoding::f64_into_bytes;
+use crate::facet::{facet_element_keys, original_string_keys};
 use crate::update::del_add::{DelAdd, KvReaderDelAdd, KvWriterDelAdd};
 use crate::update::index_documents::{create_writer, writer_into_reader};
-use crate::update::settings::InnerIndexSettingsDiff;
-use crate::{CboRoaringBitmapCodec, DocumentId, FieldId, Result, MAX_FACET_VALUE_LENGTH};
+use crate::update::settings::{InnerIndexSettings, InnerIndexSettingsDiff};
+use crate::{
+    parse_date, CboRoaringBitmapCodec, DocumentId, FieldId, Result, MAX_FACET_VALUE_LENGTH,
+};
 
 /// The length of the elements that are always in the buffer when inserting new values.
 const TRUNCATE_SIZE: usize = size_of::<FieldId>() + size_of::<DocumentId>();
@@ -31,6 +36,7 @@ pub struct ExtractedFacetValues {
     pub fid_facet_is_null_docids_chunk: grenad::Reader<BufReader<File>>,
     pub fid_facet_is_empty_docids_chunk: grenad::Reader<BufReader<File>>,
     pub fid_facet_exists_docids_chunk: grenad::Reader<BufReader<File>>,
+    pub fid_facet_element_docids_chunk: grenad::Reader<BufReader<File>>,
 }
 
 /// Extracts the facet values of each faceted field of each document.
@@ -66,6 +72,16 @@ pub fn extract_fid_docid_facet_values<R: io::Read + io::Seek>(
         true,
     );
 
+    let mut facet_element_docids_sorter = create_sorter(
+        grenad::SortAlgorithm::Unstable,
+        MergeDeladdCboRoaringBitmaps,
+        indexer.chunk_compression_type,
+        indexer.chunk_compression_level,
+        indexer.max_nb_chunks,
+        max_memory.map(|m| m / 2),
+        true,
+    );
+
     // The tuples represents the Del and Add side for a bitmap
     let mut facet_exists_docids = BTreeMap::<FieldId, (RoaringBitmap, RoaringBitmap)>::new();
     let mut facet_is_null_docids = BTreeMap::<FieldId, (RoaringBitmap, RoaringBitmap)>::new();
@@ -80,7 +96,13 @@ pub fn extract_fid_docid_facet_values<R: io::Read + io::Seek>(
     let new_faceted_fids: BTreeSet<_> =
         settings_diff.new.faceted_fields_ids.iter().copied().collect();
 
-    if !settings_diff.settings_update_only || old_faceted_fids != new_faceted_fids {
+    let old_date_fids = &settings_diff.old.date_fields_ids;
+    let new_date_fids = &settings_diff.new.date_fields_ids;
+
+    if !settings_diff.settings_update_only
+        || old_faceted_fids != new_faceted_fids
+        || old_date_fids != new_date_fids
+    {
         let mut cursor = obkv_documents.into_cursor()?;
         while let Some((docid_bytes, value)) = cursor.move_on_next()? {
             let obkv = obkv::KvReader::from_slice(value);
@@ -113,7 +135,10 @@ pub fn extract_fid_docid_facet_values<R: io::Read + io::Seek>(
                     }
                     EitherOrBoth::Both(&field_id, _) => {
                         // during settings update, recompute the changing settings only.
-                        if settings_diff.settings_update_only {
+                        if settings_diff.settings_update_only
+                            && old_date_fids.contains(&field_id)
+                                == new_date_fids.contains(&field_id)
+                        {
                             continue;
                         }
 
@@ -124,6 +149,18 @@ pub fn extract_fid_docid_facet_values<R: io::Read + io::Seek>(
                     }
                 };
 
+                // the objects of the arrays are also faceted one by one
+                // for the nested filters to only match the values of a single object,
+                // and the original strings for the case-sensitive equality filters
+                insert_facet_elements_diff(
+                    &mut facet_element_docids_sorter,
+                    settings_diff,
+                    field_id,
+                    docid_bytes,
+                    del_value.as_ref(),
+                    add_value.as_ref(),
+                )?;
+
                 if del_value.is_some() || add_value.is_some() {
                     numbers_key_buffer.clear();
                     strings_key_buffer.clear();
@@ -163,10 +200,14 @@ pub fn extract_fid_docid_facet_values<R: io::Read + io::Seek>(
                         .new
                         .geo_fields_ids
                         .map_or(false, |(lat, lng)| field_id == lat || field_id == lng);
-                    let del_filterable_values =
-                        del_value.map(|value| extract_facet_values(&value, del_geo_support));
-                    let add_filterable_values =
-                        add_value.map(|value| extract_facet_values(&value, add_geo_support));
+                    let del_date_support = old_date_fids.contains(&field_id);
+                    let add_date_support = new_date_fids.contains(&field_id);
+                    let del_filterable_values = del_value.map(|value| {
+                        extract_facet_values(&value, del_geo_support, del_date_support)
+                    });
+                    let add_filterable_values = add_value.map(|value| {
+                        extract_facet_values(&value, add_geo_support, add_date_support)
+                    });
 
                     // Those closures are just here to simplify things a bit.
                     let mut insert_numbers_diff = |del_numbers, add_numbers| {
@@ -298,6 +339,7 @@ pub fn extract_fid_docid_facet_values<R: io::Read + io::Seek>(
         fid_facet_is_null_docids_chunk: facet_is_null_docids_reader,
         fid_facet_is_empty_docids_chunk: facet_is_empty_docids_reader,
         fid_facet_exists_docids_chunk: facet_exists_docids_reader,
+        fid_facet_element_docids_chunk: sorter_into_reader(facet_element_docids_sorter, indexer)?,
     })
 }
 
@@ -500,6 +542,61 @@ fn insert_strings_diff(
     Ok(())
 }
 
+/// Computes the diff between the Del and Add keys of the elements of the array stored
+/// in the given field, see [`facet_element_keys`], and of its original strings, see
+/// [`original_string_keys`], and only inserts the parts that differ in the sorter.
+fn insert_facet_elements_diff(
+    facet_element_docids_sorter: &mut Sorter<MergeDeladdCboRoaringBitmaps>,
+    settings_diff: &InnerIndexSettingsDiff,
+    field_id: FieldId,
+    docid_bytes: &[u8],
+    del_value: Option<&Value>,
+    add_value: Option<&Value>,
+) -> Result<()> {
+    let (old, new) = (&settings_diff.old, &settings_diff.new);
+    let element_keys = |settings: &InnerIndexSettings, value: Option<&Value>| {
+        let fields_ids_map = &settings.fields_ids_map;
+        match (fields_ids_map.name(field_id), value) {
+            (Some(name), Some(value)) => {
+                let mut keys = facet_element_keys(
+                    name,
+                    value,
+                    |name| Ok(fields_ids_map.id(name)),
+                    |name| {
+                        fields_ids_map
+                            .id(name)
+                            .map_or(false, |id| settings.date_fields_ids.contains(&id))
+                    },
+                )?;
+                keys.extend(original_string_keys(field_id, value));
+                Ok(keys)
+            }
+            _ => Ok(BTreeSet::new()),
+        }
+    };
+    let del_keys = element_keys(old, del_value)?;
+    let add_keys = element_keys(new, add_value)?;
+
+    let document: [u8; 4] = docid_bytes[..4].try_into().ok().unwrap();
+    let document = DocumentId::from_be_bytes(document);
+
+    let mut buffer = Vec::new();
+    for eob in merge_join_by(del_keys.iter(), add_keys.iter(), |del, add| del.cmp(add)) {
+        let (side, key) = match eob {
+            EitherOrBoth::Both(_, _) => continue, // no need to touch anything
+            EitherOrBoth::Left(key) => (DelAdd::Deletion, key),
+            EitherOrBoth::Right(key) => (DelAdd::Addition, key),
+        };
+        buffer.clear();
+        let mut obkv = KvWriterDelAdd::new(&mut buffer);
+        obkv.insert(side, document.to_ne_bytes())?;
+        obkv.finish()?;
+        facet_element_docids_sorter.insert(key, &buffer)?;
+    }
+
+    Ok(())
+}
+
 /// Represent what a document field contains.
 enum FilterableValues {
     /// Corresponds to the JSON `null` value.
@@ -511,13 +608,14 @@ enum FilterableValues {
 }
 
 /// Extracts the facet values of a JSON field.
-fn extract_facet_values(value: &Value, geo_field: bool) -> FilterableValues {
+fn extract_facet_values(value: &Value, geo_field: bool, date_field: bool) -> FilterableValues {
     fn inner_extract_facet_values(
         value: &Value,
         can_recurse: bool,
         output_numbers: &mut Vec<f64>,
         output_strings: &mut Vec<(String, String)>,
         geo_field: bool,
+        date_field: bool,
     ) {
         match value {
             Value::Null => (),
@@ -539,6 +637,12 @@ fn extract_facet_values(value: &Value, geo_field: bool) -> FilterableValues {
                         )
                     }
                 }
+                // the dates are also faceted as timestamps to be filtered and sorted chronologically
+                if date_field {
+                    if let Some(timestamp) = parse_date(original) {
+                        output_numbers.push(timestamp as f64);
+                    }
+                }
                 let normalized = crate::normalize_facet(original);
                 output_strings.push((normalized, original.clone()));
             }
@@ -551,6 +655,7 @@ fn extract_facet_values(value: &Value, geo_field: bool) -> FilterableValues {
                             output_numbers,
                             output_strings,
                             geo_field,
+                            date_field,
                         );
                     }
                 }
@@ -567,7 +672,14 @@ fn extract_facet_values(value: &Value, geo_field: bool) -> FilterableValues {
         otherwise => {
             let mut numbers = Vec::new();
             let mut strings = Vec::new();
-            inner_extract_facet_values(otherwise, true, &mut numbers, &mut strings, geo_field);
+            inner_extract_facet_values(
+                otherwise,
+                true,
+                &mut numbers,
+                &mut strings,
+                geo_field,
+                date_field,
+            );
             FilterableValues::Values { numbers, strings }
         }
     }
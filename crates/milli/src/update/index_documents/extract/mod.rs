@@ -366,6 +366,7 @@ fn send_and_extract_flattened_documents_data(
                     fid_facet_is_null_docids_chunk,
                     fid_facet_is_empty_docids_chunk,
                     fid_facet_exists_docids_chunk,
+                    fid_facet_element_docids_chunk,
                 } = extract_fid_docid_facet_values(
                     flattened_documents_chunk.clone(),
                     indexer,
@@ -398,6 +399,10 @@ fn send_and_extract_flattened_documents_data(
                 let _ = lmdb_writer_sx
                     .send(Ok(TypedChunk::FieldIdFacetExistsDocids(fid_facet_exists_docids_chunk)));
 
+                let _ = lmdb_writer_sx.send(Ok(TypedChunk::FieldIdFacetElementDocids(
+                    fid_facet_element_docids_chunk,
+                )));
+
                 Ok((fid_docid_facet_numbers_chunk, fid_docid_facet_strings_chunk))
             },
         );
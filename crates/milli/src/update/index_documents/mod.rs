@@ -29,6 +29,7 @@ pub use self::transform::{Transform, TransformOutput};
 use super::new::StdResult;
 use crate::documents::{obkv_to_object, DocumentsBatchReader};
 use crate::error::{Error, InternalError};
+use crate::phonetic::{phonetic_words_snapshot, update_phonetic_code_words};
 use crate::thread_pool_no_abort::ThreadPoolNoAbortBuilder;
 pub use crate::update::index_documents::helpers::CursorClonableMmap;
 use crate::update::{
@@ -59,6 +60,11 @@ pub enum IndexDocumentsMethod {
     /// Merge the previous version of the document with the new version,
     /// replacing old attributes values with the new ones and add the new attributes.
     UpdateDocuments,
+
+    /// Apply the new version of the document as a JSON merge patch on the previous version,
+    /// merging nested objects and supporting array and numeric operators.
+    /// Only supported by the new indexer.
+    PatchDocuments,
 }
 
 impl Default for IndexDocumentsMethod {
@@ -221,6 +227,7 @@ where
         settings_diff.new.recompute_facets(self.wtxn, self.index)?;
         settings_diff.new.recompute_searchables(self.wtxn, self.index)?;
 
+        let phonetic_words_snapshot = phonetic_words_snapshot(self.index, self.wtxn)?;
         let settings_diff = Arc::new(settings_diff);
         let embedders_configs = Arc::new(self.index.embedding_configs(self.wtxn)?);
 
@@ -524,6 +531,10 @@ where
             word_fid_docids.map(MergerBuilder::build),
         )?;
 
+        if let Some(previous_words_fst) = phonetic_words_snapshot {
+            update_phonetic_code_words(self.index, self.wtxn, previous_words_fst)?;
+        }
+
         Ok(number_of_documents)
     }
 
@@ -2101,6 +2112,20 @@ mod tests {
         assert_eq!(cj_cmn_docs, expected_cj_cmn_docids);
     }
 
+    #[test]
+    fn patch_documents_is_not_supported_by_the_old_indexer() {
+        let index = TempIndex::new();
+        let mut wtxn = index.write_txn().unwrap();
+        let config = IndexDocumentsConfig {
+            update_method: IndexDocumentsMethod::PatchDocuments,
+            ..Default::default()
+        };
+
+        let result =
+            IndexDocuments::new(&mut wtxn, &index, &index.indexer_config, config, |_| (), || false);
+        assert!(matches!(result, Err(Error::UserError(UserError::UnsupportedDocumentPatch))));
+    }
+
     #[test]
     fn add_and_delete_documents_in_single_transform() {
         let mut index = TempIndex::new();
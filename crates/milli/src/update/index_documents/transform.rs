@@ -22,17 +22,21 @@ use crate::documents::{DocumentsBatchIndex, EnrichedDocument, EnrichedDocumentsB
 use crate::error::{Error, InternalError, UserError};
 use crate::index::{db_name, main_key};
 use crate::update::del_add::{
-    into_del_add_obkv, into_del_add_obkv_conditional_operation, DelAdd, DelAddOperation,
-    KvReaderDelAdd,
+    del_add_from_two_obkvs, into_del_add_obkv, into_del_add_obkv_conditional_operation, DelAdd,
+    DelAddOperation, KvReaderDelAdd,
 };
 use crate::update::index_documents::GrenadParameters;
+use crate::update::new::indexer::ComputedFields;
 use crate::update::settings::{InnerIndexSettings, InnerIndexSettingsDiff};
 use crate::update::{AvailableIds, UpdateIndexingStep};
-use crate::vector::parsed_vectors::{ExplicitVectors, VectorOrArrayOfVectors};
+use crate::vector::parsed_vectors::{
+    ExplicitVectors, VectorOrArrayOfVectors, RESERVED_VECTORS_FIELD_NAME,
+};
 use crate::vector::settings::WriteBackToDocuments;
 use crate::vector::ArroyWrapper;
 use crate::{
-    is_faceted_by, FieldDistribution, FieldId, FieldIdMapMissingEntry, FieldsIdsMap, Index, Result,
+    all_obkv_to_json, is_faceted_by, FieldDistribution, FieldId, FieldIdMapMissingEntry,
+    FieldsIdsMap, Index, Result,
 };
 
 pub struct TransformOutput {
@@ -110,13 +114,15 @@ impl<'a, 'i> Transform<'a, 'i> {
         index_documents_method: IndexDocumentsMethod,
         _autogenerate_docids: bool,
     ) -> Result<Self> {
-        use IndexDocumentsMethod::{ReplaceDocuments, UpdateDocuments};
+        use IndexDocumentsMethod::{PatchDocuments, ReplaceDocuments, UpdateDocuments};
 
         // We must choose the appropriate merge function for when two or more documents
         // with the same user id must be merged or fully replaced in the same batch.
         let merge_function = match index_documents_method {
             ReplaceDocuments => Either::Left(ObkvsKeepLastAdditionMergeDeletions),
             UpdateDocuments => Either::Right(ObkvsMergeAdditionsAndDeletions),
+            // The obkv merge functions cannot follow the nested deletions of a merge patch.
+            PatchDocuments => return Err(UserError::UnsupportedDocumentPatch.into()),
         };
 
         // We initialize the sorter with the user indexing settings.
@@ -273,7 +279,8 @@ impl<'a, 'i> Transform<'a, 'i> {
                 } else {
                     // we associate the base document with the new key, everything will get merged later.
                     let deladd_operation = match self.index_documents_method {
-                        IndexDocumentsMethod::UpdateDocuments => {
+                        IndexDocumentsMethod::UpdateDocuments
+                        | IndexDocumentsMethod::PatchDocuments => {
                             DelAddOperation::DeletionAndAddition
                         }
                         IndexDocumentsMethod::ReplaceDocuments => DelAddOperation::Deletion,
@@ -765,6 +772,79 @@ impl<'a, 'i> Transform<'a, 'i> {
         Ok(())
     }
 
+    /// Evaluates the new computed fields on an existing document, the values of the
+    /// previously computed fields are removed beforehand, then fills the provided
+    /// buffers with the differences between the old and the new versions of the document.
+    #[allow(clippy::too_many_arguments)]
+    fn recompute_existing_document(
+        external_id: &str,
+        old_obkv: &KvReader<FieldId>,
+        old_computed_fields: &BTreeMap<String, String>,
+        settings: &mut InnerIndexSettings,
+        computed_fields: &ComputedFields,
+        injected_vectors: serde_json::Map<String, Value>,
+        field_distribution: &mut FieldDistribution,
+        original_obkv_buffer: &mut Vec<u8>,
+        flattened_obkv_buffer: &mut Vec<u8>,
+    ) -> Result<()> {
+        let fields_ids_map = &mut settings.fields_ids_map;
+        let mut document = all_obkv_to_json(old_obkv, fields_ids_map)?;
+        let old_fields: HashSet<String> = document.keys().cloned().collect();
+
+        for field in old_computed_fields.keys().chain(settings.computed_fields.keys()) {
+            document.shift_remove(field);
+        }
+        if !injected_vectors.is_empty() {
+            let vectors = document
+                .entry(RESERVED_VECTORS_FIELD_NAME)
+                .or_insert_with(|| Value::Object(Default::default()));
+            match vectors {
+                Value::Object(vectors) => vectors.extend(injected_vectors),
+                vectors => *vectors = Value::Object(injected_vectors),
+            }
+        }
+        computed_fields.apply(external_id, &mut document)?;
+
+        for field in old_fields.iter().filter(|field| !document.contains_key(*field)) {
+            if let BEntry::Occupied(mut entry) = field_distribution.entry(field.clone()) {
+                match entry.get().checked_sub(1) {
+                    Some(0) | None => {
+                        entry.remove();
+                    }
+                    Some(new_val) => *entry.get_mut() = new_val,
+                }
+            }
+        }
+        for field in document.keys().filter(|field| !old_fields.contains(*field)) {
+            *field_distribution.entry(field.clone()).or_insert(0) += 1;
+        }
+
+        let mut key_value = Vec::with_capacity(document.len());
+        for (field, value) in &document {
+            let fid = fields_ids_map.insert(field).ok_or(UserError::AttributeLimitReached)?;
+            let value = serde_json::to_vec(value).map_err(InternalError::SerdeJson)?;
+            key_value.push((fid, Cow::from(value)));
+        }
+        key_value.sort_unstable_by_key(|(fid, _)| *fid);
+        let mut new_obkv = Vec::new();
+        Self::create_obkv_from_key_value(&mut key_value, &mut new_obkv)?;
+        let new_obkv = KvReader::<FieldId>::from_slice(&new_obkv);
+
+        original_obkv_buffer.clear();
+        del_add_from_two_obkvs(old_obkv, new_obkv, original_obkv_buffer)?;
+
+        // take the non-flattened versions if flatten_from_fields_ids_map returns None.
+        let old_flattened = Self::flatten_from_fields_ids_map(old_obkv, fields_ids_map)?;
+        let old_flattened = old_flattened.as_deref().map_or(old_obkv, KvReader::from_slice);
+        let new_flattened = Self::flatten_from_fields_ids_map(new_obkv, fields_ids_map)?;
+        let new_flattened = new_flattened.as_deref().map_or(new_obkv, KvReader::from_slice);
+
+        flattened_obkv_buffer.clear();
+        del_add_from_two_obkvs(old_flattened, new_flattened, flattened_obkv_buffer)?;
+
+        Ok(())
+    }
+
     /// Clear all databases. Returns a `TransformOutput` with a file that contains the documents
     /// of the index with the attributes reordered accordingly to the `FieldsIdsMap` given as argument.
     ///
@@ -777,7 +857,7 @@ impl<'a, 'i> Transform<'a, 'i> {
     pub fn prepare_for_documents_reindexing(
         self,
         wtxn: &mut heed::RwTxn<'i>,
-        settings_diff: InnerIndexSettingsDiff,
+        mut settings_diff: InnerIndexSettingsDiff,
     ) -> Result<TransformOutput> {
         // There already has been a document addition, the primary key should be set by now.
         let primary_key = self
@@ -788,13 +868,20 @@ impl<'a, 'i> Transform<'a, 'i> {
                 key: Some(main_key::PRIMARY_KEY_KEY),
             })?
             .to_string();
-        let field_distribution = self.index.field_distribution(wtxn)?;
+        let mut field_distribution = self.index.field_distribution(wtxn)?;
 
         let documents_ids = self.index.documents_ids(wtxn)?;
         let documents_count = documents_ids.len() as usize;
 
+        // The computed fields are evaluated again on every document when their expressions change.
+        let computed_fields = if settings_diff.reindex_computed_fields() {
+            Some(ComputedFields::compile(&settings_diff.new.computed_fields)?)
+        } else {
+            None
+        };
+
         // We initialize the sorter with the user indexing settings.
-        let mut original_sorter = if settings_diff.reindex_vectors() {
+        let mut original_sorter = if settings_diff.reindex_vectors() || computed_fields.is_some() {
             Some(create_sorter(
                 grenad::SortAlgorithm::Stable,
                 KeepFirst,
@@ -833,23 +920,26 @@ impl<'a, 'i> Transform<'a, 'i> {
             .id(crate::vector::parsed_vectors::RESERVED_VECTORS_FIELD_NAME);
 
         // We initialize the sorter with the user indexing settings.
-        let mut flattened_sorter =
-            if settings_diff.reindex_searchable() || settings_diff.reindex_facets() {
-                Some(create_sorter(
-                    grenad::SortAlgorithm::Stable,
-                    KeepFirst,
-                    self.indexer_settings.chunk_compression_type,
-                    self.indexer_settings.chunk_compression_level,
-                    self.indexer_settings.max_nb_chunks,
-                    self.indexer_settings.max_memory.map(|mem| mem / 2),
-                    true,
-                ))
-            } else {
-                None
-            };
+        let mut flattened_sorter = if settings_diff.reindex_searchable()
+            || settings_diff.reindex_facets()
+            || computed_fields.is_some()
+        {
+            Some(create_sorter(
+                grenad::SortAlgorithm::Stable,
+                KeepFirst,
+                self.indexer_settings.chunk_compression_type,
+                self.indexer_settings.chunk_compression_level,
+                self.indexer_settings.max_nb_chunks,
+                self.indexer_settings.max_memory.map(|mem| mem / 2),
+                true,
+            ))
+        } else {
+            None
+        };
 
         if original_sorter.is_some() || flattened_sorter.is_some() {
             let modified_faceted_fields = settings_diff.modified_faceted_fields();
+            let fields_count = settings_diff.new.fields_ids_map.len();
             let mut original_obkv_buffer = Vec::new();
             let mut flattened_obkv_buffer = Vec::new();
             let mut document_sorter_key_buffer = Vec::new();
@@ -887,15 +977,29 @@ impl<'a, 'i> Transform<'a, 'i> {
 
                 let injected_vectors = injected_vectors?;
 
-                Self::rebind_existing_document(
-                    old_obkv,
-                    &settings_diff,
-                    &modified_faceted_fields,
-                    injected_vectors,
-                    old_vectors_fid,
-                    Some(&mut original_obkv_buffer).filter(|_| original_sorter.is_some()),
-                    Some(&mut flattened_obkv_buffer).filter(|_| flattened_sorter.is_some()),
-                )?;
+                if let Some(computed_fields) = &computed_fields {
+                    Self::recompute_existing_document(
+                        external_id,
+                        old_obkv,
+                        &settings_diff.old.computed_fields,
+                        &mut settings_diff.new,
+                        computed_fields,
+                        injected_vectors,
+                        &mut field_distribution,
+                        &mut original_obkv_buffer,
+                        &mut flattened_obkv_buffer,
+                    )?;
+                } else {
+                    Self::rebind_existing_document(
+                        old_obkv,
+                        &settings_diff,
+                        &modified_faceted_fields,
+                        injected_vectors,
+                        old_vectors_fid,
+                        Some(&mut original_obkv_buffer).filter(|_| original_sorter.is_some()),
+                        Some(&mut flattened_obkv_buffer).filter(|_| flattened_sorter.is_some()),
+                    )?;
+                }
 
                 if let Some(original_sorter) = original_sorter.as_mut() {
                     document_sorter_key_buffer.clear();
@@ -907,6 +1011,10 @@ impl<'a, 'i> Transform<'a, 'i> {
                     flattened_sorter.insert(docid.to_be_bytes(), &flattened_obkv_buffer)?;
                 }
             }
+
+            if settings_diff.new.fields_ids_map.len() != fields_count {
+                self.index.put_fields_ids_map(wtxn, &settings_diff.new.fields_ids_map)?;
+            }
         }
 
         // delete all vectors from the embedders that need removal
@@ -114,6 +114,7 @@ pub enum Database {
     FacetIdExistsDocids,
     FacetIdF64NumberDocids,
     FacetIdStringDocids,
+    FacetIdElementDocids,
     FieldIdDocidFacetStrings,
     FieldIdDocidFacetF64s,
 }
@@ -135,6 +136,7 @@ impl Database {
             Database::FacetIdExistsDocids => index.facet_id_exists_docids.remap_types(),
             Database::FacetIdF64NumberDocids => index.facet_id_f64_docids.remap_types(),
             Database::FacetIdStringDocids => index.facet_id_string_docids.remap_types(),
+            Database::FacetIdElementDocids => index.facet_id_element_docids.remap_types(),
             Database::FieldIdDocidFacetStrings => index.field_id_docid_facet_strings.remap_types(),
             Database::FieldIdDocidFacetF64s => index.field_id_docid_facet_f64s.remap_types(),
         }
@@ -149,6 +151,7 @@ impl From<FacetKind> for Database {
             FacetKind::Null => Database::FacetIdIsNullDocids,
             FacetKind::Empty => Database::FacetIdIsEmptyDocids,
             FacetKind::Exists => Database::FacetIdExistsDocids,
+            FacetKind::Element => Database::FacetIdElementDocids,
         }
     }
 }
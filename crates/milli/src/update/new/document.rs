@@ -1,5 +1,6 @@
 use std::collections::{BTreeMap, BTreeSet};
 
+use bumpalo::Bump;
 use heed::RoTxn;
 use raw_collections::RawMap;
 use serde_json::value::RawValue;
@@ -8,7 +9,7 @@ use super::vector_document::VectorDocument;
 use super::{KvReaderFieldId, KvWriterFieldId};
 use crate::documents::FieldIdMapper;
 use crate::vector::parsed_vectors::RESERVED_VECTORS_FIELD_NAME;
-use crate::{DocumentId, GlobalFieldsIdsMap, Index, InternalError, Result, UserError};
+use crate::{DocumentId, GlobalFieldsIdsMap, Index, InternalError, Object, Result, UserError};
 
 /// A view into a document that can represent either the current version from the DB,
 /// the update data from payload or other means, or the merged updated version.
@@ -381,6 +382,15 @@ where
     Ok(KvReaderFieldId::from_slice(document_buffer))
 }
 
+/// Serializes a JSON object in the allocator, so that it can be used as the version of a document.
+pub fn object_to_raw_value<'bump>(object: &Object, bump: &'bump Bump) -> Result<&'bump RawValue> {
+    let mut buffer = bumpalo::collections::Vec::new_in(bump);
+    serde_json::to_writer(&mut buffer, object).map_err(InternalError::SerdeJson)?;
+    let document =
+        serde_json::from_slice(buffer.into_bump_slice()).map_err(InternalError::SerdeJson)?;
+    Ok(document)
+}
+
 pub type Entry<'doc> = (&'doc str, &'doc RawValue);
 
 #[derive(Debug)]
@@ -27,6 +27,7 @@ use crate::{DocumentId, FieldId, Index, Result, MAX_FACET_VALUE_LENGTH};
 
 pub struct FacetedExtractorData<'a> {
     attributes_to_extract: &'a [&'a str],
+    date_fields: &'a [&'a str],
     sender: &'a FieldIdDocidFacetSender<'a>,
     grenad_parameters: GrenadParameters,
     buckets: usize,
@@ -53,6 +54,7 @@ impl<'a, 'extractor> Extractor<'extractor> for FacetedExtractorData<'a> {
             FacetedDocidsExtractor::extract_document_change(
                 context,
                 self.attributes_to_extract,
+                self.date_fields,
                 change,
                 self.sender,
             )?
@@ -67,6 +69,7 @@ impl FacetedDocidsExtractor {
     fn extract_document_change(
         context: &DocumentChangeContext<RefCell<BalancedCaches>>,
         attributes_to_extract: &[&str],
+        date_fields: &[&str],
         document_change: DocumentChange,
         sender: &FieldIdDocidFacetSender,
     ) -> Result<()> {
@@ -77,28 +80,40 @@ impl FacetedDocidsExtractor {
         let mut del_add_facet_value = DelAddFacetValue::new(&context.doc_alloc);
         let docid = document_change.docid();
         let res = match document_change {
-            DocumentChange::Deletion(inner) => extract_document_facets(
-                attributes_to_extract,
-                inner.current(rtxn, index, context.db_fields_ids_map)?,
-                inner.external_document_id(),
-                new_fields_ids_map.deref_mut(),
-                &mut |fid, depth, value| {
-                    Self::facet_fn_with_options(
-                        &context.doc_alloc,
-                        cached_sorter.deref_mut(),
-                        BalancedCaches::insert_del_u32,
-                        &mut del_add_facet_value,
-                        DelAddFacetValue::insert_del,
-                        docid,
-                        fid,
-                        depth,
-                        value,
-                    )
-                },
-            ),
+            DocumentChange::Deletion(inner) => {
+                let element_keys = extract_document_facets(
+                    attributes_to_extract,
+                    date_fields,
+                    inner.current(rtxn, index, context.db_fields_ids_map)?,
+                    inner.external_document_id(),
+                    new_fields_ids_map.deref_mut(),
+                    &mut |fid, depth, value| {
+                        Self::facet_fn_with_options(
+                            &context.doc_alloc,
+                            cached_sorter.deref_mut(),
+                            BalancedCaches::insert_del_u32,
+                            &mut del_add_facet_value,
+                            DelAddFacetValue::insert_del,
+                            docid,
+                            fid,
+                            depth,
+                            value,
+                        )
+                    },
+                )?;
+
+                Self::element_keys_fn(
+                    &context.doc_alloc,
+                    cached_sorter.deref_mut(),
+                    BalancedCaches::insert_del_u32,
+                    docid,
+                    element_keys,
+                )
+            }
             DocumentChange::Update(inner) => {
-                extract_document_facets(
+                let element_keys = extract_document_facets(
                     attributes_to_extract,
+                    date_fields,
                     inner.current(rtxn, index, context.db_fields_ids_map)?,
                     inner.external_document_id(),
                     new_fields_ids_map.deref_mut(),
@@ -117,8 +132,17 @@ impl FacetedDocidsExtractor {
                     },
                 )?;
 
-                extract_document_facets(
+                Self::element_keys_fn(
+                    &context.doc_alloc,
+                    cached_sorter.deref_mut(),
+                    BalancedCaches::insert_del_u32,
+                    docid,
+                    element_keys,
+                )?;
+
+                let element_keys = extract_document_facets(
                     attributes_to_extract,
+                    date_fields,
                     inner.merged(rtxn, index, context.db_fields_ids_map)?,
                     inner.external_document_id(),
                     new_fields_ids_map.deref_mut(),
@@ -135,27 +159,46 @@ impl FacetedDocidsExtractor {
                             value,
                         )
                     },
+                )?;
+
+                Self::element_keys_fn(
+                    &context.doc_alloc,
+                    cached_sorter.deref_mut(),
+                    BalancedCaches::insert_add_u32,
+                    docid,
+                    element_keys,
+                )
+            }
+            DocumentChange::Insertion(inner) => {
+                let element_keys = extract_document_facets(
+                    attributes_to_extract,
+                    date_fields,
+                    inner.inserted(),
+                    inner.external_document_id(),
+                    new_fields_ids_map.deref_mut(),
+                    &mut |fid, depth, value| {
+                        Self::facet_fn_with_options(
+                            &context.doc_alloc,
+                            cached_sorter.deref_mut(),
+                            BalancedCaches::insert_add_u32,
+                            &mut del_add_facet_value,
+                            DelAddFacetValue::insert_add,
+                            docid,
+                            fid,
+                            depth,
+                            value,
+                        )
+                    },
+                )?;
+
+                Self::element_keys_fn(
+                    &context.doc_alloc,
+                    cached_sorter.deref_mut(),
+                    BalancedCaches::insert_add_u32,
+                    docid,
+                    element_keys,
                 )
             }
-            DocumentChange::Insertion(inner) => extract_document_facets(
-                attributes_to_extract,
-                inner.inserted(),
-                inner.external_document_id(),
-                new_fields_ids_map.deref_mut(),
-                &mut |fid, depth, value| {
-                    Self::facet_fn_with_options(
-                        &context.doc_alloc,
-                        cached_sorter.deref_mut(),
-                        BalancedCaches::insert_add_u32,
-                        &mut del_add_facet_value,
-                        DelAddFacetValue::insert_add,
-                        docid,
-                        fid,
-                        depth,
-                        value,
-                    )
-                },
-            ),
         };
 
         del_add_facet_value.send_data(docid, sender, &context.doc_alloc).unwrap();
@@ -268,6 +311,26 @@ impl FacetedDocidsExtractor {
         }
     }
 
+    /// Inserts the keys of the elements of the faceted arrays, see [`extract_document_facets`].
+    fn element_keys_fn<'extractor>(
+        doc_alloc: &Bump,
+        cached_sorter: &mut BalancedCaches<'extractor>,
+        cache_fn: impl Fn(&mut BalancedCaches<'extractor>, &[u8], u32) -> Result<()>,
+        docid: DocumentId,
+        element_keys: Vec<Vec<u8>>,
+    ) -> Result<()> {
+        let mut buffer = BVec::new_in(doc_alloc);
+        for key in element_keys {
+            // Element
+            // key: fid - kind - element - value
+            buffer.clear();
+            buffer.push(FacetKind::Element as u8);
+            buffer.extend_from_slice(&key);
+            cache_fn(cached_sorter, &buffer, docid)?;
+        }
+        Ok(())
+    }
+
     fn attributes_to_extract<'a>(rtxn: &'a RoTxn, index: &'a Index) -> Result<HashSet<String>> {
         index.user_defined_faceted_fields(rtxn)
     }
@@ -390,6 +453,8 @@ impl FacetedDocidsExtractor {
         let attributes_to_extract = Self::attributes_to_extract(&rtxn, index)?;
         let attributes_to_extract: Vec<_> =
             attributes_to_extract.iter().map(|s| s.as_ref()).collect();
+        let date_fields = index.date_fields(&rtxn)?;
+        let date_fields: Vec<_> = date_fields.iter().map(|s| s.as_ref()).collect();
         let datastore = ThreadLocal::new();
 
         {
@@ -399,6 +464,7 @@ impl FacetedDocidsExtractor {
 
             let extractor = FacetedExtractorData {
                 attributes_to_extract: &attributes_to_extract,
+                date_fields: &date_fields,
                 grenad_parameters,
                 buckets: rayon::current_num_threads(),
                 sender,
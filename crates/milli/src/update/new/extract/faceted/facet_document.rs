@@ -1,33 +1,54 @@
-use serde_json::Value;
+use serde_json::{Map, Value};
 
+use crate::facet::{facet_element_keys, original_string_keys};
 use crate::update::new::document::Document;
 use crate::update::new::extract::geo::extract_geo_coordinates;
 use crate::update::new::extract::perm_json_p;
-use crate::{FieldId, GlobalFieldsIdsMap, InternalError, Result, UserError};
+use crate::{parse_date, FieldId, GlobalFieldsIdsMap, InternalError, Result, UserError};
 
+/// Extracts the facet values of the document with `facet_fn` and returns the keys of the
+/// elements of its faceted arrays, see [`facet_element_keys`], and of its original strings,
+/// see [`original_string_keys`].
 pub fn extract_document_facets<'doc>(
     attributes_to_extract: &[&str],
+    date_fields: &[&str],
     document: impl Document<'doc>,
     external_document_id: &str,
     field_id_map: &mut GlobalFieldsIdsMap,
     facet_fn: &mut impl FnMut(FieldId, perm_json_p::Depth, &Value) -> Result<()>,
-) -> Result<()> {
+) -> Result<Vec<Vec<u8>>> {
+    let mut element_keys = Vec::new();
     for res in document.iter_top_level_fields() {
         let (field_name, value) = res?;
 
         let mut tokenize_field =
-            |name: &str, depth: perm_json_p::Depth, value: &Value| match field_id_map
-                .id_or_insert(name)
-            {
-                Some(field_id) => facet_fn(field_id, depth, value),
-                None => Err(UserError::AttributeLimitReached.into()),
+            |name: &str, depth: perm_json_p::Depth, value: &Value| -> Result<()> {
+                let field_id =
+                    field_id_map.id_or_insert(name).ok_or(UserError::AttributeLimitReached)?;
+                facet_fn(field_id, depth, value)?;
+
+                // the leaf strings are also faceted as written for the case-sensitive equality
+                if let Value::String(_) | Value::Bool(_) = value {
+                    element_keys.extend(original_string_keys(field_id, value));
+                }
+
+                // the dates are also faceted as timestamps to be filtered and sorted chronologically
+                match value {
+                    Value::String(s) if date_fields.contains(&name) => match parse_date(s) {
+                        Some(timestamp) => facet_fn(field_id, depth, &timestamp.into()),
+                        None => Ok(()),
+                    },
+                    _ => Ok(()),
+                }
             };
 
         // if the current field is searchable or contains a searchable attribute
         let selection = perm_json_p::select_field(field_name, Some(attributes_to_extract), &[]);
         if selection != perm_json_p::Selection::Skip {
             // parse json.
-            match serde_json::value::to_value(value).map_err(InternalError::SerdeJson)? {
+            let value = serde_json::value::to_value(value).map_err(InternalError::SerdeJson)?;
+            let arrays = faceted_arrays(field_name, &value, attributes_to_extract);
+            match value {
                 Value::Object(object) => {
                     perm_json_p::seek_leaf_values_in_object(
                         &object,
@@ -66,6 +87,20 @@ pub fn extract_document_facets<'doc>(
                 }
                 value => tokenize_field(field_name, perm_json_p::Depth::OnBaseKey, &value)?,
             }
+
+            // the objects of the faceted arrays are also faceted one by one
+            // for the nested filters to only match the values of a single object
+            for (name, value) in arrays {
+                element_keys.extend(facet_element_keys(
+                    &name,
+                    &value,
+                    |name| {
+                        let field_id = field_id_map.id_or_insert(name);
+                        Ok(Some(field_id.ok_or(UserError::AttributeLimitReached)?))
+                    },
+                    |name| date_fields.contains(&name),
+                )?);
+            }
         }
     }
 
@@ -83,5 +118,27 @@ pub fn extract_document_facets<'doc>(
         }
     }
 
-    Ok(())
+    Ok(element_keys)
+}
+
+/// Returns the faceted arrays found in the given top-level field,
+/// following the flattening of the documents done by the old indexing pipeline.
+fn faceted_arrays(
+    field_name: &str,
+    value: &Value,
+    attributes_to_extract: &[&str],
+) -> Vec<(String, Value)> {
+    if !value.is_array() && !value.is_object() {
+        return Vec::new();
+    }
+
+    let document = Map::from_iter([(field_name.to_string(), value.clone())]);
+    flatten_serde_json::flatten(&document)
+        .into_iter()
+        .filter(|(name, _)| {
+            perm_json_p::select_field(name, Some(attributes_to_extract), &[])
+                == perm_json_p::Selection::Select
+        })
+        .filter(|(_, value)| value.is_array())
+        .collect()
 }
@@ -11,6 +11,7 @@ pub enum FacetKind {
     Null = 2,
     Empty = 3,
     Exists,
+    Element,
 }
 
 impl From<u8> for FacetKind {
@@ -21,6 +22,7 @@ impl From<u8> for FacetKind {
             2 => Self::Null,
             3 => Self::Empty,
             4 => Self::Exists,
+            5 => Self::Element,
             _ => unreachable!(),
         }
     }
@@ -8,6 +8,7 @@ use bumpalo::Bump;
 use heed::RoTxn;
 
 use super::tokenize_document::{tokenizer_builder, DocumentTokenizer};
+use crate::stemming::is_stem_word;
 use crate::update::new::extract::cache::BalancedCaches;
 use crate::update::new::extract::perm_json_p::contained_in;
 use crate::update::new::indexer::document_changes::{
@@ -83,10 +84,13 @@ impl<'extractor> WordDocidsBalancedCaches<'extractor> {
             self.flush_fid_word_count(&mut buffer)?;
         }
 
-        self.fid_word_count
-            .entry(field_id)
-            .and_modify(|(_current_count, new_count)| *new_count += 1)
-            .or_insert((0, 1));
+        // the stems share the position of their word and aren't counted
+        if !is_stem_word(word) {
+            self.fid_word_count
+                .entry(field_id)
+                .and_modify(|(_current_count, new_count)| *new_count += 1)
+                .or_insert((0, 1));
+        }
         self.current_docid = Some(docid);
 
         Ok(())
@@ -128,10 +132,12 @@ impl<'extractor> WordDocidsBalancedCaches<'extractor> {
             self.flush_fid_word_count(&mut buffer)?;
         }
 
-        self.fid_word_count
-            .entry(field_id)
-            .and_modify(|(current_count, _new_count)| *current_count += 1)
-            .or_insert((1, 0));
+        if !is_stem_word(word) {
+            self.fid_word_count
+                .entry(field_id)
+                .and_modify(|(current_count, _new_count)| *current_count += 1)
+                .or_insert((1, 0));
+        }
 
         self.current_docid = Some(docid);
 
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use charabia::{SeparatorKind, Token, TokenKind, Tokenizer, TokenizerBuilder};
 use serde_json::Value;
 
+use crate::stemming::{stem_word, stemmer_for};
 use crate::update::new::document::Document;
 use crate::update::new::extract::perm_json_p::{
     seek_leaf_values_in_array, seek_leaf_values_in_object, select_field, Depth, Selection,
@@ -54,6 +55,9 @@ impl<'a> DocumentTokenizer<'a> {
                     return Ok(());
                 }
 
+                let rule =
+                    self.localized_attributes_rules.iter().find(|rule| rule.match_str(field_name));
+
                 let text;
                 let tokens = match value {
                     Value::Number(n) => {
@@ -65,11 +69,7 @@ impl<'a> DocumentTokenizer<'a> {
                         self.tokenizer.tokenize(text.as_str())
                     }
                     Value::String(text) => {
-                        let locales = self
-                            .localized_attributes_rules
-                            .iter()
-                            .find(|rule| rule.match_str(field_name))
-                            .map(|rule| rule.locales());
+                        let locales = rule.map(|rule| rule.locales());
                         self.tokenizer.tokenize_with_allow_list(text.as_str(), locales)
                     }
                     _ => return Ok(()),
@@ -79,13 +79,29 @@ impl<'a> DocumentTokenizer<'a> {
                 let tokens = process_tokens(*position, tokens)
                     .take_while(|(p, _)| *p < self.max_positions_per_attributes);
 
+                let stemmed_locales = rule.map_or(&[][..], |rule| rule.stemmed_locales());
                 for (index, token) in tokens {
+                    let stemmer = match token.kind {
+                        TokenKind::Word if !stemmed_locales.is_empty() => {
+                            stemmer_for(token.language, stemmed_locales)
+                        }
+                        _ => None,
+                    };
+
                     // keep a word only if it is not empty and fit in a LMDB key.
                     let token = token.lemma().trim();
                     if !token.is_empty() && token.len() <= MAX_WORD_LENGTH {
                         *position = index;
                         if let Ok(position) = (*position).try_into() {
                             token_fn(field_name, field_id, position, token)?;
+
+                            // the stem is indexed at the same position as the word.
+                            if let Some(stemmer) = stemmer {
+                                let stem = stem_word(&stemmer, token);
+                                if stem.len() <= MAX_WORD_LENGTH {
+                                    token_fn(field_name, field_id, position, &stem)?;
+                                }
+                            }
                         }
                     }
                 }
@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+
+use heed::RoTxn;
+use raw_collections::RawMap;
+use rhai::{Dynamic, Engine, Scope, AST};
+
+use super::document_changes::DocumentChangeContext;
+use super::update_by_function::limited_engine;
+use crate::error::InternalError;
+use crate::update::new::document::{object_to_raw_value, Document, Versions};
+use crate::update::new::thread_local::MostlySend;
+use crate::update::new::{DocumentChange, Insertion, Update};
+use crate::vector::parsed_vectors::RESERVED_VECTORS_FIELD_NAME;
+use crate::{Index, Object, Result, UserError};
+
+/// The compiled Rhai expressions of the computed fields of an index.
+///
+/// Every expression is evaluated with the document in the `doc` variable and the
+/// value it returns is stored in its field, a `()` removes the field instead.
+pub struct ComputedFields {
+    engine: Engine,
+    fields: Vec<(String, AST)>,
+}
+
+impl ComputedFields {
+    /// Compiles the computed fields of the index, `None` if it doesn't declare any.
+    pub fn new(index: &Index, rtxn: &RoTxn) -> Result<Option<Self>> {
+        let fields = index.computed_fields(rtxn)?;
+        if fields.is_empty() {
+            Ok(None)
+        } else {
+            Self::compile(&fields).map(Some)
+        }
+    }
+
+    pub fn compile(fields: &BTreeMap<String, String>) -> Result<Self> {
+        let engine = limited_engine();
+        let mut compiled = Vec::with_capacity(fields.len());
+        for (field, expression) in fields {
+            let ast = engine
+                .compile_expression(expression)
+                .map_err(|error| UserError::InvalidComputedField { field: field.clone(), error })?;
+            compiled.push((field.clone(), ast));
+        }
+        Ok(ComputedFields { engine, fields: compiled })
+    }
+
+    /// Evaluates the computed fields on the document and stores their values in it.
+    ///
+    /// The expressions all see the document as it was before any computed field was stored.
+    pub fn apply(&self, external_docid: &str, document: &mut Object) -> Result<()> {
+        let doc: rhai::Map = document
+            .iter()
+            .map(|(name, value)| {
+                let value = serde_json::from_value(value.clone())?;
+                Ok((name.as_str().into(), value))
+            })
+            .collect::<serde_json::Result<_>>()
+            .map_err(InternalError::SerdeJson)?;
+        let doc = Dynamic::from_map(doc);
+
+        let mut values = Vec::with_capacity(self.fields.len());
+        for (field, ast) in &self.fields {
+            let mut scope = Scope::new();
+            scope.push_constant_dynamic("doc", doc.clone());
+            let value =
+                self.engine.eval_ast_with_scope::<Dynamic>(&mut scope, ast).map_err(|error| {
+                    UserError::ComputedFieldRuntimeError {
+                        field: field.clone(),
+                        document_id: external_docid.to_string(),
+                        error,
+                    }
+                })?;
+            values.push((field, value));
+        }
+
+        for (field, value) in values {
+            if value.is_unit() {
+                document.shift_remove(field);
+            } else {
+                let value = serde_json::to_value(&value).map_err(InternalError::SerdeJson)?;
+                document.insert(field.clone(), value);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Evaluates the computed fields on the new version of the document of the change.
+    ///
+    /// The returned change contains the complete document, merged with the one
+    /// in the database for updates, as the expressions can depend on any field.
+    pub fn apply_on_change<'doc, T: MostlySend + 'doc>(
+        &self,
+        change: DocumentChange<'doc>,
+        context: &'doc DocumentChangeContext<T>,
+    ) -> Result<DocumentChange<'doc>> {
+        let DocumentChangeContext { index, db_fields_ids_map, rtxn, doc_alloc, .. } = context;
+
+        let (docid, external_docid, mut document) = match &change {
+            DocumentChange::Deletion(_) => return Ok(change),
+            DocumentChange::Insertion(insertion) => (
+                insertion.docid(),
+                insertion.external_document_id(),
+                document_to_object(&insertion.inserted())?,
+            ),
+            DocumentChange::Update(update) => (
+                update.docid(),
+                update.external_document_id(),
+                document_to_object(&update.merged(rtxn, index, *db_fields_ids_map)?)?,
+            ),
+        };
+
+        self.apply(external_docid, &mut document)?;
+
+        let document = object_to_raw_value(&document, doc_alloc)?;
+        let document =
+            RawMap::from_raw_value(document, doc_alloc).map_err(InternalError::SerdeJson)?;
+
+        match change {
+            DocumentChange::Insertion(_) => Ok(DocumentChange::Insertion(Insertion::create(
+                docid,
+                external_docid,
+                Versions::single(document),
+            ))),
+            _ => Ok(DocumentChange::Update(Update::create(
+                docid,
+                external_docid,
+                Versions::single(document),
+                true, // the document contains all the fields
+            ))),
+        }
+    }
+}
+
+fn document_to_object<'doc>(document: &impl Document<'doc>) -> Result<Object> {
+    let mut object = Object::new();
+    for entry in document.iter_top_level_fields() {
+        let (name, value) = entry?;
+        let value = serde_json::from_str(value.get()).map_err(InternalError::SerdeJson)?;
+        object.insert(name.to_string(), value);
+    }
+    if let Some(vectors) = document.vectors_field()? {
+        let vectors = serde_json::from_str(vectors.get()).map_err(InternalError::SerdeJson)?;
+        object.insert(RESERVED_VECTORS_FIELD_NAME.to_string(), vectors);
+    }
+    if let Some(geo) = document.geo_field()? {
+        let geo = serde_json::from_str(geo.get()).map_err(InternalError::SerdeJson)?;
+        object.insert("_geo".to_string(), geo);
+    }
+    Ok(object)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn apply(fields: &[(&str, &str)], document: Value) -> Result<Value> {
+        let fields = fields.iter().map(|(f, e)| (f.to_string(), e.to_string())).collect();
+        let computed_fields = ComputedFields::compile(&fields)?;
+        let Value::Object(mut document) = document else { panic!() };
+        computed_fields.apply("1", &mut document)?;
+        Ok(Value::Object(document))
+    }
+
+    #[test]
+    fn computed_fields() {
+        let fields = [
+            ("discount_ratio", "doc.price / doc.msrp"),
+            ("title", "doc.title.to_upper()"),
+            ("msrp", "()"),
+        ];
+        let document = json!({ "id": 1, "title": "dune", "price": 6.0, "msrp": 8.0 });
+        let document = apply(&fields, document).unwrap();
+        assert_eq!(
+            document,
+            json!({ "id": 1, "title": "DUNE", "price": 6.0, "discount_ratio": 0.75 })
+        );
+    }
+
+    #[test]
+    fn invalid_computed_fields() {
+        let error = apply(&[("ratio", "doc.price /")], json!({})).unwrap_err();
+        assert!(matches!(error, crate::Error::UserError(UserError::InvalidComputedField { .. })));
+
+        let error =
+            apply(&[("ratio", "doc.price / doc.msrp")], json!({ "price": "a" })).unwrap_err();
+        assert!(matches!(
+            error,
+            crate::Error::UserError(UserError::ComputedFieldRuntimeError { .. })
+        ));
+    }
+}
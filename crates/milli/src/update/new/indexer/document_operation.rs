@@ -6,18 +6,26 @@ use memmap2::Mmap;
 use raw_collections::RawMap;
 use rayon::slice::ParallelSlice;
 use serde_json::value::RawValue;
-use serde_json::Deserializer;
+use serde_json::{Deserializer, Value};
 
 use super::super::document_change::DocumentChange;
+use super::computed_fields::ComputedFields;
 use super::document_changes::{DocumentChangeContext, DocumentChanges, Progress};
+use super::merge_patch::apply_merge_patch;
 use super::retrieve_or_guess_primary_key;
-use crate::documents::PrimaryKey;
-use crate::update::new::document::Versions;
+use crate::document_schema::{DocumentSchema, SchemaMode};
+use crate::documents::{fetch_nested_values, PrimaryKey, DEFAULT_PRIMARY_KEY};
+use crate::index::DocumentChangeKind;
+use crate::ingest_pipeline::{apply_ingest_pipeline, IngestProcessor};
+use crate::update::new::document::{object_to_raw_value, Versions};
 use crate::update::new::steps::Step;
 use crate::update::new::thread_local::MostlySend;
 use crate::update::new::{Deletion, Insertion, Update};
 use crate::update::{AvailableIds, IndexDocumentsMethod};
-use crate::{DocumentId, Error, FieldsIdsMap, Index, InternalError, Result, UserError};
+use crate::{
+    all_obkv_to_json, DocumentId, Error, FieldsIdsMap, Index, InternalError, Object, Result,
+    UserError,
+};
 
 pub struct DocumentOperation<'pl> {
     operations: Vec<Payload<'pl>>,
@@ -34,7 +42,16 @@ impl<'pl> DocumentOperation<'pl> {
     pub fn add_documents(&mut self, payload: &'pl Mmap) -> Result<()> {
         #[cfg(unix)]
         payload.advise(memmap2::Advice::Sequential)?;
-        self.operations.push(Payload::Addition(&payload[..]));
+        self.operations.push(Payload::Addition { payload: &payload[..], auto_generate_ids: false });
+        Ok(())
+    }
+
+    /// Same as [`Self::add_documents`] but generates an id for the documents of this payload
+    /// that don't have one, even if the index doesn't auto-generate the document ids.
+    pub fn add_documents_generating_ids(&mut self, payload: &'pl Mmap) -> Result<()> {
+        #[cfg(unix)]
+        payload.advise(memmap2::Advice::Sequential)?;
+        self.operations.push(Payload::Addition { payload: &payload[..], auto_generate_ids: true });
         Ok(())
     }
 
@@ -65,6 +82,10 @@ impl<'pl> DocumentOperation<'pl> {
         let mut available_docids = AvailableIds::new(&documents_ids);
         let mut docids_version_offsets = hashbrown::HashMap::new();
         let mut primary_key = None;
+        let version_attribute = VersionAttribute::new(index, rtxn)?;
+        let index_auto_generate_ids = index.auto_generate_ids(rtxn)?;
+        let document_schema = index.document_schema(rtxn)?;
+        let ingest_pipeline = index.ingest_pipeline(rtxn)?;
 
         let payload_count = operations.len();
 
@@ -78,9 +99,14 @@ impl<'pl> DocumentOperation<'pl> {
                 payload_count as u32,
             ));
 
+            let auto_generate_ids = index_auto_generate_ids
+                || matches!(operation, Payload::Addition { auto_generate_ids: true, .. });
             let mut bytes = 0;
+            let mut skipped = 0;
+            let mut generated_ids = Vec::new();
+            let mut rejected = Vec::new();
             let result = match operation {
-                Payload::Addition(payload) => extract_addition_payload_changes(
+                Payload::Addition { payload, .. } => extract_addition_payload_changes(
                     indexer,
                     index,
                     rtxn,
@@ -89,7 +115,14 @@ impl<'pl> DocumentOperation<'pl> {
                     new_fields_ids_map,
                     &mut available_docids,
                     &mut bytes,
+                    &mut skipped,
+                    auto_generate_ids,
+                    &mut generated_ids,
+                    &ingest_pipeline,
+                    document_schema.as_ref(),
+                    &mut rejected,
                     &docids_version_offsets,
+                    version_attribute.as_ref(),
                     method,
                     payload,
                 ),
@@ -105,8 +138,11 @@ impl<'pl> DocumentOperation<'pl> {
 
             let mut document_count = 0;
             let error = match result {
-                Ok(new_docids_version_offsets) => {
+                Ok(mut new_docids_version_offsets) => {
                     document_count = new_docids_version_offsets.len() as u64;
+                    for payload_operations in new_docids_version_offsets.values_mut() {
+                        payload_operations.payload_index = payload_index;
+                    }
                     // If we don't have any error then we can merge the content of this payload
                     // into to main payload. Else we just drop this payload extraction.
                     merge_version_offsets(&mut docids_version_offsets, new_docids_version_offsets);
@@ -115,7 +151,29 @@ impl<'pl> DocumentOperation<'pl> {
                 Err(Error::UserError(user_error)) => Some(user_error),
                 Err(e) => return Err(e),
             };
-            operations_stats.push(PayloadStats { document_count, bytes, error });
+            let skipped_documents = version_attribute.as_ref().map(|_| match error {
+                Some(_) => 0,
+                None => skipped,
+            });
+            let generated_document_ids = auto_generate_ids.then(|| match error {
+                Some(_) => Vec::new(),
+                None => generated_ids,
+            });
+            let rejected_documents = document_schema
+                .as_ref()
+                .filter(|schema| schema.mode == SchemaMode::Lenient)
+                .map(|_| match error {
+                    Some(_) => Vec::new(),
+                    None => rejected,
+                });
+            operations_stats.push(PayloadStats {
+                document_count,
+                bytes,
+                skipped_documents,
+                generated_document_ids,
+                rejected_documents,
+                error,
+            });
         }
 
         send_progress(Progress::from_step_substep(
@@ -133,7 +191,12 @@ impl<'pl> DocumentOperation<'pl> {
         docids_version_offsets.sort_unstable_by_key(|(_, po)| method.sort_key(&po.operations));
 
         let docids_version_offsets = docids_version_offsets.into_bump_slice();
-        Ok((DocumentOperationChanges { docids_version_offsets }, operations_stats, primary_key))
+        let computed_fields = ComputedFields::new(index, rtxn)?;
+        Ok((
+            DocumentOperationChanges { docids_version_offsets, computed_fields },
+            operations_stats,
+            primary_key,
+        ))
     }
 }
 
@@ -153,17 +216,34 @@ fn extract_addition_payload_changes<'r, 'pl: 'r>(
     new_fields_ids_map: &mut FieldsIdsMap,
     available_docids: &mut AvailableIds,
     bytes: &mut u64,
+    skipped: &mut u64,
+    auto_generate_ids: bool,
+    generated_ids: &mut Vec<String>,
+    ingest_pipeline: &[IngestProcessor],
+    document_schema: Option<&DocumentSchema>,
+    rejected: &mut Vec<String>,
     main_docids_version_offsets: &hashbrown::HashMap<&'pl str, PayloadOperations<'pl>>,
+    version_attribute: Option<&VersionAttribute>,
     method: MergeMethod,
     payload: &'pl [u8],
 ) -> Result<hashbrown::HashMap<&'pl str, PayloadOperations<'pl>>> {
     let mut new_docids_version_offsets = hashbrown::HashMap::<&str, PayloadOperations<'pl>>::new();
+    let mut version_alloc = Bump::new();
 
     let mut previous_offset = 0;
     let mut iter = Deserializer::from_slice(payload).into_iter::<&RawValue>();
     while let Some(doc) = iter.next().transpose().map_err(InternalError::SerdeJson)? {
         *bytes = previous_offset as u64;
 
+        // the documents are transformed before anything, even their id, is extracted from them
+        let (doc, transformed) = match ingest_pipeline {
+            [] => (doc, false),
+            pipeline => {
+                let partial = !matches!(method, MergeMethod::ForReplacement(_));
+                (transform_document(pipeline, doc, partial, indexer)?, true)
+            }
+        };
+
         // Only guess the primary key if it is the first document
         let retrieved_primary_key = if previous_offset == 0 {
             let doc =
@@ -179,6 +259,13 @@ fn extract_addition_payload_changes<'r, 'pl: 'r>(
 
             let (pk, _has_been_changed) = match result {
                 Ok(Ok(pk)) => pk,
+                // the documents will get their ids generated in the default primary key
+                Ok(Err(UserError::NoPrimaryKeyCandidateFound)) if auto_generate_ids => {
+                    match PrimaryKey::new_or_insert(DEFAULT_PRIMARY_KEY, new_fields_ids_map) {
+                        Ok(pk) => (pk, true),
+                        Err(user_error) => return Err(Error::UserError(user_error)),
+                    }
+                }
                 Ok(Err(user_error)) => return Err(Error::UserError(user_error)),
                 Err(error) => return Err(error),
             };
@@ -189,25 +276,90 @@ fn extract_addition_payload_changes<'r, 'pl: 'r>(
             primary_key.as_ref().unwrap()
         };
 
-        let external_id = match retrieved_primary_key.extract_fields_and_docid(
-            doc,
-            new_fields_ids_map,
-            indexer,
-        ) {
-            Ok(edi) => edi,
+        let current_offset = iter.byte_offset();
+        let (doc, content, external_id, generated) = match retrieved_primary_key
+            .extract_fields_and_docid(doc, new_fields_ids_map, indexer)
+        {
+            Ok(edi) if transformed => (doc, doc.get().as_bytes(), edi, false),
+            Ok(edi) => (doc, &payload[previous_offset..current_offset], edi, false),
+            // ids can't be generated for the several fields of a composite primary key
+            Err(Error::UserError(UserError::MissingDocumentId { .. }))
+                if auto_generate_ids
+                    && !matches!(retrieved_primary_key, PrimaryKey::Composite { .. }) =>
+            {
+                let doc = retrieved_primary_key.generate_document_id(doc, indexer)?;
+                let edi = retrieved_primary_key.extract_fields_and_docid(
+                    doc,
+                    new_fields_ids_map,
+                    indexer,
+                )?;
+                (doc, doc.get().as_bytes(), edi, true)
+            }
             Err(e) => return Err(e),
         };
 
         let external_id = external_id.to_de();
-        let current_offset = iter.byte_offset();
-        let document_offset = DocumentOffset { content: &payload[previous_offset..current_offset] };
+        let document_offset = DocumentOffset { content };
+
+        // merge patches describe changes rather than values, they can't be checked here
+        let is_patch = matches!(method, MergeMethod::ForPatches(_));
+        if let Some(schema) = document_schema.filter(|_| !is_patch) {
+            let complete = matches!(method, MergeMethod::ForReplacement(_));
+            let violations = {
+                let document =
+                    RawMap::from_raw_value(doc, &version_alloc).map_err(UserError::SerdeJson)?;
+                schema.violations(&document, complete).map_err(InternalError::SerdeJson)?
+            };
+            version_alloc.reset();
+
+            if !violations.is_empty() {
+                rejected.push(format!("document `{external_id}`: {}", violations.join(", ")));
+                if schema.mode == SchemaMode::Lenient {
+                    previous_offset = iter.byte_offset();
+                    continue;
+                }
+            }
+        }
+
+        let version = match version_attribute {
+            Some(attribute) => {
+                let version = attribute.incoming_version(external_id, doc, &version_alloc)?;
+                version_alloc.reset();
+
+                let current_version = match new_docids_version_offsets
+                    .get(external_id)
+                    .or_else(|| main_docids_version_offsets.get(external_id))
+                {
+                    Some(payload_operations) => payload_operations.version,
+                    None => match index.external_documents_ids().get(rtxn, external_id)? {
+                        Some(docid) => attribute.stored_version(index, rtxn, docid)?,
+                        None => None,
+                    },
+                };
+
+                // The document is outdated, we ignore it
+                if current_version.is_some_and(|current| version <= current) {
+                    *skipped += 1;
+                    previous_offset = iter.byte_offset();
+                    continue;
+                }
+
+                Some(version)
+            }
+            None => None,
+        };
+
+        // the generated ids are only reported for the documents that are not rejected
+        if generated {
+            generated_ids.push(external_id.to_string());
+        }
 
         match main_docids_version_offsets.get(external_id) {
             None => {
                 match index.external_documents_ids().get(rtxn, external_id) {
                     Ok(Some(docid)) => match new_docids_version_offsets.entry(external_id) {
                         Entry::Occupied(mut entry) => {
-                            entry.get_mut().push_addition(document_offset)
+                            entry.get_mut().push_addition(document_offset, version)
                         }
                         Entry::Vacant(entry) => {
                             entry.insert(PayloadOperations::new_addition(
@@ -215,12 +367,13 @@ fn extract_addition_payload_changes<'r, 'pl: 'r>(
                                 docid,
                                 false, // is new
                                 document_offset,
+                                version,
                             ));
                         }
                     },
                     Ok(None) => match new_docids_version_offsets.entry(external_id) {
                         Entry::Occupied(mut entry) => {
-                            entry.get_mut().push_addition(document_offset)
+                            entry.get_mut().push_addition(document_offset, version)
                         }
                         Entry::Vacant(entry) => {
                             let docid = match available_docids.next() {
@@ -232,6 +385,7 @@ fn extract_addition_payload_changes<'r, 'pl: 'r>(
                                 docid,
                                 true, // is new
                                 document_offset,
+                                version,
                             ));
                         }
                     },
@@ -239,13 +393,16 @@ fn extract_addition_payload_changes<'r, 'pl: 'r>(
                 }
             }
             Some(payload_operations) => match new_docids_version_offsets.entry(external_id) {
-                Entry::Occupied(mut entry) => entry.get_mut().push_addition(document_offset),
+                Entry::Occupied(mut entry) => {
+                    entry.get_mut().push_addition(document_offset, version)
+                }
                 Entry::Vacant(entry) => {
                     entry.insert(PayloadOperations::new_addition(
                         method,
                         payload_operations.docid,
                         payload_operations.is_new,
                         document_offset,
+                        version,
                     ));
                 }
             },
@@ -254,9 +411,26 @@ fn extract_addition_payload_changes<'r, 'pl: 'r>(
         previous_offset = iter.byte_offset();
     }
 
+    if !rejected.is_empty() && document_schema.is_some_and(|s| s.mode == SchemaMode::Strict) {
+        return Err(UserError::InvalidDocumentSchema { reports: std::mem::take(rejected) }.into());
+    }
+
     Ok(new_docids_version_offsets)
 }
 
+/// Returns a copy of the document on which the ingest pipeline has been applied.
+fn transform_document<'pl>(
+    pipeline: &[IngestProcessor],
+    document: &RawValue,
+    partial: bool,
+    bump: &'pl Bump,
+) -> Result<&'pl RawValue> {
+    let mut document: Object =
+        serde_json::from_str(document.get()).map_err(InternalError::SerdeJson)?;
+    apply_ingest_pipeline(pipeline, &mut document, partial);
+    object_to_raw_value(&document, bump)
+}
+
 fn extract_deletion_payload_changes<'s, 'pl: 's>(
     index: &Index,
     rtxn: &RoTxn,
@@ -326,7 +500,12 @@ fn merge_version_offsets<'s, 'pl>(
 
     for (key, new_payload) in new {
         match main.entry(key) {
-            Entry::Occupied(mut entry) => entry.get_mut().append_operations(new_payload.operations),
+            Entry::Occupied(mut entry) => {
+                let payload_operations = entry.get_mut();
+                payload_operations.payload_index = new_payload.payload_index;
+                payload_operations.version = new_payload.version;
+                payload_operations.append_operations(new_payload.operations)
+            }
             Entry::Vacant(entry) => {
                 entry.insert(new_payload);
             }
@@ -353,13 +532,20 @@ impl<'pl> DocumentChanges<'pl> for DocumentOperationChanges<'pl> {
         'pl: 'doc,
     {
         let (external_doc, payload_operations) = item;
-        payload_operations.merge_method.merge(
+        let change = payload_operations.merge_method.merge(
             payload_operations.docid,
             external_doc,
             payload_operations.is_new,
-            &context.doc_alloc,
+            context,
             &payload_operations.operations[..],
-        )
+        )?;
+
+        match (&self.computed_fields, change) {
+            (Some(computed_fields), Some(change)) => {
+                computed_fields.apply_on_change(change, context).map(Some)
+            }
+            (_, change) => Ok(change),
+        }
     }
 
     fn len(&self) -> usize {
@@ -369,19 +555,126 @@ impl<'pl> DocumentChanges<'pl> for DocumentOperationChanges<'pl> {
 
 pub struct DocumentOperationChanges<'pl> {
     docids_version_offsets: &'pl [(&'pl str, PayloadOperations<'pl>)],
+    computed_fields: Option<ComputedFields>,
+}
+
+impl<'pl> DocumentOperationChanges<'pl> {
+    /// Returns the external id of the documents along with the kind of change that will be
+    /// applied to them and the index of the last payload that modified them.
+    ///
+    /// The deletions of documents that do not exist in the database are skipped.
+    pub fn changelog(&self) -> impl Iterator<Item = (&'pl str, DocumentChangeKind, usize)> + 'pl {
+        self.docids_version_offsets.iter().filter_map(|(external_id, payload_operations)| {
+            let kind = match (payload_operations.operations.last(), payload_operations.is_new) {
+                (Some(InnerDocOp::Deletion), true) | (None, _) => return None,
+                (Some(InnerDocOp::Deletion), false) => DocumentChangeKind::Deletion,
+                (Some(InnerDocOp::Addition(_)), true) => DocumentChangeKind::Addition,
+                (Some(InnerDocOp::Addition(_)), false) => DocumentChangeKind::Update,
+            };
+            Some((*external_id, kind, payload_operations.payload_index))
+        })
+    }
 }
 
 pub enum Payload<'pl> {
-    Addition(&'pl [u8]),
+    Addition { payload: &'pl [u8], auto_generate_ids: bool },
     Deletion(&'pl [&'pl str]),
 }
 
 pub struct PayloadStats {
     pub bytes: u64,
     pub document_count: u64,
+    /// The number of documents ignored because their version was not greater than the
+    /// current one, only set when the index has a version attribute.
+    pub skipped_documents: Option<u64>,
+    /// The ids generated for the documents without one, only set when the index
+    /// automatically generates the ids.
+    pub generated_document_ids: Option<Vec<String>>,
+    /// The reasons why the documents were not indexed, one per document, only set
+    /// when the index has a lenient document schema.
+    pub rejected_documents: Option<Vec<String>>,
     pub error: Option<UserError>,
 }
 
+/// The attribute used to version the documents of an index.
+///
+/// An incoming document is only applied if its version is strictly greater than the version
+/// of the stored document or of the last document sent with the same id.
+struct VersionAttribute<'t> {
+    /// The name of the attribute, a dotted name is resolved through the nested objects.
+    name: &'t str,
+    /// The fields ids map of the database, to find the attribute in the stored documents.
+    fields_ids_map: FieldsIdsMap,
+}
+
+impl<'t> VersionAttribute<'t> {
+    fn new(index: &Index, rtxn: &'t RoTxn) -> Result<Option<Self>> {
+        match index.version_attribute(rtxn)? {
+            Some(name) => {
+                let fields_ids_map = index.fields_ids_map(rtxn)?;
+                Ok(Some(VersionAttribute { name, fields_ids_map }))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Extracts the version of a document of the payload, it must be a positive integer.
+    fn incoming_version(&self, external_id: &str, doc: &RawValue, alloc: &Bump) -> Result<u64> {
+        let doc = RawMap::from_raw_value(doc, alloc).map_err(UserError::SerdeJson)?;
+        let mut values = fetch_nested_values(self.name, |name| match doc.get(name) {
+            Some(value) => {
+                Ok(Some(serde_json::from_str(value.get()).map_err(InternalError::SerdeJson)?))
+            }
+            None => Ok(None),
+        })?;
+        if let Some(version) = single_version(&values) {
+            return Ok(version);
+        }
+
+        let value = match values.len() {
+            0 => None,
+            1 => values.pop(),
+            _ => Some(Value::Array(values)),
+        };
+        Err(UserError::InvalidDocumentVersion {
+            document_id: external_id.to_string(),
+            version_attribute: self.name.to_string(),
+            value,
+        }
+        .into())
+    }
+
+    /// Returns the version of a stored document.
+    ///
+    /// Documents indexed before the version attribute was set may not have a valid version,
+    /// they are considered unversioned and are replaced by any incoming version.
+    fn stored_version(
+        &self,
+        index: &Index,
+        rtxn: &RoTxn,
+        docid: DocumentId,
+    ) -> Result<Option<u64>> {
+        let Some(document) = index.documents.get(rtxn, &docid)? else { return Ok(None) };
+        let values = fetch_nested_values(self.name, |name| {
+            match self.fields_ids_map.id(name).and_then(|fid| document.get(fid)) {
+                Some(value) => {
+                    Ok(Some(serde_json::from_slice(value).map_err(InternalError::SerdeJson)?))
+                }
+                None => Ok(None),
+            }
+        })?;
+        Ok(single_version(&values))
+    }
+}
+
+/// Returns the version if the attribute holds a single positive integer.
+fn single_version(values: &[Value]) -> Option<u64> {
+    match values {
+        [value] => value.as_u64(),
+        _ => None,
+    }
+}
+
 pub struct PayloadOperations<'pl> {
     /// The internal document id of the document.
     pub docid: DocumentId,
@@ -389,13 +682,24 @@ pub struct PayloadOperations<'pl> {
     pub is_new: bool,
     /// The operations to perform, in order, on this document.
     pub operations: Vec<InnerDocOp<'pl>>,
+    /// The index of the last payload that modified this document.
+    pub payload_index: usize,
+    /// The version of the last document sent, only set when the index has a version attribute.
+    pub version: Option<u64>,
     /// The merge method we are using to merge payloads and documents.
     merge_method: MergeMethod,
 }
 
 impl<'pl> PayloadOperations<'pl> {
     fn new_deletion(merge_method: MergeMethod, docid: DocumentId, is_new: bool) -> Self {
-        Self { docid, is_new, operations: vec![InnerDocOp::Deletion], merge_method }
+        Self {
+            docid,
+            is_new,
+            operations: vec![InnerDocOp::Deletion],
+            payload_index: 0,
+            version: None,
+            merge_method,
+        }
     }
 
     fn new_addition(
@@ -403,22 +707,32 @@ impl<'pl> PayloadOperations<'pl> {
         docid: DocumentId,
         is_new: bool,
         offset: DocumentOffset<'pl>,
+        version: Option<u64>,
     ) -> Self {
-        Self { docid, is_new, operations: vec![InnerDocOp::Addition(offset)], merge_method }
+        Self {
+            docid,
+            is_new,
+            operations: vec![InnerDocOp::Addition(offset)],
+            payload_index: 0,
+            version,
+            merge_method,
+        }
     }
 }
 
 impl<'pl> PayloadOperations<'pl> {
-    fn push_addition(&mut self, offset: DocumentOffset<'pl>) {
+    fn push_addition(&mut self, offset: DocumentOffset<'pl>, version: Option<u64>) {
         if self.merge_method.useless_previous_changes() {
             self.operations.clear();
         }
-        self.operations.push(InnerDocOp::Addition(offset))
+        self.operations.push(InnerDocOp::Addition(offset));
+        self.version = version;
     }
 
     fn push_deletion(&mut self) {
         self.operations.clear();
         self.operations.push(InnerDocOp::Deletion);
+        self.version = None;
     }
 
     fn append_operations(&mut self, mut operations: Vec<InnerDocOp<'pl>>) {
@@ -451,12 +765,12 @@ trait MergeChanges {
     /// Returns a key that is used to order the payloads the right way.
     fn sort_key(&self, docops: &[InnerDocOp]) -> usize;
 
-    fn merge<'doc>(
+    fn merge<'doc, T: MostlySend + 'doc>(
         &self,
         docid: DocumentId,
         external_docid: &'doc str,
         is_new: bool,
-        doc_alloc: &'doc Bump,
+        context: &'doc DocumentChangeContext<T>,
         operations: &'doc [InnerDocOp],
     ) -> Result<Option<DocumentChange<'doc>>>;
 }
@@ -465,6 +779,7 @@ trait MergeChanges {
 enum MergeMethod {
     ForReplacement(MergeDocumentForReplacement),
     ForUpdates(MergeDocumentForUpdates),
+    ForPatches(MergeDocumentForPatches),
 }
 
 impl MergeChanges for MergeMethod {
@@ -472,6 +787,7 @@ impl MergeChanges for MergeMethod {
         match self {
             MergeMethod::ForReplacement(merge) => merge.useless_previous_changes(),
             MergeMethod::ForUpdates(merge) => merge.useless_previous_changes(),
+            MergeMethod::ForPatches(merge) => merge.useless_previous_changes(),
         }
     }
 
@@ -479,23 +795,27 @@ impl MergeChanges for MergeMethod {
         match self {
             MergeMethod::ForReplacement(merge) => merge.sort_key(docops),
             MergeMethod::ForUpdates(merge) => merge.sort_key(docops),
+            MergeMethod::ForPatches(merge) => merge.sort_key(docops),
         }
     }
 
-    fn merge<'doc>(
+    fn merge<'doc, T: MostlySend + 'doc>(
         &self,
         docid: DocumentId,
         external_docid: &'doc str,
         is_new: bool,
-        doc_alloc: &'doc Bump,
+        context: &'doc DocumentChangeContext<T>,
         operations: &'doc [InnerDocOp],
     ) -> Result<Option<DocumentChange<'doc>>> {
         match self {
             MergeMethod::ForReplacement(merge) => {
-                merge.merge(docid, external_docid, is_new, doc_alloc, operations)
+                merge.merge(docid, external_docid, is_new, context, operations)
             }
             MergeMethod::ForUpdates(merge) => {
-                merge.merge(docid, external_docid, is_new, doc_alloc, operations)
+                merge.merge(docid, external_docid, is_new, context, operations)
+            }
+            MergeMethod::ForPatches(merge) => {
+                merge.merge(docid, external_docid, is_new, context, operations)
             }
         }
     }
@@ -510,6 +830,9 @@ impl From<IndexDocumentsMethod> for MergeMethod {
             IndexDocumentsMethod::UpdateDocuments => {
                 MergeMethod::ForUpdates(MergeDocumentForUpdates)
             }
+            IndexDocumentsMethod::PatchDocuments => {
+                MergeMethod::ForPatches(MergeDocumentForPatches)
+            }
         }
     }
 }
@@ -534,14 +857,15 @@ impl MergeChanges for MergeDocumentForReplacement {
     /// Returns only the most recent version of a document based on the updates from the payloads.
     ///
     /// This function is only meant to be used when doing a replacement and not an update.
-    fn merge<'doc>(
+    fn merge<'doc, T: MostlySend + 'doc>(
         &self,
         docid: DocumentId,
         external_doc: &'doc str,
         is_new: bool,
-        doc_alloc: &'doc Bump,
+        context: &'doc DocumentChangeContext<T>,
         operations: &'doc [InnerDocOp],
     ) -> Result<Option<DocumentChange<'doc>>> {
+        let doc_alloc = &context.doc_alloc;
         match operations.last() {
             Some(InnerDocOp::Addition(DocumentOffset { content })) => {
                 let document = serde_json::from_slice(content).unwrap();
@@ -597,14 +921,15 @@ impl MergeChanges for MergeDocumentForUpdates {
     /// in the grenad update files and merges them to generate a new boxed obkv.
     ///
     /// This function is only meant to be used when doing an update and not a replacement.
-    fn merge<'doc>(
+    fn merge<'doc, T: MostlySend + 'doc>(
         &self,
         docid: DocumentId,
         external_docid: &'doc str,
         is_new: bool,
-        doc_alloc: &'doc Bump,
+        context: &'doc DocumentChangeContext<T>,
         operations: &'doc [InnerDocOp],
     ) -> Result<Option<DocumentChange<'doc>>> {
+        let doc_alloc = &context.doc_alloc;
         if operations.is_empty() {
             unreachable!("We must not have empty set of operations on a document");
         }
@@ -669,3 +994,81 @@ impl MergeChanges for MergeDocumentForUpdates {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy)]
+struct MergeDocumentForPatches;
+
+impl MergeChanges for MergeDocumentForPatches {
+    fn useless_previous_changes(&self) -> bool {
+        false
+    }
+
+    /// Reorders to read the first changes first as the patches must be applied in order.
+    fn sort_key(&self, docops: &[InnerDocOp]) -> usize {
+        MergeDocumentForUpdates.sort_key(docops)
+    }
+
+    /// Reads the previous version of a document from the database and applies the patches
+    /// from the update files on it, in order, to generate the new version of the document.
+    ///
+    /// This function is only meant to be used when patching documents.
+    fn merge<'doc, T: MostlySend + 'doc>(
+        &self,
+        docid: DocumentId,
+        external_docid: &'doc str,
+        is_new: bool,
+        context: &'doc DocumentChangeContext<T>,
+        operations: &'doc [InnerDocOp],
+    ) -> Result<Option<DocumentChange<'doc>>> {
+        let DocumentChangeContext { index, db_fields_ids_map, rtxn, doc_alloc, .. } = context;
+
+        let last_deletion = operations.iter().rposition(|op| matches!(op, InnerDocOp::Deletion));
+        let patches = &operations[last_deletion.map_or(0, |i| i + 1)..];
+
+        if patches.is_empty() {
+            return if is_new {
+                Ok(None)
+            } else {
+                let deletion = Deletion::create(docid, external_docid);
+                Ok(Some(DocumentChange::Deletion(deletion)))
+            };
+        }
+
+        // A patch applied after a deletion is applied on an empty document
+        let mut document = if is_new || last_deletion.is_some() {
+            Object::new()
+        } else {
+            all_obkv_to_json(index.document(rtxn, docid)?, db_fields_ids_map)?
+        };
+
+        for patch in patches {
+            let DocumentOffset { content } = match patch {
+                InnerDocOp::Addition(offset) => offset,
+                InnerDocOp::Deletion => unreachable!("Deletion in document operations"),
+            };
+            let patch = serde_json::from_slice(content).map_err(InternalError::SerdeJson)?;
+            apply_merge_patch(&mut document, patch).map_err(|error| {
+                UserError::InvalidDocumentPatch { document_id: external_docid.to_string(), error }
+            })?;
+        }
+
+        let document = object_to_raw_value(&document, doc_alloc)?;
+        let document =
+            RawMap::from_raw_value(document, doc_alloc).map_err(InternalError::SerdeJson)?;
+
+        if is_new {
+            Ok(Some(DocumentChange::Insertion(Insertion::create(
+                docid,
+                external_docid,
+                Versions::single(document),
+            ))))
+        } else {
+            Ok(Some(DocumentChange::Update(Update::create(
+                docid,
+                external_docid,
+                Versions::single(document),
+                true, // the patched document contains all the fields
+            ))))
+        }
+    }
+}
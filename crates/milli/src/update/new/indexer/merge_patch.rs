@@ -0,0 +1,200 @@
+//! Applies JSON merge patches ([RFC 7396](https://www.rfc-editor.org/rfc/rfc7396)) on documents.
+//!
+//! On top of the standard merge semantics, where objects are merged recursively and `null`
+//! removes a field, an object containing a single operator key applies the operator on the
+//! value of the document instead of replacing it:
+//! - `{ "$append": value }` pushes the value, or all the values of an array, at the end of an array,
+//! - `{ "$remove": value }` removes all the occurrences of the value, or of the values of an array, from an array,
+//! - `{ "$increment": number }` adds the number to the current value, a negative number decrements it.
+
+use serde_json::{Map, Number, Value};
+use thiserror::Error;
+
+const APPEND: &str = "$append";
+const REMOVE: &str = "$remove";
+const INCREMENT: &str = "$increment";
+
+#[derive(Error, Debug)]
+pub enum PatchError {
+    #[error("cannot apply `{operator}` on the `{path}` field as it is not an array")]
+    NotAnArray { operator: &'static str, path: String },
+    #[error("cannot increment the `{path}` field as it is not a number")]
+    NotANumber { path: String },
+    #[error("`$increment` expects a number but received `{value}`")]
+    InvalidIncrement { value: Value },
+    #[error("cannot increment the `{path}` field as the result is not a finite number")]
+    NonFiniteNumber { path: String },
+}
+
+/// Applies the patch on the document.
+pub fn apply_merge_patch(
+    document: &mut Map<String, Value>,
+    patch: Map<String, Value>,
+) -> Result<(), PatchError> {
+    merge_object(document, patch, "")
+}
+
+fn merge_object(
+    target: &mut Map<String, Value>,
+    patch: Map<String, Value>,
+    path: &str,
+) -> Result<(), PatchError> {
+    for (key, value) in patch {
+        let path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+        if value.is_null() {
+            target.remove(&key);
+        } else if let Some(current) = target.get_mut(&key) {
+            merge_value(current, value, &path)?;
+        } else {
+            let mut current = Value::Null;
+            merge_value(&mut current, value, &path)?;
+            // a `$remove` on a missing field must not create it
+            if !current.is_null() {
+                target.insert(key, current);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn merge_value(target: &mut Value, patch: Value, path: &str) -> Result<(), PatchError> {
+    match patch {
+        Value::Object(patch) => match operator(&patch) {
+            Some((operator, argument)) => apply_operator(target, operator, argument, path),
+            None => {
+                if !target.is_object() {
+                    *target = Value::Object(Map::new());
+                }
+                let Value::Object(target) = target else { unreachable!() };
+                merge_object(target, patch, path)
+            }
+        },
+        patch => {
+            *target = patch;
+            Ok(())
+        }
+    }
+}
+
+/// Returns the operator and its argument if the object is an operator.
+fn operator(patch: &Map<String, Value>) -> Option<(&'static str, &Value)> {
+    if patch.len() != 1 {
+        return None;
+    }
+    let (key, value) = patch.iter().next()?;
+    [APPEND, REMOVE, INCREMENT].into_iter().find(|op| *op == key.as_str()).map(|op| (op, value))
+}
+
+fn apply_operator(
+    target: &mut Value,
+    operator: &'static str,
+    argument: &Value,
+    path: &str,
+) -> Result<(), PatchError> {
+    let arguments = match argument {
+        Value::Array(values) => values.as_slice(),
+        value => std::slice::from_ref(value),
+    };
+
+    match operator {
+        APPEND => {
+            if target.is_null() {
+                *target = Value::Array(Vec::new());
+            }
+            let Value::Array(array) = target else {
+                return Err(PatchError::NotAnArray { operator, path: path.to_string() });
+            };
+            array.extend(arguments.iter().cloned());
+        }
+        REMOVE => match target {
+            Value::Null => (),
+            Value::Array(array) => array.retain(|value| !arguments.contains(value)),
+            _ => return Err(PatchError::NotAnArray { operator, path: path.to_string() }),
+        },
+        INCREMENT => {
+            let Value::Number(increment) = argument else {
+                return Err(PatchError::InvalidIncrement { value: argument.clone() });
+            };
+            let current = match target {
+                Value::Null => Number::from(0),
+                Value::Number(number) => number.clone(),
+                _ => return Err(PatchError::NotANumber { path: path.to_string() }),
+            };
+            match add(&current, increment) {
+                Some(sum) => *target = Value::Number(sum),
+                None => return Err(PatchError::NonFiniteNumber { path: path.to_string() }),
+            }
+        }
+        _ => unreachable!("unknown patch operator {operator}"),
+    }
+
+    Ok(())
+}
+
+/// Adds two numbers, keeping integers as long as the result fits in an `i64`.
+/// Returns `None` if the result is not a finite number.
+fn add(left: &Number, right: &Number) -> Option<Number> {
+    match (left.as_i64(), right.as_i64()) {
+        (Some(left), Some(right)) => match left.checked_add(right) {
+            Some(sum) => Some(Number::from(sum)),
+            None => Number::from_f64(left as f64 + right as f64),
+        },
+        _ => {
+            Number::from_f64(left.as_f64().unwrap_or_default() + right.as_f64().unwrap_or_default())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn patch(document: Value, patch: Value) -> Result<Value, PatchError> {
+        let Value::Object(mut document) = document else { panic!() };
+        let Value::Object(patch) = patch else { panic!() };
+        apply_merge_patch(&mut document, patch)?;
+        Ok(Value::Object(document))
+    }
+
+    #[test]
+    fn merge_nested_objects() {
+        let document = json!({ "id": 1, "meta": { "author": "Jean", "year": 1990 }, "old": true });
+        let result = patch(document, json!({ "meta": { "year": 1991 }, "old": null })).unwrap();
+        assert_eq!(result, json!({ "id": 1, "meta": { "author": "Jean", "year": 1991 } }));
+    }
+
+    #[test]
+    fn array_operators() {
+        let document = json!({ "tags": ["a", "b", "a"] });
+        let result = patch(document, json!({ "tags": { "$append": ["c", "d"] } })).unwrap();
+        assert_eq!(result, json!({ "tags": ["a", "b", "a", "c", "d"] }));
+
+        let result = patch(result, json!({ "tags": { "$remove": "a" } })).unwrap();
+        assert_eq!(result, json!({ "tags": ["b", "c", "d"] }));
+
+        let result = patch(json!({}), json!({ "tags": { "$append": "a" } })).unwrap();
+        assert_eq!(result, json!({ "tags": ["a"] }));
+
+        let result = patch(json!({ "id": 1 }), json!({ "tags": { "$remove": "a" } })).unwrap();
+        assert_eq!(result, json!({ "id": 1 }));
+
+        let error = patch(json!({ "tags": "a" }), json!({ "tags": { "$append": "b" } }));
+        insta::assert_snapshot!(error.unwrap_err(), @"cannot apply `$append` on the `tags` field as it is not an array");
+    }
+
+    #[test]
+    fn increment() {
+        let document = json!({ "stats": { "views": 10, "rating": 4.5 } });
+        let patch_stats = json!({ "stats": { "views": { "$increment": 2 }, "rating": { "$increment": -0.5 }, "likes": { "$increment": 1 } } });
+        let result = patch(document, patch_stats).unwrap();
+        assert_eq!(result, json!({ "stats": { "views": 12, "rating": 4.0, "likes": 1 } }));
+
+        let error = patch(json!({ "views": "10" }), json!({ "views": { "$increment": 1 } }));
+        insta::assert_snapshot!(error.unwrap_err(), @"cannot increment the `views` field as it is not a number");
+
+        let error = patch(json!({ "score": 1e308 }), json!({ "score": { "$increment": 1e308 } }));
+        insta::assert_snapshot!(error.unwrap_err(), @"cannot increment the `score` field as the result is not a finite number");
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::{OnceLock, RwLock};
 use std::thread::{self, Builder};
 
 use big_s::S;
+pub use computed_fields::ComputedFields;
 use document_changes::{extract, DocumentChanges, IndexingContext, Progress};
 pub use document_deletion::DocumentDeletion;
 pub use document_operation::{DocumentOperation, PayloadStats};
@@ -10,6 +11,7 @@ use hashbrown::HashMap;
 use heed::types::{Bytes, DecodeIgnore, Str};
 use heed::{RoTxn, RwTxn};
 use itertools::{merge_join_by, EitherOrBoth};
+pub use merge_patch::PatchError;
 pub use partial_dump::PartialDump;
 use rand::SeedableRng as _;
 use raw_collections::RawMap;
@@ -27,10 +29,11 @@ use super::words_prefix_docids::{
     compute_word_prefix_docids, compute_word_prefix_fid_docids, compute_word_prefix_position_docids,
 };
 use super::StdResult;
-use crate::documents::{PrimaryKey, DEFAULT_PRIMARY_KEY};
+use crate::documents::{validate_primary_key, PrimaryKey, DEFAULT_PRIMARY_KEY};
 use crate::facet::FacetType;
 use crate::fields_ids_map::metadata::{FieldIdMapWithMetadata, MetadataBuilder};
 use crate::index::main_key::{WORDS_FST_KEY, WORDS_PREFIXES_FST_KEY};
+use crate::phonetic::{phonetic_words_snapshot, update_phonetic_code_words};
 use crate::proximity::ProximityPrecision;
 use crate::update::del_add::DelAdd;
 use crate::update::new::extract::EmbeddingExtractor;
@@ -45,10 +48,12 @@ use crate::{
     ThreadPoolNoAbortBuilder, UserError,
 };
 
+mod computed_fields;
 pub(crate) mod de;
 pub mod document_changes;
 mod document_deletion;
 mod document_operation;
+mod merge_patch;
 mod partial_dump;
 mod update_by_function;
 
@@ -96,6 +101,7 @@ where
 
     let mut field_distribution = index.field_distribution(wtxn)?;
     let mut document_ids = index.documents_ids(wtxn)?;
+    let phonetic_words_snapshot = phonetic_words_snapshot(index, wtxn)?;
 
     thread::scope(|s| -> Result<()> {
         let indexer_span = tracing::Span::current();
@@ -457,6 +463,9 @@ where
     let mut inner_index_settings = InnerIndexSettings::from_index(index, wtxn, Some(embedders))?;
     inner_index_settings.recompute_facets(wtxn, index)?;
     inner_index_settings.recompute_searchables(wtxn, index)?;
+    if let Some(previous_words_fst) = phonetic_words_snapshot {
+        update_phonetic_code_words(index, wtxn, previous_words_fst)?;
+    }
     index.put_field_distribution(wtxn, &field_distribution)?;
     index.put_documents_ids(wtxn, &document_ids)?;
     index.set_updated_at(wtxn, &OffsetDateTime::now_utc())?;
@@ -639,6 +648,9 @@ pub fn retrieve_or_guess_primary_key<'a>(
         // did we request a primary key in the operation?
         let primary_key = if let Some(primary_key_from_op) = primary_key_from_op {
             // set primary key from operation
+            if let Err(err) = validate_primary_key(primary_key_from_op) {
+                return Ok(Err(err));
+            }
             primary_key_from_op
         } else {
             // guess primary key
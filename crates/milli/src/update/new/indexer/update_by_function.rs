@@ -1,19 +1,24 @@
+use std::cell::RefCell;
+
+use heed::RoTxn;
 use raw_collections::RawMap;
 use rayon::iter::IndexedParallelIterator;
 use rayon::slice::ParallelSlice as _;
 use rhai::{Dynamic, Engine, OptimizationLevel, Scope, AST};
 use roaring::RoaringBitmap;
 
+use super::computed_fields::ComputedFields;
 use super::document_changes::DocumentChangeContext;
 use super::DocumentChanges;
 use crate::documents::Error::InvalidDocumentFormat;
 use crate::documents::PrimaryKey;
 use crate::error::{FieldIdMapMissingEntry, InternalError};
+use crate::index::DocumentChangeKind;
 use crate::update::new::document::Versions;
 use crate::update::new::ref_cell_ext::RefCellExt as _;
-use crate::update::new::thread_local::MostlySend;
+use crate::update::new::thread_local::{FullySend, MostlySend, ThreadLocal};
 use crate::update::new::{Deletion, DocumentChange, KvReaderFieldId, Update};
-use crate::{all_obkv_to_json, Error, FieldsIdsMap, Object, Result, UserError};
+use crate::{all_obkv_to_json, Error, FieldsIdsMap, Index, Object, Result, UserError};
 
 pub struct UpdateByFunction {
     documents: RoaringBitmap,
@@ -29,6 +34,10 @@ pub struct UpdateByFunctionChanges<'doc> {
     // It is sad that the RoaringBitmap doesn't
     // implement IndexedParallelIterator
     documents: Vec<u32>,
+    /// The external ids of the edited and deleted documents, collected by each thread
+    /// and only when the changelog of the index is enabled.
+    changelog: Option<ThreadLocal<FullySend<RefCell<Vec<(String, DocumentChangeKind)>>>>>,
+    computed_fields: Option<ComputedFields>,
 }
 
 impl UpdateByFunction {
@@ -38,23 +47,13 @@ impl UpdateByFunction {
 
     pub fn into_changes<'index>(
         self,
+        index: &Index,
+        rtxn: &RoTxn,
         primary_key: &'index PrimaryKey,
     ) -> Result<UpdateByFunctionChanges<'index>> {
         let Self { documents, context, code } = self;
 
-        // Setup the security and limits of the Engine
-        let mut engine = Engine::new();
-        engine.set_optimization_level(OptimizationLevel::Full);
-        engine.set_max_call_levels(1000);
-        // It is an arbitrary value. We need to let users define this in the settings.
-        engine.set_max_operations(1_000_000);
-        engine.set_max_variables(1000);
-        engine.set_max_functions(30);
-        engine.set_max_expr_depths(100, 1000);
-        engine.set_max_string_size(1024 * 1024 * 1024); // 1 GiB
-        engine.set_max_array_size(10_000);
-        engine.set_max_map_size(10_000);
-
+        let engine = limited_engine();
         let ast = engine.compile(code).map_err(UserError::DocumentEditionCompilationError)?;
         let context = match context {
             Some(context) => {
@@ -62,6 +61,8 @@ impl UpdateByFunction {
             }
             None => None,
         };
+        let computed_fields = ComputedFields::new(index, rtxn)?;
+        let changelog = index.changelog(rtxn)?.then(ThreadLocal::new);
 
         Ok(UpdateByFunctionChanges {
             primary_key,
@@ -69,10 +70,27 @@ impl UpdateByFunction {
             ast,
             context,
             documents: documents.into_iter().collect(),
+            changelog,
+            computed_fields,
         })
     }
 }
 
+impl UpdateByFunctionChanges<'_> {
+    /// Returns the external id of the documents edited or deleted by the function
+    /// along with the kind of change that was applied to them, only when the
+    /// changelog of the index is enabled.
+    pub fn into_changelog(self) -> Vec<(String, DocumentChangeKind)> {
+        self.changelog.into_iter().flatten().flat_map(|changes| changes.0.into_inner()).collect()
+    }
+
+    fn record_change(&self, external_id: &str, kind: DocumentChangeKind) {
+        if let Some(changelog) = &self.changelog {
+            changelog.get_or_default().0.borrow_mut().push((external_id.to_string(), kind));
+        }
+    }
+}
+
 impl<'index> DocumentChanges<'index> for UpdateByFunctionChanges<'index> {
     type Item = u32;
 
@@ -127,10 +145,13 @@ impl<'index> DocumentChanges<'index> for UpdateByFunctionChanges<'index> {
 
         match scope.remove::<Dynamic>("doc") {
             // If the "doc" variable has been set to (), we effectively delete the document.
-            Some(doc) if doc.is_unit() => Ok(Some(DocumentChange::Deletion(Deletion::create(
-                docid,
-                doc_alloc.alloc_str(&document_id),
-            )))),
+            Some(doc) if doc.is_unit() => {
+                self.record_change(&document_id, DocumentChangeKind::Deletion);
+                Ok(Some(DocumentChange::Deletion(Deletion::create(
+                    docid,
+                    doc_alloc.alloc_str(&document_id),
+                ))))
+            }
             None => unreachable!("missing doc variable from the Rhai scope"),
             Some(new_document) => match new_document.try_cast() {
                 Some(new_rhai_document) => {
@@ -162,13 +183,20 @@ impl<'index> DocumentChanges<'index> for UpdateByFunctionChanges<'index> {
                         } else {
                             let raw_new_doc = RawMap::from_raw_value(raw_new_doc, doc_alloc)
                                 .map_err(InternalError::SerdeJson)?;
+                            self.record_change(&document_id, DocumentChangeKind::Update);
 
-                            Ok(Some(DocumentChange::Update(Update::create(
+                            let change = DocumentChange::Update(Update::create(
                                 docid,
                                 new_document_id,
                                 Versions::single(raw_new_doc),
                                 true, // It is like document replacement
-                            ))))
+                            ));
+                            match &self.computed_fields {
+                                Some(computed_fields) => {
+                                    computed_fields.apply_on_change(change, context).map(Some)
+                                }
+                                None => Ok(Some(change)),
+                            }
                         }
                     } else {
                         Ok(None)
@@ -184,6 +212,22 @@ impl<'index> DocumentChanges<'index> for UpdateByFunctionChanges<'index> {
     }
 }
 
+/// Returns an engine with the security and limits used to run the user scripts.
+pub(super) fn limited_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.set_optimization_level(OptimizationLevel::Full);
+    engine.set_max_call_levels(1000);
+    // It is an arbitrary value. We need to let users define this in the settings.
+    engine.set_max_operations(1_000_000);
+    engine.set_max_variables(1000);
+    engine.set_max_functions(30);
+    engine.set_max_expr_depths(100, 1000);
+    engine.set_max_string_size(1024 * 1024 * 1024); // 1 GiB
+    engine.set_max_array_size(10_000);
+    engine.set_max_map_size(10_000);
+    engine
+}
+
 fn obkv_to_rhaimap(obkv: &KvReaderFieldId, fields_ids_map: &FieldsIdsMap) -> Result<rhai::Map> {
     let all_keys = obkv.iter().map(|(k, _v)| k).collect::<Vec<_>>();
     let map: Result<rhai::Map> = all_keys
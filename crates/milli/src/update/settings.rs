@@ -15,14 +15,19 @@ use super::del_add::DelAddOperation;
 use super::index_documents::{IndexDocumentsConfig, Transform};
 use super::IndexerConfig;
 use crate::criterion::Criterion;
+use crate::document_schema::DocumentSchema;
+use crate::documents::validate_primary_key;
 use crate::error::UserError;
 use crate::index::{
     IndexEmbeddingConfig, DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS,
 };
+use crate::ingest_pipeline::IngestProcessor;
 use crate::order_by_map::OrderByMap;
+use crate::phonetic::{build_phonetic_code_words, PhoneticAlgorithm};
 use crate::prompt::default_max_bytes;
 use crate::proximity::ProximityPrecision;
 use crate::update::index_documents::IndexDocumentsMethod;
+use crate::update::new::indexer::ComputedFields;
 use crate::update::{IndexDocuments, UpdateIndexingStep};
 use crate::vector::parsed_vectors::RESERVED_VECTORS_FIELD_NAME;
 use crate::vector::settings::{
@@ -30,7 +35,9 @@ use crate::vector::settings::{
     WriteBackToDocuments,
 };
 use crate::vector::{Embedder, EmbeddingConfig, EmbeddingConfigs};
-use crate::{FieldId, FieldsIdsMap, Index, LocalizedAttributesRule, LocalizedFieldIds, Result};
+use crate::{
+    FieldId, FieldsIdsMap, Index, LocalizedAttributesRule, LocalizedFieldIds, Result, Weight,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub enum Setting<T> {
@@ -153,6 +160,8 @@ pub struct Settings<'a, 't, 'i> {
     indexer_config: &'a IndexerConfig,
 
     searchable_fields: Setting<Vec<String>>,
+    /// Explicit weights of the searchable attributes, replacing their positional importance.
+    searchable_attributes_weights: Setting<BTreeMap<String, Weight>>,
     displayed_fields: Setting<Vec<String>>,
     filterable_fields: Setting<HashSet<String>>,
     sortable_fields: Setting<HashSet<String>>,
@@ -170,6 +179,9 @@ pub struct Settings<'a, 't, 'i> {
     exact_words: Setting<BTreeSet<String>>,
     /// Attributes on which typo tolerance is disabled.
     exact_attributes: Setting<HashSet<String>>,
+    /// Attributes matched by the phonetic codes of the query words.
+    phonetic_attributes: Setting<HashSet<String>>,
+    phonetic_algorithm: Setting<PhoneticAlgorithm>,
     max_values_per_facet: Setting<usize>,
     sort_facet_values_by: Setting<OrderByMap>,
     pagination_max_total_hits: Setting<usize>,
@@ -177,6 +189,13 @@ pub struct Settings<'a, 't, 'i> {
     embedder_settings: Setting<BTreeMap<String, Setting<EmbeddingSettings>>>,
     search_cutoff: Setting<u64>,
     localized_attributes_rules: Setting<Vec<LocalizedAttributesRule>>,
+    changelog: Setting<bool>,
+    version_attribute: Setting<String>,
+    auto_generate_ids: Setting<bool>,
+    document_schema: Setting<DocumentSchema>,
+    ingest_pipeline: Setting<Vec<IngestProcessor>>,
+    computed_fields: Setting<BTreeMap<String, String>>,
+    date_fields: Setting<BTreeSet<String>>,
 }
 
 impl<'a, 't, 'i> Settings<'a, 't, 'i> {
@@ -189,6 +208,7 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
             wtxn,
             index,
             searchable_fields: Setting::NotSet,
+            searchable_attributes_weights: Setting::NotSet,
             displayed_fields: Setting::NotSet,
             filterable_fields: Setting::NotSet,
             sortable_fields: Setting::NotSet,
@@ -205,6 +225,8 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
             min_word_len_two_typos: Setting::NotSet,
             min_word_len_one_typo: Setting::NotSet,
             exact_attributes: Setting::NotSet,
+            phonetic_attributes: Setting::NotSet,
+            phonetic_algorithm: Setting::NotSet,
             max_values_per_facet: Setting::NotSet,
             sort_facet_values_by: Setting::NotSet,
             pagination_max_total_hits: Setting::NotSet,
@@ -212,6 +234,13 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
             embedder_settings: Setting::NotSet,
             search_cutoff: Setting::NotSet,
             localized_attributes_rules: Setting::NotSet,
+            changelog: Setting::NotSet,
+            version_attribute: Setting::NotSet,
+            auto_generate_ids: Setting::NotSet,
+            document_schema: Setting::NotSet,
+            ingest_pipeline: Setting::NotSet,
+            computed_fields: Setting::NotSet,
+            date_fields: Setting::NotSet,
             indexer_config,
         }
     }
@@ -224,6 +253,14 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.searchable_fields = Setting::Set(names);
     }
 
+    pub fn reset_searchable_attributes_weights(&mut self) {
+        self.searchable_attributes_weights = Setting::Reset;
+    }
+
+    pub fn set_searchable_attributes_weights(&mut self, weights: BTreeMap<String, Weight>) {
+        self.searchable_attributes_weights = Setting::Set(weights);
+    }
+
     pub fn reset_displayed_fields(&mut self) {
         self.displayed_fields = Setting::Reset;
     }
@@ -362,6 +399,22 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.exact_attributes = Setting::Reset;
     }
 
+    pub fn set_phonetic_attributes(&mut self, attrs: HashSet<String>) {
+        self.phonetic_attributes = Setting::Set(attrs);
+    }
+
+    pub fn reset_phonetic_attributes(&mut self) {
+        self.phonetic_attributes = Setting::Reset;
+    }
+
+    pub fn set_phonetic_algorithm(&mut self, value: PhoneticAlgorithm) {
+        self.phonetic_algorithm = Setting::Set(value);
+    }
+
+    pub fn reset_phonetic_algorithm(&mut self) {
+        self.phonetic_algorithm = Setting::Reset;
+    }
+
     pub fn set_max_values_per_facet(&mut self, value: usize) {
         self.max_values_per_facet = Setting::Set(value);
     }
@@ -418,6 +471,62 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.localized_attributes_rules = Setting::Reset;
     }
 
+    pub fn set_changelog(&mut self, value: bool) {
+        self.changelog = Setting::Set(value);
+    }
+
+    pub fn reset_changelog(&mut self) {
+        self.changelog = Setting::Reset;
+    }
+
+    pub fn set_version_attribute(&mut self, version_attribute: String) {
+        self.version_attribute = Setting::Set(version_attribute);
+    }
+
+    pub fn reset_version_attribute(&mut self) {
+        self.version_attribute = Setting::Reset;
+    }
+
+    pub fn set_auto_generate_ids(&mut self, value: bool) {
+        self.auto_generate_ids = Setting::Set(value);
+    }
+
+    pub fn reset_auto_generate_ids(&mut self) {
+        self.auto_generate_ids = Setting::Reset;
+    }
+
+    pub fn set_document_schema(&mut self, value: DocumentSchema) {
+        self.document_schema = Setting::Set(value);
+    }
+
+    pub fn reset_document_schema(&mut self) {
+        self.document_schema = Setting::Reset;
+    }
+
+    pub fn set_ingest_pipeline(&mut self, value: Vec<IngestProcessor>) {
+        self.ingest_pipeline = Setting::Set(value);
+    }
+
+    pub fn reset_ingest_pipeline(&mut self) {
+        self.ingest_pipeline = Setting::Reset;
+    }
+
+    pub fn set_computed_fields(&mut self, value: BTreeMap<String, String>) {
+        self.computed_fields = Setting::Set(value);
+    }
+
+    pub fn reset_computed_fields(&mut self) {
+        self.computed_fields = Setting::Reset;
+    }
+
+    pub fn set_date_fields(&mut self, names: BTreeSet<String>) {
+        self.date_fields = Setting::Set(names);
+    }
+
+    pub fn reset_date_fields(&mut self) {
+        self.date_fields = Setting::Reset;
+    }
+
     #[tracing::instrument(
         level = "trace"
         skip(self, progress_callback, should_abort, settings_diff),
@@ -535,6 +644,40 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         }
     }
 
+    /// Updates the explicit weights of the searchable attributes.
+    fn update_searchable_attributes_weights(&mut self) -> Result<bool> {
+        let changed = match self.searchable_attributes_weights {
+            Setting::Set(ref weights) => {
+                if &self.index.searchable_attributes_weights(self.wtxn)? == weights {
+                    false
+                } else {
+                    self.index.put_searchable_attributes_weights(self.wtxn, weights)?;
+                    true
+                }
+            }
+            Setting::Reset => self.index.delete_searchable_attributes_weights(self.wtxn)?,
+            Setting::NotSet => false,
+        };
+
+        // The weights are only used when ranking the documents, recomputing the fieldids weights
+        // map of the user defined searchable attributes is enough and no reindexing is needed.
+        if changed {
+            if let Some(fields) = self.index.user_defined_searchable_fields(self.wtxn)? {
+                let fields: Vec<_> = fields.into_iter().map(String::from).collect();
+                let names: Vec<_> = fields.iter().map(String::as_str).collect();
+                let fields_ids_map = self.index.fields_ids_map(self.wtxn)?;
+                self.index.put_all_searchable_fields_from_fields_ids_map(
+                    self.wtxn,
+                    &names,
+                    &fields_ids_map.nested_ids(RESERVED_VECTORS_FIELD_NAME),
+                    &fields_ids_map,
+                )?;
+            }
+        }
+
+        Ok(changed)
+    }
+
     fn update_stop_words(&mut self) -> Result<bool> {
         match self.stop_words {
             Setting::Set(ref stop_words) => {
@@ -736,6 +879,41 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         }
     }
 
+    fn update_phonetic_attributes(&mut self) -> Result<bool> {
+        match self.phonetic_attributes {
+            Setting::Set(ref attrs) => {
+                let old_attrs = self.index.phonetic_attributes(self.wtxn)?;
+                let old_attrs = old_attrs.into_iter().map(String::from).collect::<HashSet<_>>();
+
+                if attrs != &old_attrs {
+                    let attrs = attrs.iter().map(String::as_str).collect::<Vec<_>>();
+                    self.index.put_phonetic_attributes(self.wtxn, &attrs)?;
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+            Setting::Reset => Ok(self.index.delete_phonetic_attributes(self.wtxn)?),
+            Setting::NotSet => Ok(false),
+        }
+    }
+
+    fn update_phonetic_algorithm(&mut self) -> Result<bool> {
+        match self.phonetic_algorithm {
+            Setting::Set(new) => {
+                let old = self.index.phonetic_algorithm(self.wtxn)?;
+                if old == Some(new) {
+                    Ok(false)
+                } else {
+                    self.index.put_phonetic_algorithm(self.wtxn, new)?;
+                    Ok(true)
+                }
+            }
+            Setting::Reset => Ok(self.index.delete_phonetic_algorithm(self.wtxn)?),
+            Setting::NotSet => Ok(false),
+        }
+    }
+
     fn update_filterable(&mut self) -> Result<()> {
         match self.filterable_fields {
             Setting::Set(ref fields) => {
@@ -770,6 +948,17 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         Ok(())
     }
 
+    fn update_date_fields(&mut self) -> Result<()> {
+        match self.date_fields {
+            Setting::Set(ref fields) => self.index.put_date_fields(self.wtxn, fields)?,
+            Setting::Reset => {
+                self.index.delete_date_fields(self.wtxn)?;
+            }
+            Setting::NotSet => (),
+        }
+        Ok(())
+    }
+
     fn update_criteria(&mut self) -> Result<()> {
         match &self.criteria {
             Setting::Set(criteria) => {
@@ -786,6 +975,7 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
     fn update_primary_key(&mut self) -> Result<()> {
         match self.primary_key {
             Setting::Set(ref primary_key) => {
+                validate_primary_key(primary_key)?;
                 if self.index.number_of_documents(self.wtxn)? == 0 {
                     let mut fields_ids_map = self.index.fields_ids_map(self.wtxn)?;
                     fields_ids_map.insert(primary_key).ok_or(UserError::AttributeLimitReached)?;
@@ -1170,6 +1360,119 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         Ok(())
     }
 
+    fn update_changelog(&mut self) -> Result<bool> {
+        let changed = match self.changelog {
+            Setting::Set(new) => {
+                let old = self.index.changelog(self.wtxn)?;
+                if old == new {
+                    false
+                } else {
+                    self.index.put_changelog(self.wtxn, new)?;
+                    true
+                }
+            }
+            Setting::Reset => self.index.delete_changelog(self.wtxn)?,
+            Setting::NotSet => false,
+        };
+
+        Ok(changed)
+    }
+
+    fn update_version_attribute(&mut self) -> Result<bool> {
+        let changed = match self.version_attribute {
+            Setting::Set(ref attr) => {
+                let old = self.index.version_attribute(self.wtxn)?;
+                if old == Some(attr.as_str()) {
+                    false
+                } else {
+                    self.index.put_version_attribute(self.wtxn, attr)?;
+                    true
+                }
+            }
+            Setting::Reset => self.index.delete_version_attribute(self.wtxn)?,
+            Setting::NotSet => false,
+        };
+        Ok(changed)
+    }
+
+    fn update_auto_generate_ids(&mut self) -> Result<bool> {
+        let changed = match self.auto_generate_ids {
+            Setting::Set(new) => {
+                let old = self.index.auto_generate_ids(self.wtxn)?;
+                if old == new {
+                    false
+                } else {
+                    self.index.put_auto_generate_ids(self.wtxn, new)?;
+                    true
+                }
+            }
+            Setting::Reset => self.index.delete_auto_generate_ids(self.wtxn)?,
+            Setting::NotSet => false,
+        };
+
+        Ok(changed)
+    }
+
+    /// The schema only applies to the documents sent after it is set,
+    /// the ones already in the index are not checked again.
+    fn update_document_schema(&mut self) -> Result<bool> {
+        let changed = match self.document_schema {
+            Setting::Set(ref new) => {
+                let old = self.index.document_schema(self.wtxn)?;
+                if old.as_ref() == Some(new) {
+                    false
+                } else {
+                    self.index.put_document_schema(self.wtxn, new)?;
+                    true
+                }
+            }
+            Setting::Reset => self.index.delete_document_schema(self.wtxn)?,
+            Setting::NotSet => false,
+        };
+
+        Ok(changed)
+    }
+
+    /// Like the document schema, the pipeline only applies to the documents sent after it is set.
+    fn update_ingest_pipeline(&mut self) -> Result<bool> {
+        let changed = match self.ingest_pipeline {
+            Setting::Set(ref new) => {
+                let old = self.index.ingest_pipeline(self.wtxn)?;
+                if &old == new {
+                    false
+                } else {
+                    self.index.put_ingest_pipeline(self.wtxn, new)?;
+                    true
+                }
+            }
+            Setting::Reset => self.index.delete_ingest_pipeline(self.wtxn)?,
+            Setting::NotSet => false,
+        };
+
+        Ok(changed)
+    }
+
+    /// The expressions are compiled beforehand to reject the invalid ones,
+    /// the documents are then reindexed to compute the new fields.
+    fn update_computed_fields(&mut self) -> Result<bool> {
+        let changed = match self.computed_fields {
+            Setting::Set(ref new) => {
+                let old = self.index.computed_fields(self.wtxn)?;
+                if &old == new {
+                    false
+                } else {
+                    ComputedFields::compile(new)?;
+                    self.index.put_computed_fields(self.wtxn, new)?;
+                    true
+                }
+            }
+            Setting::Reset => self.index.delete_computed_fields(self.wtxn)?,
+            Setting::NotSet => false,
+        };
+
+        Ok(changed)
+    }
+
     pub fn execute<FP, FA>(mut self, progress_callback: FP, should_abort: FA) -> Result<()>
     where
         FP: Fn(UpdateIndexingStep) + Sync,
@@ -1191,19 +1494,29 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.update_sort_facet_values_by()?;
         self.update_pagination_max_total_hits()?;
         self.update_search_cutoff()?;
+        self.update_changelog()?;
+        self.update_version_attribute()?;
+        self.update_auto_generate_ids()?;
+        self.update_document_schema()?;
+        self.update_ingest_pipeline()?;
 
         // could trigger re-indexing
+        self.update_computed_fields()?;
         self.update_filterable()?;
         self.update_sortable()?;
+        self.update_date_fields()?;
         self.update_stop_words()?;
         self.update_non_separator_tokens()?;
         self.update_separator_tokens()?;
         self.update_dictionary()?;
         self.update_synonyms()?;
         self.update_searchable()?;
+        self.update_searchable_attributes_weights()?;
         self.update_exact_attributes()?;
         self.update_proximity_precision()?;
         self.update_localized_attributes_rules()?;
+        let phonetic_attributes_updated = self.update_phonetic_attributes()?;
+        let phonetic_algorithm_updated = self.update_phonetic_algorithm()?;
 
         let embedding_config_updates = self.update_embedding_configs()?;
 
@@ -1214,7 +1527,9 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
             .index
             .primary_key(self.wtxn)?
             .and_then(|name| new_inner_settings.fields_ids_map.id(name));
-        let settings_update_only = true;
+        // the documents are reindexed as updated ones when their computed fields change
+        let settings_update_only =
+            old_inner_settings.computed_fields == new_inner_settings.computed_fields;
         let inner_settings_diff = InnerIndexSettingsDiff::new(
             old_inner_settings,
             new_inner_settings,
@@ -1227,6 +1542,11 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
             self.reindex(&progress_callback, &should_abort, inner_settings_diff)?;
         }
 
+        // the phonetic codes are derived from the words of the index, no reindexing is needed
+        if phonetic_attributes_updated || phonetic_algorithm_updated {
+            build_phonetic_code_words(self.index, self.wtxn)?;
+        }
+
         Ok(())
     }
 }
@@ -1338,7 +1658,10 @@ impl InnerIndexSettingsDiff {
     }
 
     pub fn any_reindexing_needed(&self) -> bool {
-        self.reindex_searchable() || self.reindex_facets() || self.reindex_vectors()
+        self.reindex_searchable()
+            || self.reindex_facets()
+            || self.reindex_vectors()
+            || self.reindex_computed_fields()
     }
 
     pub fn reindex_searchable(&self) -> bool {
@@ -1393,12 +1716,18 @@ impl InnerIndexSettingsDiff {
 
         (existing_fields - old_faceted_fields) != (existing_fields - new_faceted_fields)
             || self.old.localized_faceted_fields_ids != self.new.localized_faceted_fields_ids
+            || self.old.date_fields_ids != self.new.date_fields_ids
     }
 
     pub fn reindex_vectors(&self) -> bool {
         !self.embedding_config_updates.is_empty()
     }
 
+    /// The computed fields must be evaluated again on all the documents.
+    pub fn reindex_computed_fields(&self) -> bool {
+        self.old.computed_fields != self.new.computed_fields
+    }
+
     pub fn settings_update_only(&self) -> bool {
         self.settings_update_only
     }
@@ -1428,6 +1757,8 @@ pub(crate) struct InnerIndexSettings {
     pub embedding_configs: EmbeddingConfigs,
     pub existing_fields: HashSet<String>,
     pub geo_fields_ids: Option<(FieldId, FieldId)>,
+    pub date_fields_ids: BTreeSet<FieldId>,
+    pub computed_fields: BTreeMap<String, String>,
     pub non_searchable_fields_ids: Vec<FieldId>,
     pub non_faceted_fields_ids: Vec<FieldId>,
     pub localized_searchable_fields_ids: LocalizedFieldIds,
@@ -1480,6 +1811,9 @@ impl InnerIndexSettings {
             }
             None => None,
         };
+        let date_fields_ids =
+            index.date_fields(rtxn)?.iter().filter_map(|field| fields_ids_map.id(field)).collect();
+        let computed_fields = index.computed_fields(rtxn)?;
         let localized_attributes_rules = index.localized_attributes_rules(rtxn)?;
         let localized_searchable_fields_ids = LocalizedFieldIds::new(
             &localized_attributes_rules,
@@ -1510,6 +1844,8 @@ impl InnerIndexSettings {
             embedding_configs,
             existing_fields,
             geo_fields_ids,
+            date_fields_ids,
+            computed_fields,
             non_searchable_fields_ids: vectors_fids.clone(),
             non_faceted_fields_ids: vectors_fids.clone(),
             localized_searchable_fields_ids,
@@ -2698,6 +3034,7 @@ mod tests {
                     index: _,
                     indexer_config: _,
                     searchable_fields,
+                    searchable_attributes_weights,
                     displayed_fields,
                     filterable_fields,
                     sortable_fields,
@@ -2714,6 +3051,8 @@ mod tests {
                     min_word_len_one_typo,
                     exact_words,
                     exact_attributes,
+                    phonetic_attributes,
+                    phonetic_algorithm,
                     max_values_per_facet,
                     sort_facet_values_by,
                     pagination_max_total_hits,
@@ -2721,8 +3060,16 @@ mod tests {
                     embedder_settings,
                     search_cutoff,
                     localized_attributes_rules,
+                    changelog,
+                    version_attribute,
+                    auto_generate_ids,
+                    document_schema,
+                    ingest_pipeline,
+                    computed_fields,
+                    date_fields,
                 } = settings;
                 assert!(matches!(searchable_fields, Setting::NotSet));
+                assert!(matches!(searchable_attributes_weights, Setting::NotSet));
                 assert!(matches!(displayed_fields, Setting::NotSet));
                 assert!(matches!(filterable_fields, Setting::NotSet));
                 assert!(matches!(sortable_fields, Setting::NotSet));
@@ -2739,6 +3086,8 @@ mod tests {
                 assert!(matches!(min_word_len_one_typo, Setting::NotSet));
                 assert!(matches!(exact_words, Setting::NotSet));
                 assert!(matches!(exact_attributes, Setting::NotSet));
+                assert!(matches!(phonetic_attributes, Setting::NotSet));
+                assert!(matches!(phonetic_algorithm, Setting::NotSet));
                 assert!(matches!(max_values_per_facet, Setting::NotSet));
                 assert!(matches!(sort_facet_values_by, Setting::NotSet));
                 assert!(matches!(pagination_max_total_hits, Setting::NotSet));
@@ -2746,6 +3095,13 @@ mod tests {
                 assert!(matches!(embedder_settings, Setting::NotSet));
                 assert!(matches!(search_cutoff, Setting::NotSet));
                 assert!(matches!(localized_attributes_rules, Setting::NotSet));
+                assert!(matches!(changelog, Setting::NotSet));
+                assert!(matches!(version_attribute, Setting::NotSet));
+                assert!(matches!(auto_generate_ids, Setting::NotSet));
+                assert!(matches!(document_schema, Setting::NotSet));
+                assert!(matches!(ingest_pipeline, Setting::NotSet));
+                assert!(matches!(computed_fields, Setting::NotSet));
+                assert!(matches!(date_fields, Setting::NotSet));
             })
             .unwrap();
     }